@@ -0,0 +1,31 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use bisere::checksum;
+
+const SIZE: usize = 1 << 20;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    // 1 MB of non-repeating bytes
+    let data: Vec<u8> = (0..SIZE as u32).map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8).collect();
+    assert_eq!(checksum::crc32(&data), checksum::crc32_scalar(&data));
+    
+    let mut group = c.benchmark_group("checksum_1mb");
+    group.throughput(Throughput::Bytes(SIZE as u64));
+    
+    group.bench_function("crc32_scalar", |b| {
+        b.iter(|| checksum::crc32_scalar(black_box(&data)))
+    });
+    
+    // Same as crc32_scalar unless built with `--features crc32fast`
+    group.bench_function("crc32", |b| {
+        b.iter(|| checksum::crc32(black_box(&data)))
+    });
+    
+    group.bench_function("fnv1a64", |b| {
+        b.iter(|| checksum::fnv1a64(black_box(&data)))
+    });
+    
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -22,6 +22,17 @@ struct UserDataSerde {
     active: u8,
 }
 
+// Offset table matching the `UserData` layout
+fn user_entries() -> Vec<OffsetEntry> {
+    let mut offset = 0u32;
+    vec![
+        OffsetEntry { field_id: 1, offset, field_type: FieldType::Uint64 as u16, size: 8 },
+        OffsetEntry { field_id: 2, offset: { offset += 8; offset }, field_type: FieldType::Uint32 as u16, size: 4 },
+        OffsetEntry { field_id: 3, offset: { offset += 4; offset }, field_type: FieldType::Float64 as u16, size: 8 },
+        OffsetEntry { field_id: 4, offset: { offset += 8; offset }, field_type: FieldType::Uint8 as u16, size: 1 },
+    ]
+}
+
 // Helper to serialize with biSere
 fn bisere_serialize(data: &UserData) -> Vec<u8> {
     let mut serializer = BinarySerializer::new();
@@ -32,14 +43,7 @@ fn bisere_serialize(data: &UserData) -> Vec<u8> {
     let header = FormatHeader::new(offset_table_size, data_size, var_size);
     serializer.write_header(header);
     
-    let mut offset = 0u32;
-    let entries = vec![
-        OffsetEntry { field_id: 1, offset, field_type: FieldType::Uint64 as u16, size: 8 },
-        OffsetEntry { field_id: 2, offset: { offset += 8; offset }, field_type: FieldType::Uint32 as u16, size: 4 },
-        OffsetEntry { field_id: 3, offset: { offset += 4; offset }, field_type: FieldType::Float64 as u16, size: 8 },
-        OffsetEntry { field_id: 4, offset: { offset += 8; offset }, field_type: FieldType::Uint8 as u16, size: 1 },
-    ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&user_entries()).unwrap();
     serializer.write_data(bytemuck::bytes_of(data));
     serializer.write_var_data(&[]);
     serializer.into_buffer()
@@ -47,10 +51,10 @@ fn bisere_serialize(data: &UserData) -> Vec<u8> {
 
 fn bisere_deserialize(buffer: &[u8]) -> (u64, u32, f64, u8) {
     let view = BinaryView::view(buffer).unwrap();
-    let id = *view.get_field::<u64>(1).unwrap();
-    let age = *view.get_field::<u32>(2).unwrap();
-    let score = *view.get_field::<f64>(3).unwrap();
-    let active = *view.get_field::<u8>(4).unwrap();
+    let id = view.get_field_copy::<u64>(1).unwrap();
+    let age = view.get_field_copy::<u32>(2).unwrap();
+    let score = view.get_field_copy::<f64>(3).unwrap();
+    let active = view.get_field_copy::<u8>(4).unwrap();
     (id, age, score, active)
 }
 
@@ -201,9 +205,9 @@ fn criterion_benchmark(c: &mut Criterion) {
     group.bench_function("bisere_zero_copy", |b| {
         let view = BinaryView::view(&bisere_buf).unwrap();
         b.iter(|| {
-            black_box(*view.get_field::<u64>(1).unwrap());
-            black_box(*view.get_field::<u32>(2).unwrap());
-            black_box(*view.get_field::<f64>(3).unwrap());
+            black_box(view.get_field_copy::<u64>(1).unwrap());
+            black_box(view.get_field_copy::<u32>(2).unwrap());
+            black_box(view.get_field_copy::<f64>(3).unwrap());
         })
     });
     
@@ -240,6 +244,14 @@ fn criterion_benchmark(c: &mut Criterion) {
         })
     });
     
+    let editor = MutEditor::new(Schema::new(user_entries()).unwrap());
+    group.bench_function("bisere_editor_bind", |b| {
+        b.iter(|| {
+            let mut edit = editor.bind(black_box(&mut bisere_buf)).unwrap();
+            edit.set(2, &31u32).unwrap();
+        })
+    });
+    
     group.bench_function("bincode_re_serialize", |b| {
         b.iter(|| {
             let mut data: UserDataSerde = bincode_deserialize(black_box(&bincode_buf));
@@ -258,6 +270,35 @@ fn criterion_benchmark(c: &mut Criterion) {
     
     group.finish();
 
+    // Bulk scan: validate and read every field of a wide buffer
+    let mut builder = SchemaBuilder::new();
+    for field_id in 0..64u32 {
+        builder.add_fixed(field_id, FieldType::Uint64, &(field_id as u64).to_le_bytes()).unwrap();
+    }
+    let wide_buf = builder.build().unwrap();
+    let mut group = c.benchmark_group("bulk_scan");
+    group.throughput(Throughput::Elements(64));
+    
+    group.bench_function("view_then_get", |b| {
+        b.iter(|| {
+            let view = BinaryView::view(black_box(&wide_buf)).unwrap();
+            for field_id in 0..64u32 {
+                black_box(view.get_field_copy::<u64>(field_id).unwrap());
+            }
+        })
+    });
+    
+    group.bench_function("validated_iter", |b| {
+        b.iter(|| {
+            let view = BinaryView::view(black_box(&wide_buf)).unwrap();
+            for field in view.into_validated_iter() {
+                black_box(field.unwrap());
+            }
+        })
+    });
+    
+    group.finish();
+
     // Buffer size comparison
     println!("\n=== Buffer Size Comparison ===");
     println!("biSere:      {} bytes", bisere_buf.len());
@@ -21,10 +21,13 @@ struct TestStructSerde {
 }
 
 fn bisere_serialize_many(data: &[TestStruct]) -> Vec<u8> {
-    let mut serializer = BinarySerializer::new();
+    bisere_serialize_with(BinarySerializer::new(), data)
+}
+
+fn bisere_serialize_with(mut serializer: BinarySerializer, data: &[TestStruct]) -> Vec<u8> {
     let num_fields = 4;
     let offset_table_size = (data.len() * num_fields * std::mem::size_of::<OffsetEntry>()) as u32;
-    let data_size = (data.len() * std::mem::size_of::<TestStruct>()) as u32;
+    let data_size = std::mem::size_of_val(data) as u32;
     let var_size = 0;
     
     let header = FormatHeader::new(offset_table_size, data_size, var_size);
@@ -61,7 +64,7 @@ fn bisere_serialize_many(data: &[TestStruct]) -> Vec<u8> {
         });
     }
     
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
     
     // Serialize all structs
     let mut all_data = Vec::new();
@@ -130,6 +133,62 @@ fn criterion_benchmark(c: &mut Criterion) {
     }
     
     group.finish();
+    
+    // Looking up the last field: linear scan vs binary search over a sorted table
+    let mut group = c.benchmark_group("lookup_varying_sizes");
+    
+    for size in [1usize, 10, 100, 1000] {
+        let data_vec: Vec<TestStruct> = (0..size).map(|i| TestStruct {
+            id: i as u64,
+            age: (i % 100) as u32,
+            score: (i as f64) * 0.1,
+            active: (i % 2) as u8,
+        }).collect();
+        let last_id = (size * 4) as u32;
+        
+        let linear = bisere_serialize_many(&data_vec);
+        let sorted = bisere_serialize_with(BinarySerializer::new().with_sorted_table(), &data_vec);
+        
+        for (name, buffer) in [("bisere_linear", &linear), ("bisere_sorted", &sorted)] {
+            let view = BinaryView::view(buffer).unwrap();
+            group.bench_with_input(BenchmarkId::new(name, size), &last_id, |b, &field_id| {
+                b.iter(|| black_box(view.get_field_copy::<u8>(black_box(field_id)).unwrap()))
+            });
+        }
+    }
+    
+    group.finish();
+    
+    // Reading every record back: one slice vs one lookup per field
+    let mut group = c.benchmark_group("read_all_varying_sizes");
+    
+    for size in [1usize, 10, 100, 1000] {
+        let data_vec: Vec<TestStruct> = (0..size).map(|i| TestStruct {
+            id: i as u64,
+            age: (i % 100) as u32,
+            score: (i as f64) * 0.1,
+            active: (i % 2) as u8,
+        }).collect();
+        let buffer = bisere_serialize_with(BinarySerializer::new().with_sorted_table(), &data_vec);
+        let view = BinaryView::view(&buffer).unwrap();
+        
+        group.throughput(Throughput::Elements(size as u64));
+        
+        group.bench_with_input(BenchmarkId::new("bisere_record_slice", size), &size, |b, &count| {
+            b.iter(|| {
+                let records = view.get_record_slice::<TestStruct>(1, count).unwrap();
+                black_box(records.iter().map(|record| { record.age } as u64).sum::<u64>())
+            })
+        });
+        
+        group.bench_with_input(BenchmarkId::new("bisere_per_field", size), &size, |b, &count| {
+            b.iter(|| {
+                black_box((0..count as u32).map(|i| view.get_field_copy::<u32>(i * 4 + 2).unwrap() as u64).sum::<u64>())
+            })
+        });
+    }
+    
+    group.finish();
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,459 @@
+//! Procedural macros for `bisere`; use them through the `bisere` re-exports
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    braced, bracketed, parse_macro_input, Attribute, Data, DeriveInput, Fields, Ident, LitInt, Token, Type, Visibility,
+};
+
+/// Generate a typed owned struct, reader and mutator from a field list
+///
+/// ```text
+/// schema! {
+///     pub struct User {
+///         id: u64 = 1,
+///         age: u32 = 2,
+///         name: String[64] = 10,
+///     }
+/// }
+/// ```
+///
+/// Each field is `name: type = field_id`. Fixed fields may be any of
+/// `u8`..`u64`, `i8`..`i64`, `f32`, `f64` or `bool`; `String[N]` and
+/// `Vec<u8>[N]` declare var fields reserving `N` bytes. Offsets are assigned
+/// in declaration order when the macro expands. The expansion defines:
+///
+/// - `User`: owned values with `schema()` and `to_bytes()`
+/// - `User::view(&[u8]) -> Result<UserView>` with one getter per field
+/// - `User::view_mut(&mut [u8]) -> Result<UserViewMut>` with `set_<field>` setters
+#[proc_macro]
+pub fn schema(input: TokenStream) -> TokenStream {
+    let def = parse_macro_input!(input as SchemaDef);
+    match expand(&def) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Implement `to_bisere` and `from_bisere` for a struct of fixed-size fields
+///
+/// ```text
+/// #[derive(BiSere)]
+/// struct UserData {
+///     #[bisere(id = 1)]
+///     id: u64,
+///     #[bisere(id = 2)]
+///     age: u32,
+///     active: bool,
+/// }
+/// ```
+///
+/// Fields may be any of `u8`..`u64`, `i8`..`i64`, `f32`, `f64` or `bool` and
+/// are laid out in declaration order. `#[bisere(id = N)]` sets a field's id;
+/// untagged fields take their 1-based position in the struct. Use `schema!`
+/// when strings or blobs are needed.
+#[proc_macro_derive(BiSere, attributes(bisere))]
+pub fn derive_bisere(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_derive(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+struct SchemaDef {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    fields: Punctuated<FieldDef, Token![,]>,
+}
+
+struct FieldDef {
+    name: Ident,
+    ty: Type,
+    capacity: Option<LitInt>,
+    id: LitInt,
+}
+
+impl Parse for SchemaDef {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![struct]>()?;
+        let name = input.parse()?;
+        let content;
+        braced!(content in input);
+        let fields = content.parse_terminated(FieldDef::parse, Token![,])?;
+
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            fields,
+        })
+    }
+}
+
+impl Parse for FieldDef {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        let capacity = if input.peek(syn::token::Bracket) {
+            let content;
+            bracketed!(content in input);
+            Some(content.parse()?)
+        } else {
+            None
+        };
+        input.parse::<Token![=]>()?;
+        let id = input.parse()?;
+
+        Ok(Self {
+            name,
+            ty,
+            capacity,
+            id,
+        })
+    }
+}
+
+/// How a declared Rust type maps onto the wire format
+enum Kind {
+    Fixed { variant: &'static str, width: u16 },
+    Bool,
+    String { capacity: u16 },
+    Blob { capacity: u16 },
+}
+
+impl Kind {
+    fn of(field: &FieldDef) -> syn::Result<Self> {
+        let ty = &field.ty;
+        let kind = Self::of_type(ty)?;
+
+        match (kind, &field.capacity) {
+            (Kind::String { .. }, Some(lit)) => Ok(Kind::String { capacity: lit.base10_parse()? }),
+            (Kind::Blob { .. }, Some(lit)) => Ok(Kind::Blob { capacity: lit.base10_parse()? }),
+            (Kind::String { .. } | Kind::Blob { .. }, None) => Err(syn::Error::new_spanned(
+                ty,
+                "variable-length fields need a capacity, e.g. `String[64]`",
+            )),
+            (_, Some(lit)) => Err(syn::Error::new_spanned(lit, "fixed-size fields take no capacity")),
+            (kind, None) => Ok(kind),
+        }
+    }
+
+    fn of_type(ty: &Type) -> syn::Result<Self> {
+        let type_name = quote!(#ty).to_string().replace(' ', "");
+        let fixed = |variant, width| Kind::Fixed { variant, width };
+
+        let kind = match type_name.as_str() {
+            "u8" => fixed("Uint8", 1),
+            "u16" => fixed("Uint16", 2),
+            "u32" => fixed("Uint32", 4),
+            "u64" => fixed("Uint64", 8),
+            "i8" => fixed("Int8", 1),
+            "i16" => fixed("Int16", 2),
+            "i32" => fixed("Int32", 4),
+            "i64" => fixed("Int64", 8),
+            "f32" => fixed("Float32", 4),
+            "f64" => fixed("Float64", 8),
+            "bool" => Kind::Bool,
+            "String" => Kind::String { capacity: 0 },
+            "Vec<u8>" => Kind::Blob { capacity: 0 },
+            _ => return Err(syn::Error::new_spanned(ty, "unsupported field type")),
+        };
+        Ok(kind)
+    }
+
+    fn variant(&self) -> Ident {
+        let name = match self {
+            Kind::Fixed { variant, .. } => variant,
+            Kind::Bool => "Bool",
+            Kind::String { .. } => "String",
+            Kind::Blob { .. } => "Blob",
+        };
+        format_ident!("{}", name)
+    }
+
+    fn size(&self) -> u16 {
+        match *self {
+            Kind::Fixed { width, .. } => width,
+            Kind::Bool => 1,
+            Kind::String { capacity } | Kind::Blob { capacity } => capacity,
+        }
+    }
+
+    fn is_variable(&self) -> bool {
+        matches!(self, Kind::String { .. } | Kind::Blob { .. })
+    }
+}
+
+fn expand(def: &SchemaDef) -> syn::Result<TokenStream2> {
+    let SchemaDef { attrs, vis, name, .. } = def;
+    let view_name = format_ident!("{}View", name);
+    let view_mut_name = format_ident!("{}ViewMut", name);
+
+    let mut seen = Vec::new();
+    let mut data_size = 0u32;
+    let mut var_size = 0u32;
+
+    let mut owned_fields = Vec::new();
+    let mut entries = Vec::new();
+    let mut pushes = Vec::new();
+    let mut getters = Vec::new();
+    let mut setters = Vec::new();
+
+    for field in &def.fields {
+        let field_id: u32 = field.id.base10_parse()?;
+        if seen.contains(&field_id) {
+            return Err(syn::Error::new_spanned(&field.id, "duplicate field id"));
+        }
+        seen.push(field_id);
+
+        let kind = Kind::of(field)?;
+        let variant = kind.variant();
+        let size = kind.size();
+        let cursor = if kind.is_variable() { &mut var_size } else { &mut data_size };
+        let offset = *cursor;
+        *cursor = offset
+            .checked_add(size as u32)
+            .ok_or_else(|| syn::Error::new_spanned(&field.name, "section size exceeds u32::MAX"))?;
+
+        let field_name = &field.name;
+        let setter = format_ident!("set_{}", field_name);
+        let ty = &field.ty;
+
+        owned_fields.push(quote! { pub #field_name: #ty });
+        entries.push(quote! {
+            ::bisere::OffsetEntry {
+                field_id: #field_id,
+                offset: #offset,
+                field_type: ::bisere::FieldType::#variant as u16,
+                size: #size,
+            }
+        });
+
+        match kind {
+            Kind::Fixed { .. } => {
+                pushes.push(quote! {
+                    serializer.push_fixed(#field_id, ::bisere::FieldType::#variant, &self.#field_name.to_le_bytes())?;
+                });
+                getters.push(quote! {
+                    pub fn #field_name(&self) -> ::bisere::Result<#ty> {
+                        self.view.get_field_copy(#field_id)
+                    }
+                });
+                setters.push(quote! {
+                    pub fn #setter(&mut self, value: #ty) -> ::bisere::Result<()> {
+                        self.view.modify_field(#field_id, &value)
+                    }
+                });
+            }
+            Kind::Bool => {
+                pushes.push(quote! {
+                    serializer.push_fixed(#field_id, ::bisere::FieldType::Bool, &[self.#field_name as u8])?;
+                });
+                getters.push(quote! {
+                    pub fn #field_name(&self) -> ::bisere::Result<bool> {
+                        Ok(self.view.get_field_copy::<u8>(#field_id)? != 0)
+                    }
+                });
+                setters.push(quote! {
+                    pub fn #setter(&mut self, value: bool) -> ::bisere::Result<()> {
+                        self.view.modify_field(#field_id, &(value as u8))
+                    }
+                });
+            }
+            Kind::String { capacity } => {
+                pushes.push(quote! {
+                    serializer.push_var(#field_id, ::bisere::FieldType::String, self.#field_name.as_bytes(), #capacity)?;
+                });
+                getters.push(quote! {
+                    pub fn #field_name(&self) -> ::bisere::Result<&str> {
+                        self.view.get_string(#field_id)
+                    }
+                });
+                setters.push(quote! {
+                    pub fn #setter(&mut self, value: &str) -> ::bisere::Result<()> {
+                        self.view.modify_string(#field_id, value)
+                    }
+                });
+            }
+            Kind::Blob { capacity } => {
+                pushes.push(quote! {
+                    serializer.push_var(#field_id, ::bisere::FieldType::Blob, &self.#field_name, #capacity)?;
+                });
+                getters.push(quote! {
+                    pub fn #field_name(&self) -> ::bisere::Result<&'a [u8]> {
+                        self.view.get_blob(#field_id)
+                    }
+                });
+                setters.push(quote! {
+                    pub fn #setter(&mut self, value: &[u8]) -> ::bisere::Result<()> {
+                        self.view.modify_blob(#field_id, value)
+                    }
+                });
+            }
+        }
+    }
+
+    Ok(quote! {
+        #(#attrs)*
+        #[derive(Debug, Clone, PartialEq, Default)]
+        #vis struct #name {
+            #(#owned_fields,)*
+        }
+
+        impl #name {
+            /// Offset table computed when the schema was expanded
+            pub fn schema() -> ::bisere::Schema {
+                ::bisere::Schema::new(::bisere::__private::vec![#(#entries),*])
+                    .expect("section sizes were checked at expansion")
+            }
+
+            /// Serialize these values using the schema's layout
+            pub fn to_bytes(&self) -> ::bisere::Result<::bisere::__private::Vec<u8>> {
+                let mut serializer = ::bisere::IncrementalSerializer::new();
+                #(#pushes)*
+                Ok(serializer.finish())
+            }
+
+            /// Check `buffer` against the schema and wrap it in a typed reader
+            pub fn view(buffer: &[u8]) -> ::bisere::Result<#view_name<'_>> {
+                Self::schema().conforms(buffer)?;
+                Ok(#view_name {
+                    view: ::bisere::BinaryView::view(buffer)?,
+                })
+            }
+
+            /// Check `buffer` against the schema and wrap it in a typed mutator
+            pub fn view_mut(buffer: &mut [u8]) -> ::bisere::Result<#view_mut_name<'_>> {
+                Self::schema().conforms(buffer)?;
+                Ok(#view_mut_name {
+                    view: ::bisere::BinaryViewMut::view_mut(buffer)?,
+                })
+            }
+        }
+
+        /// Typed reader generated by `schema!`
+        #vis struct #view_name<'a> {
+            view: ::bisere::BinaryView<'a>,
+        }
+
+        impl<'a> #view_name<'a> {
+            /// The untyped view underneath
+            pub fn inner(&self) -> &::bisere::BinaryView<'a> {
+                &self.view
+            }
+
+            #(#getters)*
+        }
+
+        /// Typed mutator generated by `schema!`
+        #vis struct #view_mut_name<'a> {
+            view: ::bisere::BinaryViewMut<'a>,
+        }
+
+        impl #view_mut_name<'_> {
+            #(#setters)*
+        }
+    })
+}
+
+/// Field id from `#[bisere(id = N)]`, or `position` when untagged
+fn derive_field_id(attrs: &[Attribute], position: u32) -> syn::Result<(u32, Option<LitInt>)> {
+    let mut id = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("bisere")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("id") {
+                id = Some(meta.value()?.parse::<LitInt>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `id = N`"))
+            }
+        })?;
+    }
+
+    match id {
+        Some(lit) => Ok((lit.base10_parse()?, Some(lit))),
+        None => Ok((position, None)),
+    }
+}
+
+fn expand_derive(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new_spanned(input, "derive(BiSere) needs named fields")),
+        },
+        _ => return Err(syn::Error::new_spanned(input, "derive(BiSere) only supports structs")),
+    };
+
+    let mut seen = Vec::new();
+    let mut pushes = Vec::new();
+    let mut reads = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let (field_id, lit) = derive_field_id(&field.attrs, index as u32 + 1)?;
+        if seen.contains(&field_id) {
+            return Err(match lit {
+                Some(lit) => syn::Error::new_spanned(lit, "duplicate field id"),
+                None => syn::Error::new_spanned(field, "duplicate field id"),
+            });
+        }
+        seen.push(field_id);
+
+        let field_name = field.ident.as_ref().expect("named field");
+        match Kind::of_type(&field.ty)? {
+            kind @ Kind::Fixed { .. } => {
+                let variant = kind.variant();
+                pushes.push(quote! {
+                    serializer
+                        .push_fixed(#field_id, ::bisere::FieldType::#variant, &{ self.#field_name }.to_le_bytes())
+                        .expect("derived value width matches its field type");
+                });
+                reads.push(quote! { #field_name: view.get_field_copy(#field_id)? });
+            }
+            Kind::Bool => {
+                pushes.push(quote! {
+                    serializer
+                        .push_fixed(#field_id, ::bisere::FieldType::Bool, &[self.#field_name as u8])
+                        .expect("derived value width matches its field type");
+                });
+                reads.push(quote! { #field_name: view.get_field_copy::<u8>(#field_id)? != 0 });
+            }
+            Kind::String { .. } | Kind::Blob { .. } => {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "derive(BiSere) only supports fixed-size fields; use `schema!` for strings and blobs",
+                ));
+            }
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Serialize every field, laid out in declaration order
+            pub fn to_bisere(&self) -> ::bisere::__private::Vec<u8> {
+                let mut serializer = ::bisere::IncrementalSerializer::new();
+                #(#pushes)*
+                serializer.finish()
+            }
+
+            /// Read every field back out of `buffer` by id
+            pub fn from_bisere(buffer: &[u8]) -> ::bisere::Result<Self> {
+                let view = ::bisere::BinaryView::view(buffer)?;
+                Ok(Self {
+                    #(#reads,)*
+                })
+            }
+        }
+    })
+}
@@ -3,11 +3,15 @@ use bytemuck::{Pod, Zeroable};
 
 // Test data structures
 #[repr(C, packed)]
-#[derive(Debug, Clone, Copy, Pod, Zeroable, PartialEq)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable, PartialEq, BiSere)]
 struct UserData {
+    #[bisere(id = 1)]
     id: u64,
+    #[bisere(id = 2)]
     age: u32,
+    #[bisere(id = 3)]
     score: f64,
+    #[bisere(id = 4)]
     active: u8,
 }
 
@@ -335,28 +339,24 @@ fn test_basic_serialization() -> Result<()> {
 
     // Deserialize
     let view = BinaryView::view(&buffer)?;
-    let id: &u64 = view.get_field(1)?;
-    let age: &u32 = view.get_field(2)?;
-    let score: &f64 = view.get_field(3)?;
-    let active: &u8 = view.get_field(4)?;
-
-    // Copy values to avoid alignment issues with packed structs
-    let id_val = *id;
-    let age_val = *age;
-    let score_val = *score;
-    let active_val = *active;
+    let id: u64 = view.get_field_copy(1)?;
+    let age: u32 = view.get_field_copy(2)?;
+    let score: f64 = view.get_field_copy(3)?;
+    let active: u8 = view.get_field_copy(4)?;
+
+    // Copy packed struct fields out before comparing
     let user_id = user.id;
     let user_age = user.age;
     let user_score = user.score;
     let user_active = user.active;
 
     // Verify
-    assert_eq!(id_val, user_id, "ID mismatch");
-    assert_eq!(age_val, user_age, "Age mismatch");
-    assert_eq!(score_val, user_score, "Score mismatch");
-    assert_eq!(active_val, user_active, "Active mismatch");
+    assert_eq!(id, user_id, "ID mismatch");
+    assert_eq!(age, user_age, "Age mismatch");
+    assert_eq!(score, user_score, "Score mismatch");
+    assert_eq!(active, user_active, "Active mismatch");
 
-    println!("│ Deserialized: ID={}, Age={}, Score={}, Active={}", id_val, age_val, score_val, active_val != 0);
+    println!("│ Deserialized: ID={}, Age={}, Score={}, Active={}", id, age, score, active != 0);
     Ok(())
 }
 
@@ -413,9 +413,9 @@ fn test_inplace_modification() -> Result<()> {
 
     // Verify modifications
     let view = BinaryView::view(&buffer)?;
-    assert_eq!(*view.get_field::<u32>(2)?, new_age, "Age modification failed");
-    assert_eq!(*view.get_field::<f64>(3)?, new_score, "Score modification failed");
-    assert_eq!(*view.get_field::<u8>(4)?, new_active, "Active modification failed");
+    assert_eq!(view.get_field_copy::<u32>(2)?, new_age, "Age modification failed");
+    assert_eq!(view.get_field_copy::<f64>(3)?, new_score, "Score modification failed");
+    assert_eq!(view.get_field_copy::<u8>(4)?, new_active, "Active modification failed");
 
     println!("│ Modified: Age={}, Score={}, Active={}", new_age, new_score, new_active != 0);
     Ok(())
@@ -453,28 +453,25 @@ fn test_multiple_types() -> Result<()> {
             size: 4,
         },
     ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries)?;
     serializer.write_data(bytemuck::bytes_of(&point));
     serializer.write_var_data(&[]);
 
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer)?;
 
-    let x: &f32 = view.get_field(1)?;
-    let y: &f32 = view.get_field(2)?;
-    let z: &f32 = view.get_field(3)?;
+    let x: f32 = view.get_field_copy(1)?;
+    let y: f32 = view.get_field_copy(2)?;
+    let z: f32 = view.get_field_copy(3)?;
 
-    // Copy values to avoid alignment issues
-    let x_val = *x;
-    let y_val = *y;
-    let z_val = *z;
+    // Copy packed struct fields out before comparing
     let point_x = point.x;
     let point_y = point.y;
     let point_z = point.z;
 
-    assert_eq!(x_val, point_x, "X mismatch");
-    assert_eq!(y_val, point_y, "Y mismatch");
-    assert_eq!(z_val, point_z, "Z mismatch");
+    assert_eq!(x, point_x, "X mismatch");
+    assert_eq!(y, point_y, "Y mismatch");
+    assert_eq!(z, point_z, "Z mismatch");
 
     println!("│ Point3D: x={}, y={}, z={}", x, y, z);
     Ok(())
@@ -486,7 +483,7 @@ fn test_string_fields() -> Result<()> {
 
     let mut serializer = BinarySerializer::new();
     let header = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
+        std::mem::size_of::<OffsetEntry>() as u32,
         0,
         max_size,
     );
@@ -498,7 +495,7 @@ fn test_string_fields() -> Result<()> {
         field_type: FieldType::String as u16,
         size: max_size as u16,
     }];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries)?;
     serializer.write_data(&[]);
 
     let mut var_data = vec![0u8; max_size as usize];
@@ -532,7 +529,7 @@ fn test_blob_fields() -> Result<()> {
 
     let mut serializer = BinarySerializer::new();
     let header = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
+        std::mem::size_of::<OffsetEntry>() as u32,
         0,
         max_size,
     );
@@ -544,7 +541,7 @@ fn test_blob_fields() -> Result<()> {
         field_type: FieldType::Blob as u16,
         size: max_size as u16,
     }];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries)?;
     serializer.write_data(&[]);
 
     let mut var_data = vec![0u8; max_size as usize];
@@ -582,80 +579,37 @@ fn test_mixed_fields() -> Result<()> {
     let name = "John Doe";
     let max_var_size = 256;
 
-    let mut serializer = BinarySerializer::new();
-    let offset_table_size = 5 * std::mem::size_of::<OffsetEntry>() as u32;
-    let data_size = std::mem::size_of::<UserData>() as u32;
-
-    let header = FormatHeader::new(offset_table_size, data_size, max_var_size);
-    serializer.write_header(header);
-
-    let mut offset = 0u32;
-    let entries = vec![
-        OffsetEntry {
-            field_id: 1,
-            offset,
-            field_type: FieldType::Uint64 as u16,
-            size: 8,
-        },
-        OffsetEntry {
-            field_id: 2,
-            offset: { offset += 8; offset },
-            field_type: FieldType::Uint32 as u16,
-            size: 4,
-        },
-        OffsetEntry {
-            field_id: 3,
-            offset: { offset += 4; offset },
-            field_type: FieldType::Float64 as u16,
-            size: 8,
-        },
-        OffsetEntry {
-            field_id: 4,
-            offset: { offset += 8; offset },
-            field_type: FieldType::Uint8 as u16,
-            size: 1,
-        },
-        OffsetEntry {
-            field_id: 10,
-            offset: 0,
-            field_type: FieldType::String as u16,
-            size: max_var_size as u16,
-        },
-    ];
-    serializer.write_offset_table(&entries);
-    serializer.write_data(bytemuck::bytes_of(&user));
-
-    let mut var_data = vec![0u8; max_var_size as usize];
-    var_data[0..name.len()].copy_from_slice(name.as_bytes());
-    serializer.write_var_data(&var_data);
-
-    let buffer = serializer.into_buffer();
+    // The builder assigns offsets and sizes the sections
+    let mut builder = SchemaBuilder::new();
+    builder
+        .add_fixed_field(1, FieldType::Uint64, &{ user.id })?
+        .add_fixed_field(2, FieldType::Uint32, &{ user.age })?
+        .add_fixed_field(3, FieldType::Float64, &{ user.score })?
+        .add_fixed_field(4, FieldType::Uint8, &{ user.active })?
+        .add_string_field(10, max_var_size, name)?;
+    let buffer = builder.build()?;
     let view = BinaryView::view(&buffer)?;
 
-    let id: &u64 = view.get_field(1)?;
-    let age: &u32 = view.get_field(2)?;
-    let score: &f64 = view.get_field(3)?;
-    let active: &u8 = view.get_field(4)?;
+    let id: u64 = view.get_field_copy(1)?;
+    let age: u32 = view.get_field_copy(2)?;
+    let score: f64 = view.get_field_copy(3)?;
+    let active: u8 = view.get_field_copy(4)?;
     let name_str = view.get_string(10)?;
 
-    // Copy values to avoid alignment issues
-    let id_val = *id;
-    let age_val = *age;
-    let score_val = *score;
-    let active_val = *active;
+    // Copy packed struct fields out before comparing
     let user_id = user.id;
     let user_age = user.age;
     let user_score = user.score;
     let user_active = user.active;
 
-    assert_eq!(id_val, user_id);
-    assert_eq!(age_val, user_age);
-    assert_eq!(score_val, user_score);
-    assert_eq!(active_val, user_active);
+    assert_eq!(id, user_id);
+    assert_eq!(age, user_age);
+    assert_eq!(score, user_score);
+    assert_eq!(active, user_active);
     assert_eq!(name_str, name);
 
     println!("│ Mixed fields: ID={}, Age={}, Score={}, Active={}, Name='{}'", 
-             id, age, score, *active != 0, name_str);
+             id, age, score, active != 0, name_str);
     Ok(())
 }
 
@@ -672,7 +626,7 @@ fn test_error_handling() -> Result<()> {
     // Test field not found
     let buffer = serialize_user_data(&UserData { id: 1, age: 1, score: 1.0, active: 1 })?;
     let view = BinaryView::view(&buffer)?;
-    match view.get_field::<u32>(999) {
+    match view.get_field_copy::<u32>(999) {
         Err(SerializationError::FieldNotFound { .. }) => {
             println!("│ ✓ FieldNotFound error caught");
         }
@@ -711,7 +665,7 @@ fn test_format_validation() -> Result<()> {
     };
 
     let buffer = serialize_user_data(&user)?;
-    let _view = BinaryView::view(&buffer)?;
+    let view = BinaryView::view(&buffer)?;
 
     // Read header directly from buffer (since header field is private)
     use bisere::format::{FormatHeader, HEADER_SIZE, MAGIC, VERSION};
@@ -721,20 +675,15 @@ fn test_format_validation() -> Result<()> {
     let magic = header.magic;
     let version = header.version;
     let header_size_val = header.header_size;
-    let offset_table_size_val = header.offset_table_size;
-    let total_size = header.total_size();
 
     // Verify header fields
     assert_eq!(magic, MAGIC, "Magic number mismatch");
     assert_eq!(version, VERSION, "Version mismatch");
     assert_eq!(header_size_val, HEADER_SIZE as u32, "Header size mismatch");
 
-    println!("│ Magic: {:#x}", magic);
-    println!("│ Version: {}", version);
-    println!("│ Total size: {} bytes", total_size);
-    println!("│ Offset table size: {} bytes ({} entries)", 
-             offset_table_size_val,
-             offset_table_size_val as usize / std::mem::size_of::<OffsetEntry>());
+    for line in view.debug_dump().lines() {
+        println!("│ {}", line);
+    }
 
     Ok(())
 }
@@ -758,15 +707,10 @@ fn test_buffer_layout() -> Result<()> {
     let header_size = header.header_size as usize;
     let offset_table_size = header.offset_table_size as usize;
     let data_size = header.data_size as usize;
-    let var_size = header.var_size as usize;
     let expected_size = header.total_size();
 
     assert_eq!(buffer.len(), expected_size, "Buffer size mismatch");
 
-    println!("│ Header size: {} bytes", header_size);
-    println!("│ Offset table size: {} bytes", offset_table_size);
-    println!("│ Data section size: {} bytes", data_size);
-    println!("│ Variable section size: {} bytes", var_size);
     println!("│ Total: {} bytes", expected_size);
 
     // Verify section offsets
@@ -824,21 +768,21 @@ fn test_all_integer_types() -> Result<()> {
         OffsetEntry { field_id: 7, offset: { offset += 2; offset }, field_type: FieldType::Uint32 as u16, size: 4 },
         OffsetEntry { field_id: 8, offset: { offset += 4; offset }, field_type: FieldType::Uint64 as u16, size: 8 },
     ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries)?;
     serializer.write_data(bytemuck::bytes_of(&data));
     serializer.write_var_data(&[]);
 
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer)?;
 
-    let i8_val = *view.get_field::<i8>(1)?;
-    let i16_val = *view.get_field::<i16>(2)?;
-    let i32_val = *view.get_field::<i32>(3)?;
-    let i64_val = *view.get_field::<i64>(4)?;
-    let u8_val = *view.get_field::<u8>(5)?;
-    let u16_val = *view.get_field::<u16>(6)?;
-    let u32_val = *view.get_field::<u32>(7)?;
-    let u64_val = *view.get_field::<u64>(8)?;
+    let i8_val = view.get_field_copy::<i8>(1)?;
+    let i16_val = view.get_field_copy::<i16>(2)?;
+    let i32_val = view.get_field_copy::<i32>(3)?;
+    let i64_val = view.get_field_copy::<i64>(4)?;
+    let u8_val = view.get_field_copy::<u8>(5)?;
+    let u16_val = view.get_field_copy::<u16>(6)?;
+    let u32_val = view.get_field_copy::<u32>(7)?;
+    let u64_val = view.get_field_copy::<u64>(8)?;
 
     assert_eq!(i8_val, -128);
     assert_eq!(i16_val, -32768);
@@ -887,18 +831,18 @@ fn test_edge_case_values() -> Result<()> {
         OffsetEntry { field_id: 4, offset: { offset += 8; offset }, field_type: FieldType::Float64 as u16, size: 8 },
         OffsetEntry { field_id: 5, offset: { offset += 8; offset }, field_type: FieldType::Float64 as u16, size: 8 },
     ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries)?;
     serializer.write_data(bytemuck::bytes_of(&data));
     serializer.write_var_data(&[]);
 
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer)?;
 
-    let zero_u64 = *view.get_field::<u64>(1)?;
-    let max_u64 = *view.get_field::<u64>(2)?;
-    let min_i64 = *view.get_field::<i64>(3)?;
-    let zero_f64 = *view.get_field::<f64>(4)?;
-    let neg_f64 = *view.get_field::<f64>(5)?;
+    let zero_u64 = view.get_field_copy::<u64>(1)?;
+    let max_u64 = view.get_field_copy::<u64>(2)?;
+    let min_i64 = view.get_field_copy::<i64>(3)?;
+    let zero_f64 = view.get_field_copy::<f64>(4)?;
+    let neg_f64 = view.get_field_copy::<f64>(5)?;
 
     assert_eq!(zero_u64, 0);
     assert_eq!(max_u64, u64::MAX);
@@ -925,7 +869,7 @@ fn test_multiple_strings() -> Result<()> {
         OffsetEntry { field_id: 20, offset: 100, field_type: FieldType::String as u16, size: 200 },
         OffsetEntry { field_id: 30, offset: 300, field_type: FieldType::String as u16, size: 212 },
     ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries)?;
     serializer.write_data(&[]);
 
     let mut var_data = vec![0u8; 512];
@@ -963,7 +907,7 @@ fn test_multiple_blobs() -> Result<()> {
         OffsetEntry { field_id: 22, offset: 50, field_type: FieldType::Blob as u16, size: 100 },
         OffsetEntry { field_id: 33, offset: 150, field_type: FieldType::Blob as u16, size: 362 },
     ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries)?;
     serializer.write_data(&[]);
     serializer.write_var_data(&vec![0u8; 512]);
 
@@ -992,7 +936,7 @@ fn test_empty_strings_blobs() -> Result<()> {
     // Test empty string
     let mut serializer = BinarySerializer::new();
     let header = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
+        std::mem::size_of::<OffsetEntry>() as u32,
         0,
         100,
     );
@@ -1004,9 +948,9 @@ fn test_empty_strings_blobs() -> Result<()> {
         field_type: FieldType::String as u16,
         size: 100,
     }];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries)?;
     serializer.write_data(&[]);
-    serializer.write_var_data(&vec![0u8; 100]);
+    serializer.write_var_data(&[0u8; 100]);
 
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer)?;
@@ -1015,7 +959,7 @@ fn test_empty_strings_blobs() -> Result<()> {
     // Test empty blob
     let mut serializer2 = BinarySerializer::new();
     let header2 = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
+        std::mem::size_of::<OffsetEntry>() as u32,
         0,
         100,
     );
@@ -1027,9 +971,9 @@ fn test_empty_strings_blobs() -> Result<()> {
         field_type: FieldType::Blob as u16,
         size: 100,
     }];
-    serializer2.write_offset_table(&entries2);
+    serializer2.write_offset_table(&entries2)?;
     serializer2.write_data(&[]);
-    serializer2.write_var_data(&vec![0u8; 100]);
+    serializer2.write_var_data(&[0u8; 100]);
 
     let buffer2 = serializer2.into_buffer();
     let view2 = BinaryView::view(&buffer2)?;
@@ -1045,7 +989,7 @@ fn test_empty_strings_blobs() -> Result<()> {
 fn test_unicode_strings() -> Result<()> {
     let mut serializer = BinarySerializer::new();
     let header = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
+        std::mem::size_of::<OffsetEntry>() as u32,
         0,
         256,
     );
@@ -1057,7 +1001,7 @@ fn test_unicode_strings() -> Result<()> {
         field_type: FieldType::String as u16,
         size: 256,
     }];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries)?;
     serializer.write_data(&[]);
 
     let unicode_str = "Hello 世界 🌍";
@@ -1088,7 +1032,7 @@ fn test_non_sequential_field_ids() -> Result<()> {
         OffsetEntry { field_id: 200, offset: { offset += 8; offset }, field_type: FieldType::Uint32 as u16, size: 4 },
         OffsetEntry { field_id: 1, offset: { offset += 4; offset }, field_type: FieldType::Uint64 as u16, size: 8 },
     ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries)?;
 
     let mut data = vec![0u8; data_size as usize];
     data[0..4].copy_from_slice(&100u32.to_le_bytes());
@@ -1101,10 +1045,10 @@ fn test_non_sequential_field_ids() -> Result<()> {
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer)?;
 
-    let v100 = *view.get_field::<u32>(100)?;
-    let v50 = *view.get_field::<u64>(50)?;
-    let v200 = *view.get_field::<u32>(200)?;
-    let v1 = *view.get_field::<u64>(1)?;
+    let v100 = view.get_field_copy::<u32>(100)?;
+    let v50 = view.get_field_copy::<u64>(50)?;
+    let v200 = view.get_field_copy::<u32>(200)?;
+    let v1 = view.get_field_copy::<u64>(1)?;
 
     assert_eq!(v100, 100);
     assert_eq!(v50, 200);
@@ -1127,7 +1071,7 @@ fn test_multiple_modifications() -> Result<()> {
     }
 
     let view = BinaryView::view(&buffer)?;
-    let final_age = *view.get_field::<u32>(2)?;
+    let final_age = view.get_field_copy::<u32>(2)?;
     assert_eq!(final_age, 29);
 
     println!("│ Multiple modifications: final age after 10 changes = {}", final_age);
@@ -1153,7 +1097,7 @@ fn test_many_fields() -> Result<()> {
         });
         offset += 4;
     }
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries)?;
 
     let mut data = vec![0u8; data_size as usize];
     for i in 0..NUM_FIELDS {
@@ -1168,7 +1112,7 @@ fn test_many_fields() -> Result<()> {
 
     let mut all_correct = true;
     for i in 0..NUM_FIELDS {
-        let value = *view.get_field::<u32>(i as u32)?;
+        let value = view.get_field_copy::<u32>(i as u32)?;
         if value != (i * 100) as u32 {
             all_correct = false;
             break;
@@ -1183,7 +1127,7 @@ fn test_many_fields() -> Result<()> {
 fn test_string_boundary_conditions() -> Result<()> {
     let mut serializer = BinarySerializer::new();
     let header = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
+        std::mem::size_of::<OffsetEntry>() as u32,
         0,
         10,
     );
@@ -1195,7 +1139,7 @@ fn test_string_boundary_conditions() -> Result<()> {
         field_type: FieldType::String as u16,
         size: 10,
     }];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries)?;
     serializer.write_data(&[]);
 
     let mut var_data = vec![0u8; 10];
@@ -1213,45 +1157,6 @@ fn test_string_boundary_conditions() -> Result<()> {
 
 // Helper function to serialize UserData
 fn serialize_user_data(user: &UserData) -> Result<Vec<u8>> {
-    let mut serializer = BinarySerializer::new();
-    let offset_table_size = 4 * std::mem::size_of::<OffsetEntry>() as u32;
-    let data_size = std::mem::size_of::<UserData>() as u32;
-    let var_size = 256;
-
-    let header = FormatHeader::new(offset_table_size, data_size, var_size);
-    serializer.write_header(header);
-
-    let mut offset = 0u32;
-    let entries = vec![
-        OffsetEntry {
-            field_id: 1,
-            offset,
-            field_type: FieldType::Uint64 as u16,
-            size: 8,
-        },
-        OffsetEntry {
-            field_id: 2,
-            offset: { offset += 8; offset },
-            field_type: FieldType::Uint32 as u16,
-            size: 4,
-        },
-        OffsetEntry {
-            field_id: 3,
-            offset: { offset += 4; offset },
-            field_type: FieldType::Float64 as u16,
-            size: 8,
-        },
-        OffsetEntry {
-            field_id: 4,
-            offset: { offset += 8; offset },
-            field_type: FieldType::Uint8 as u16,
-            size: 1,
-        },
-    ];
-    serializer.write_offset_table(&entries);
-    serializer.write_data(bytemuck::bytes_of(user));
-    serializer.write_var_data(&vec![0u8; var_size as usize]);
-
-    Ok(serializer.into_buffer())
+    Ok(user.to_bisere())
 }
 
@@ -78,7 +78,7 @@ fn main() -> Result<()> {
             size: 1,
         },
     ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries)?;
     
     // Write data
     let user_bytes = bytemuck::bytes_of(&user);
@@ -93,13 +93,13 @@ fn main() -> Result<()> {
     // 3. Deserialize (zero-copy)
     let view = BinaryView::view(&buffer)?;
     
-    let id: &u64 = view.get_field(1)?;
-    let age: &u32 = view.get_field(2)?;
-    let score: &f64 = view.get_field(3)?;
-    let active: &u8 = view.get_field(4)?;
+    let id: u64 = view.get_field_copy(1)?;
+    let age: u32 = view.get_field_copy(2)?;
+    let score: f64 = view.get_field_copy(3)?;
+    let active: u8 = view.get_field_copy(4)?;
     
     println!("\nDeserialized (zero-copy) - ID: {}, Age: {}, Score: {}, Active: {}", 
-             id, age, score, *active != 0);
+             id, age, score, active != 0);
     
     // 4. In-place modification
     let mut buffer_mut = buffer.clone();
@@ -112,14 +112,14 @@ fn main() -> Result<()> {
     
     // Verify modification
     let view2 = BinaryView::view(&buffer_mut)?;
-    let modified_age: &u32 = view2.get_field(2)?;
+    let modified_age: u32 = view2.get_field_copy(2)?;
     println!("Verified modified age: {}", modified_age);
     
     // 5. String example
     println!("\n=== String Field Example ===");
     let mut serializer2 = BinarySerializer::new();
     let header2 = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
+        std::mem::size_of::<OffsetEntry>() as u32,
         0,
         256,
     );
@@ -131,7 +131,7 @@ fn main() -> Result<()> {
         field_type: FieldType::String as u16,
         size: 256,
     }];
-    serializer2.write_offset_table(&string_entries);
+    serializer2.write_offset_table(&string_entries)?;
     serializer2.write_data(&[]);
     
     let mut var_data = vec![0u8; 256];
@@ -165,7 +165,7 @@ fn main() -> Result<()> {
     }
     
     // Test field not found
-    match view.get_field::<u32>(999) {
+    match view.get_field_copy::<u32>(999) {
         Err(SerializationError::FieldNotFound { field_id }) => {
             println!("Caught FieldNotFound error for field_id: {}", field_id);
         }
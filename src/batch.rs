@@ -0,0 +1,212 @@
+//! Many records sharing one offset table in a single buffer
+
+use crate::error::{Result, SerializationError};
+use crate::format::{Endianness, FieldType, FormatHeader, OffsetEntry, FLAG_BATCH};
+use crate::ops::element_table_area;
+use crate::schema::{check_var, Schema};
+use crate::serializer::{check_unique, value_width, BinarySerializer};
+use crate::value::FieldValue;
+use bytemuck::Pod;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Serializes records of one `Schema` with the offset table stored once
+///
+/// Every record occupies `schema.data_size()` bytes of the data section and
+/// `schema.var_size()` bytes of the var section, so records are located by
+/// stride rather than by per-record tables.
+#[derive(Debug, Clone)]
+pub struct BatchSerializer {
+    schema: Schema,
+    data: Vec<u8>,
+    var_data: Vec<u8>,
+    count: u64,
+}
+
+impl BatchSerializer {
+    pub fn new(schema: Schema) -> Self {
+        Self {
+            schema,
+            data: Vec::new(),
+            var_data: Vec::new(),
+            count: 0,
+        }
+    }
+    
+    /// Append one record; `values` are given in schema entry order
+    ///
+    /// Fixed values must be exactly their entry's size, otherwise
+    /// `FieldSizeMismatch`. The first push also rejects a schema listing an
+    /// id twice (`DuplicateFieldId`).
+    pub fn push(&mut self, values: &[FieldValue]) -> Result<()> {
+        let entries = self.schema.entries();
+        if self.count == 0 {
+            check_unique(entries)?;
+        }
+        if values.len() != entries.len() {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: entries.len(),
+                got: values.len(),
+            });
+        }
+        
+        let mut data = vec![0u8; self.schema.data_size() as usize];
+        let mut var_data = vec![0u8; self.schema.var_size() as usize];
+        
+        for (entry, value) in entries.iter().zip(values) {
+            let field_type = value.field_type();
+            if entry.field_type != field_type as u16 {
+                return Err(SerializationError::FieldTypeMismatch {
+                    field_id: entry.field_id,
+                    expected: entry.field_type,
+                    found: field_type as u16,
+                });
+            }
+            
+            let bytes = value.as_bytes();
+            let section = if field_type.is_variable() {
+                check_var(field_type, &bytes, entry.size, true)?;
+                &mut var_data
+            } else if bytes.len() != entry.size as usize {
+                return Err(SerializationError::FieldSizeMismatch {
+                    expected: entry.size as usize,
+                    got: bytes.len(),
+                });
+            } else {
+                &mut data
+            };
+            let start = entry.offset as usize;
+            section[start..start + bytes.len()].copy_from_slice(&bytes);
+        }
+        
+        self.data.extend_from_slice(&data);
+        self.var_data.extend_from_slice(&var_data);
+        self.count += 1;
+        Ok(())
+    }
+    
+    /// Number of records pushed so far
+    pub fn len(&self) -> usize {
+        self.count as usize
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+    
+    /// Write a `FLAG_BATCH` header carrying the record count, then the shared
+    /// table, the concatenated record sections and the schema's `Array`
+    /// element types
+    pub fn finish(self) -> Vec<u8> {
+        let mut header = FormatHeader::new(
+            self.schema.offset_table_size(),
+            self.data.len() as u32,
+            self.var_data.len() as u32,
+        );
+        header.set_flag(FLAG_BATCH);
+        header.set_record_count(self.count);
+        let extension_area = element_table_area(&mut header, self.schema.array_elements());
+        
+        let mut serializer = BinarySerializer::new();
+        serializer.write_header(header);
+        serializer.write_offset_table_unchecked(self.schema.entries());
+        serializer.write_data(&self.data);
+        serializer.write_var_data(&self.var_data);
+        serializer.write_extension(&extension_area);
+        serializer.into_buffer()
+    }
+}
+
+/// One record of a batch buffer, read through the shared offset table
+#[derive(Debug, Clone, Copy)]
+pub struct RecordView<'a> {
+    offset_table: &'a [OffsetEntry],
+    endianness: Endianness,
+    element_table: Option<&'a [u8]>,
+    data: &'a [u8],
+    var_data: &'a [u8],
+}
+
+impl<'a> RecordView<'a> {
+    pub(crate) fn new(
+        offset_table: &'a [OffsetEntry],
+        endianness: Endianness,
+        element_table: Option<&'a [u8]>,
+        data: &'a [u8],
+        var_data: &'a [u8],
+    ) -> Self {
+        Self {
+            offset_table,
+            endianness,
+            element_table,
+            data,
+            var_data,
+        }
+    }
+    
+    /// This record's slices of the data and var sections
+    pub(crate) fn sections(&self) -> (&'a [u8], &'a [u8]) {
+        (self.data, self.var_data)
+    }
+    
+    pub fn find_entry(&self, field_id: u32) -> Option<&'a OffsetEntry> {
+        self.offset_table.iter().find(|e| e.field_id == field_id)
+    }
+    
+    /// A field's full stored bytes within this record
+    pub fn field_bytes(&self, field_id: u32) -> Result<&'a [u8]> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        
+        let section = if entry.is_variable() { self.var_data } else { self.data };
+        let start = entry.offset as usize;
+        let end = start + entry.size as usize;
+        section.get(start..end).ok_or(SerializationError::InvalidOffset {
+            offset: end,
+            size: section.len(),
+        })
+    }
+    
+    /// Read a fixed-size field by value, in host byte order
+    pub fn get_field_copy<T: Pod>(&self, field_id: u32) -> Result<T> {
+        let bytes = self.field_bytes(field_id)?;
+        if bytes.len() != core::mem::size_of::<T>() {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: bytes.len(),
+                got: core::mem::size_of::<T>(),
+            });
+        }
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        let width = value_width(entry, self.element_table);
+        Ok(entry.convert_value(bytemuck::pod_read_unaligned(bytes), width, self.endianness, Endianness::native()))
+    }
+    
+    /// String field up to its NUL terminator (or the end of its reservation)
+    pub fn get_string(&self, field_id: u32) -> Result<&'a str> {
+        let bytes = self.var_bytes(field_id, FieldType::String)?;
+        let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        core::str::from_utf8(&bytes[..end]).map_err(|_| SerializationError::FieldSizeMismatch {
+            expected: 0,
+            got: 0,
+        })
+    }
+    
+    /// Blob field's full reservation
+    pub fn get_blob(&self, field_id: u32) -> Result<&'a [u8]> {
+        self.var_bytes(field_id, FieldType::Blob)
+    }
+    
+    fn var_bytes(&self, field_id: u32, expected: FieldType) -> Result<&'a [u8]> {
+        let field_type = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?
+            .field_type;
+        if field_type != expected as u16 {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: expected as usize,
+                got: field_type as usize,
+            });
+        }
+        self.field_bytes(field_id)
+    }
+}
@@ -0,0 +1,166 @@
+//! Checksum algorithms used for buffer integrity checks
+
+/// Starting state for `fnv1a64_update`
+pub const FNV1A64_INIT: u64 = 0xcbf2_9ce4_8422_2325;
+
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a hash
+pub fn fnv1a64(bytes: &[u8]) -> u64 {
+    fnv1a64_update(FNV1A64_INIT, bytes)
+}
+
+/// Continue an FNV-1a hash over more bytes, for hashing non-contiguous input
+pub fn fnv1a64_update(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+const CRC32_POLY: u32 = 0xEDB8_8320;
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ CRC32_POLY } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// CRC-32 (IEEE 802.3, as used by zlib and PNG)
+pub fn crc32(bytes: &[u8]) -> u32 {
+    crc32_update(0, bytes)
+}
+
+/// Continue a CRC-32 from a previous result over more bytes
+///
+/// With the `crc32fast` feature this uses SIMD (PCLMULQDQ on x86, the CRC
+/// instructions on AArch64) where the CPU supports it; the result is always
+/// identical to `crc32_scalar_update`.
+pub fn crc32_update(crc: u32, bytes: &[u8]) -> u32 {
+    #[cfg(feature = "crc32fast")]
+    {
+        let mut hasher = crc32fast::Hasher::new_with_initial(crc);
+        hasher.update(bytes);
+        hasher.finalize()
+    }
+    #[cfg(not(feature = "crc32fast"))]
+    {
+        crc32_scalar_update(crc, bytes)
+    }
+}
+
+/// Portable table-driven CRC-32, regardless of features
+pub fn crc32_scalar(bytes: &[u8]) -> u32 {
+    crc32_scalar_update(0, bytes)
+}
+
+/// Table-driven `crc32_update`
+pub fn crc32_scalar_update(crc: u32, bytes: &[u8]) -> u32 {
+    let mut crc = !crc;
+    for &byte in bytes {
+        crc = CRC32_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+const CRC32C_POLY: u32 = 0x82F6_3B78;
+
+const CRC32C_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ CRC32C_POLY } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// CRC-32C (Castagnoli, as used by iSCSI, ext4 and many storage formats)
+pub fn crc32c(bytes: &[u8]) -> u32 {
+    crc32c_update(0, bytes)
+}
+
+/// Continue a CRC-32C from a previous result over more bytes
+///
+/// With `std`, uses the SSE4.2 `crc32` instruction on x86-64 CPUs that have
+/// it, detected at runtime; the result is always identical to
+/// `crc32c_scalar_update`.
+pub fn crc32c_update(crc: u32, bytes: &[u8]) -> u32 {
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    {
+        if std::arch::is_x86_feature_detected!("sse4.2") {
+            // SAFETY: the CPU supports SSE4.2, checked just above
+            return unsafe { crc32c_sse42_update(crc, bytes) };
+        }
+    }
+    crc32c_scalar_update(crc, bytes)
+}
+
+/// Portable table-driven CRC-32C, regardless of CPU
+pub fn crc32c_scalar(bytes: &[u8]) -> u32 {
+    crc32c_scalar_update(0, bytes)
+}
+
+/// Table-driven `crc32c_update`
+pub fn crc32c_scalar_update(crc: u32, bytes: &[u8]) -> u32 {
+    let mut crc = !crc;
+    for &byte in bytes {
+        crc = CRC32C_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+#[target_feature(enable = "sse4.2")]
+unsafe fn crc32c_sse42_update(crc: u32, bytes: &[u8]) -> u32 {
+    use core::arch::x86_64::{_mm_crc32_u64, _mm_crc32_u8};
+    
+    let mut chunks = bytes.chunks_exact(8);
+    let mut crc = !crc as u64;
+    for chunk in &mut chunks {
+        crc = _mm_crc32_u64(crc, u64::from_le_bytes(chunk.try_into().unwrap()));
+    }
+    let mut crc = crc as u32;
+    for &byte in chunks.remainder() {
+        crc = _mm_crc32_u8(crc, byte);
+    }
+    !crc
+}
+
+/// Algorithm of a buffer's whole-buffer checksum
+///
+/// 32-bit CRCs are zero-extended into the header's 64-bit `checksum` slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumAlgo {
+    Fnv1a64,
+    Crc32,
+    Crc32c,
+}
+
+impl ChecksumAlgo {
+    /// Checksum of `parts` as if concatenated, widened to `u64`
+    pub fn checksum(self, parts: &[&[u8]]) -> u64 {
+        match self {
+            ChecksumAlgo::Fnv1a64 => parts.iter().fold(FNV1A64_INIT, |hash, part| fnv1a64_update(hash, part)),
+            ChecksumAlgo::Crc32 => parts.iter().fold(0, |crc, part| crc32_update(crc, part)) as u64,
+            ChecksumAlgo::Crc32c => parts.iter().fold(0, |crc, part| crc32c_update(crc, part)) as u64,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! Codecs for the var section written by `BinarySerializer::with_var_compression`
+
+use crate::error::{Result, SerializationError};
+use alloc::vec::Vec;
+
+/// How `BinarySerializer::finish` compresses the var section
+///
+/// The codec is recorded as a header flag (`FLAG_VAR_ZSTD`); the level only
+/// matters when writing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Codec {
+    /// Zstandard at `level` (1 to 22, or 0 for zstd's default)
+    Zstd { level: i32 },
+}
+
+impl Codec {
+    pub(crate) fn compress(self, data: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            Codec::Zstd { level } => Ok(zstd::bulk::compress(data, level)?),
+        }
+    }
+}
+
+/// Decompress a zstd var section, which must come out exactly `size` bytes
+pub(crate) fn decompress(data: &[u8], size: usize) -> Result<Vec<u8>> {
+    let decompressed = zstd::bulk::decompress(data, size)?;
+    if decompressed.len() != size {
+        return Err(SerializationError::LengthMismatch {
+            expected: size,
+            got: decompressed.len(),
+        });
+    }
+    Ok(decompressed)
+}
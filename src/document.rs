@@ -0,0 +1,182 @@
+//! Owned buffer whose var fields grow on demand
+
+use crate::error::{Result, SerializationError};
+use crate::format::{
+    Endianness, FieldType, FormatHeader, OffsetEntry, FLAG_ALIGNED_LAYOUT, FLAG_BATCH, FLAG_SORTED_TABLE,
+};
+use crate::schema::check_fixed;
+use crate::serializer::{BinaryView, BinaryViewMut};
+use alloc::vec::Vec;
+
+/// A serialized buffer held by value, so var fields can outgrow their reservation
+///
+/// `BinaryViewMut::modify_string` and `modify_blob` fail with
+/// `FieldSizeMismatch` once a value no longer fits its slot. `set_string` and
+/// `set_blob` instead widen the slot to exactly what the value needs: var data
+/// after the field shifts down, and `var_size` and the later var offsets are
+/// updated. Fixed fields never move. Growing the last var field only moves
+/// the extension area; growing an earlier one also moves the var data behind it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedDocument {
+    buffer: Vec<u8>,
+}
+
+impl OwnedDocument {
+    /// Take ownership of a buffer that `BinaryView::view` accepts
+    pub fn new(buffer: Vec<u8>) -> Result<Self> {
+        BinaryView::view(&buffer)?;
+        Ok(Self { buffer })
+    }
+    
+    pub fn view(&self) -> Result<BinaryView<'_>> {
+        BinaryView::view(&self.buffer)
+    }
+    
+    /// In-place access for edits that fit; these never grow a field
+    pub fn view_mut(&mut self) -> Result<BinaryViewMut<'_>> {
+        BinaryViewMut::view_mut(&mut self.buffer)
+    }
+    
+    /// Store `value` in a string field, growing its reservation if needed
+    ///
+    /// The field's CRC and a stored whole-buffer checksum are kept current.
+    pub fn set_string(&mut self, field_id: u32, value: &str) -> Result<()> {
+        match self.view_mut()?.modify_string(field_id, value) {
+            Err(SerializationError::FieldSizeMismatch { got, .. }) if self.is_var(field_id, FieldType::String)? => {
+                self.grow(field_id, got)?;
+                self.view_mut()?.modify_string(field_id, value)?;
+            }
+            result => result?,
+        }
+        self.refresh(field_id)
+    }
+    
+    /// Store `value` in a blob field, growing its reservation if needed
+    ///
+    /// The field's CRC and a stored whole-buffer checksum are kept current.
+    pub fn set_blob(&mut self, field_id: u32, value: &[u8]) -> Result<()> {
+        match self.view_mut()?.modify_blob(field_id, value) {
+            Err(SerializationError::FieldSizeMismatch { got, .. }) if self.is_var(field_id, FieldType::Blob)? => {
+                self.grow(field_id, got)?;
+                self.view_mut()?.modify_blob(field_id, value)?;
+            }
+            result => result?,
+        }
+        self.refresh(field_id)
+    }
+    
+    /// Add a fixed-size field at the end of the data section
+    ///
+    /// `value` is little-endian, as for `IncrementalSerializer::push_fixed`, and
+    /// is converted if the buffer stores the other byte order. The new entry
+    /// grows the offset table, so the data, var and extension sections all
+    /// move down by one entry; their relative offsets don't change. A sorted
+    /// table keeps its order. Since every field moves, `FLAG_ALIGNED_LAYOUT`
+    /// no longer holds and is cleared. An id already in the table fails with
+    /// `DuplicateFieldId`, and a batch buffer, whose records would each need
+    /// the value, with `BatchBuffer`.
+    pub fn append_fixed_field(&mut self, field_id: u32, field_type: FieldType, value: &[u8]) -> Result<()> {
+        let view = self.view()?;
+        if view.has_field(field_id) {
+            return Err(SerializationError::DuplicateFieldId { field_id });
+        }
+        let size = check_fixed(field_type, value)?;
+        let endianness = view.endianness();
+        let mut header = FormatHeader::read_from(&self.buffer)?;
+        if header.has_flag(FLAG_BATCH) {
+            return Err(SerializationError::BatchBuffer);
+        }
+        
+        let entry = OffsetEntry {
+            field_id,
+            offset: header.data_size,
+            field_type: field_type as u16,
+            size,
+        };
+        let value = entry.convert_bytes(value, size as usize, Endianness::Little, endianness).into_owned();
+        
+        // Value first: splicing the entry in afterwards would shift the data end
+        let data_end = header.var_section_offset();
+        self.buffer.splice(data_end..data_end, value);
+        
+        let table_start = header.header_size as usize;
+        let table_end = table_start + header.offset_table_size as usize;
+        let entries = bytemuck::cast_slice::<u8, OffsetEntry>(&self.buffer[table_start..table_end]);
+        let index = if header.has_flag(FLAG_SORTED_TABLE) {
+            entries.partition_point(|existing| existing.field_id < field_id)
+        } else {
+            entries.len()
+        };
+        let insert_at = table_start + index * core::mem::size_of::<OffsetEntry>();
+        self.buffer.splice(insert_at..insert_at, bytemuck::bytes_of(&entry).iter().copied());
+        
+        header.offset_table_size += core::mem::size_of::<OffsetEntry>() as u32;
+        header.data_size += size as u32;
+        header.clear_flag(FLAG_ALIGNED_LAYOUT);
+        if let Some(count) = header.entry_count() {
+            header.set_entry_count(count + 1);
+        }
+        header.write_to(&mut self.buffer);
+        self.refresh(field_id)
+    }
+    
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer
+    }
+    
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buffer
+    }
+    
+    fn is_var(&self, field_id: u32, field_type: FieldType) -> Result<bool> {
+        let view = self.view()?;
+        Ok(view.find_entry(field_id).is_some_and(|entry| entry.field_type == field_type as u16))
+    }
+    
+    /// Widen a var field's reservation to `needed` bytes, shifting the var data after it
+    ///
+    /// Records of a batch share the offset table, so widening one record's
+    /// field would misplace every other record: `BatchBuffer`.
+    fn grow(&mut self, field_id: u32, needed: usize) -> Result<()> {
+        let new_size = u16::try_from(needed).map_err(|_| SerializationError::FieldTooLarge {
+            field_id,
+            requested: needed,
+        })?;
+        let mut header = FormatHeader::read_from(&self.buffer)?;
+        if header.has_flag(FLAG_BATCH) {
+            return Err(SerializationError::BatchBuffer);
+        }
+        let target = *self.view()?
+            .find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        let extra = (new_size - target.size) as usize;
+        let field_end = target.offset as usize + target.size as usize;
+        
+        let insert_at = header.var_section_offset() + field_end;
+        self.buffer.splice(insert_at..insert_at, core::iter::repeat_n(0, extra));
+        
+        header.var_size += extra as u32;
+        header.write_to(&mut self.buffer);
+        let table_start = header.header_size as usize;
+        let table_end = table_start + header.offset_table_size as usize;
+        let entries = bytemuck::cast_slice_mut::<u8, OffsetEntry>(&mut self.buffer[table_start..table_end]);
+        for entry in entries {
+            if entry.field_id == field_id {
+                entry.size = new_size;
+            } else if entry.is_variable() && entry.offset as usize >= field_end {
+                entry.offset += extra as u32;
+            }
+        }
+        Ok(())
+    }
+    
+    fn refresh(&mut self, field_id: u32) -> Result<()> {
+        let had_checksum = self.view()?.has_checksum();
+        let mut view = self.view_mut()?;
+        view.update_field_crc(field_id)?;
+        if had_checksum {
+            view.store_checksum()?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+//! Reusable in-place editor for many buffers sharing one schema
+
+use crate::error::{Result, SerializationError};
+use crate::format::{Endianness, FieldType, FormatHeader, HEADER_SIZE};
+use crate::schema::Schema;
+use bytemuck::Pod;
+use alloc::vec::Vec;
+
+/// Field writer prepared once from a schema and bound to one buffer at a time
+///
+/// `BinaryViewMut::view_mut` re-parses and re-checks the offset table of
+/// every buffer. When all buffers share a layout, `bind` instead checks that
+/// the header sizes match and the stored table equals the schema's byte for
+/// byte (a single comparison), then writes through the schema's offsets.
+#[derive(Debug, Clone)]
+pub struct MutEditor {
+    schema: Schema,
+    table: Vec<u8>,
+}
+
+/// A `MutEditor` bound to one buffer, from `MutEditor::bind`
+pub struct BoundEditor<'a> {
+    schema: &'a Schema,
+    buffer: &'a mut [u8],
+    data_start: usize,
+    endianness: Endianness,
+}
+
+impl MutEditor {
+    pub fn new(schema: Schema) -> Self {
+        let table = bytemuck::cast_slice(schema.entries()).to_vec();
+        Self { schema, table }
+    }
+    
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+    
+    /// Check `buffer` against the schema and prepare to edit it
+    ///
+    /// Fails with the usual header errors, `BufferTooSmall`, or
+    /// `SchemaMismatch` when the section sizes or offset table differ from the
+    /// schema's.
+    pub fn bind<'a>(&'a self, buffer: &'a mut [u8]) -> Result<BoundEditor<'a>> {
+        let header = FormatHeader::read_from(buffer)?;
+        header.validate()?;
+        
+        let total_size = header.total_size();
+        if buffer.len() < total_size {
+            return Err(SerializationError::BufferTooSmall {
+                needed: total_size,
+                have: buffer.len(),
+            });
+        }
+        
+        let (offset_table_size, data_size, var_size) = (header.offset_table_size, header.data_size, header.var_size);
+        let table_start = header.header_size as usize;
+        if header.header_size as usize != HEADER_SIZE
+            || offset_table_size != self.schema.offset_table_size()
+            || data_size != self.schema.data_size()
+            || var_size != self.schema.var_size()
+            || buffer[table_start..table_start + self.table.len()] != self.table[..]
+        {
+            return Err(SerializationError::SchemaMismatch);
+        }
+        
+        Ok(BoundEditor {
+            schema: &self.schema,
+            buffer,
+            data_start: header.data_section_offset(),
+            endianness: header.endianness(),
+        })
+    }
+}
+
+impl BoundEditor<'_> {
+    /// Start of a fixed field's bytes and the width of each value it holds
+    fn fixed_start<T: Pod>(&self, field_id: u32) -> Result<(usize, usize)> {
+        let entry = self.schema.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        
+        let value_size = core::mem::size_of::<T>();
+        if entry.is_variable() || value_size != entry.size as usize {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: entry.size as usize,
+                got: value_size,
+            });
+        }
+        
+        let width = if entry.field_type == FieldType::Array as u16 {
+            self.schema.array_element_type(field_id).and_then(FieldType::fixed_size).unwrap_or(1)
+        } else {
+            value_size
+        };
+        Ok((self.data_start + entry.offset as usize, width))
+    }
+    
+    /// Overwrite a fixed-size field, as `BinaryViewMut::modify_field` does
+    pub fn set<T: Pod>(&mut self, field_id: u32, value: &T) -> Result<()> {
+        let (start, width) = self.fixed_start::<T>(field_id)?;
+        let value = self.swap(*value, width);
+        self.buffer[start..start + core::mem::size_of::<T>()].copy_from_slice(bytemuck::bytes_of(&value));
+        Ok(())
+    }
+    
+    /// Read a fixed-size field by value
+    pub fn get<T: Pod>(&self, field_id: u32) -> Result<T> {
+        let (start, width) = self.fixed_start::<T>(field_id)?;
+        Ok(self.swap(bytemuck::pod_read_unaligned(&self.buffer[start..start + core::mem::size_of::<T>()]), width))
+    }
+    
+    /// Convert a fixed value between host order and the buffer's (an involution)
+    ///
+    /// Each `width`-byte value is reversed on its own, so an array field's
+    /// elements keep their order.
+    fn swap<T: Pod>(&self, mut value: T, width: usize) -> T {
+        if self.endianness != Endianness::native() && width >= 2 {
+            for element in bytemuck::bytes_of_mut(&mut value).chunks_exact_mut(width) {
+                element.reverse();
+            }
+        }
+        value
+    }
+}
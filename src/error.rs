@@ -1,24 +1,120 @@
+//! Errors returned across the crate
+//!
+//! With the `std` feature the error implements `std::error::Error` and
+//! `Display` through `thiserror`; without it, it is a plain `Debug` enum.
+
+#[cfg(feature = "std")]
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[derive(Debug)]
+#[cfg_attr(feature = "std", derive(Error))]
 pub enum SerializationError {
-    #[error("Invalid magic number: expected {expected:#x}, found {found:#x}")]
+    #[cfg_attr(feature = "std", error("Invalid magic number: expected {expected:#x}, found {found:#x}"))]
     InvalidMagic { expected: u32, found: u32 },
     
-    #[error("Unsupported format version: {version}")]
+    #[cfg_attr(feature = "std", error("Unsupported format version: {version}"))]
     UnsupportedVersion { version: u32 },
     
-    #[error("Field not found: {field_id}")]
+    #[cfg_attr(feature = "std", error("Field not found: {field_id}"))]
     FieldNotFound { field_id: u32 },
     
-    #[error("Field size mismatch: expected {expected}, got {got}")]
+    #[cfg_attr(feature = "std", error("Field size mismatch: expected {expected}, got {got}"))]
     FieldSizeMismatch { expected: usize, got: usize },
     
-    #[error("Buffer too small: need {needed} bytes, have {have}")]
+    #[cfg_attr(feature = "std", error("Buffer too small: need {needed} bytes, have {have}"))]
     BufferTooSmall { needed: usize, have: usize },
     
-    #[error("Invalid offset: {offset} exceeds buffer size {size}")]
+    #[cfg_attr(feature = "std", error("Invalid offset: {offset} exceeds buffer size {size}"))]
     InvalidOffset { offset: usize, size: usize },
+    
+    #[cfg_attr(feature = "std", error("Field {field_id} too large: requested {requested} bytes, max {}", u16::MAX))]
+    FieldTooLarge { field_id: u32, requested: usize },
+    
+    #[cfg_attr(feature = "std", error("Missing field: {field_id}"))]
+    MissingField { field_id: u32 },
+    
+    #[cfg_attr(feature = "std", error("Field {field_id} type mismatch: expected {expected}, found {found}"))]
+    FieldTypeMismatch { field_id: u32, expected: u16, found: u16 },
+    
+    #[cfg_attr(feature = "std", error("Unexpected field: {field_id}"))]
+    UnexpectedField { field_id: u32 },
+    
+    #[cfg_attr(feature = "std", error("Misaligned access: offset {offset} is not aligned to {align} bytes"))]
+    MisalignedAccess { offset: usize, align: usize },
+    
+    #[cfg_attr(feature = "std", error("Field {field_id} lies outside its section"))]
+    MisplacedField { field_id: u32 },
+    
+    #[cfg_attr(feature = "std", error("Checksum mismatch: expected {expected:#x}, found {found:#x}"))]
+    ChecksumMismatch { expected: u64, found: u64 },
+    
+    #[cfg_attr(feature = "std", error("Duplicate field id: {field_id}"))]
+    DuplicateFieldId { field_id: u32 },
+    
+    #[cfg_attr(feature = "std", error("Unknown field type: {value}"))]
+    UnknownFieldType { value: u16 },
+    
+    #[cfg_attr(feature = "std", error("Buffer is not a delta"))]
+    NotADelta,
+    
+    #[cfg_attr(feature = "std", error("Record {index} out of range: buffer holds {count} records"))]
+    RecordOutOfRange { index: usize, count: usize },
+    
+    #[cfg_attr(feature = "std", error("Inconsistent header: offset_table_size {offset_table_size} does not hold {entry_count} entries"))]
+    InconsistentHeader { offset_table_size: u32, entry_count: u32 },
+    
+    #[cfg_attr(feature = "std", error("Buffer has no offset table; use view_with_schema"))]
+    ExternalSchemaRequired,
+    
+    #[cfg_attr(feature = "std", error("Fields {first} and {second} overlap"))]
+    OverlappingFields { first: u32, second: u32 },
+    
+    #[cfg_attr(feature = "std", error("Value not in the enum dictionary of field {field_id}"))]
+    UnknownEnumValue { field_id: u32 },
+    
+    #[cfg_attr(feature = "std", error("Buffer is not signed"))]
+    SignatureMissing,
+    
+    #[cfg_attr(feature = "std", error("Signature does not match the buffer contents"))]
+    SignatureMismatch,
+    
+    #[cfg_attr(feature = "std", error("A whole-buffer checksum can't be combined with a signature"))]
+    ChecksumWithSignature,
+    
+    #[cfg_attr(feature = "std", error("Buffer length {got} does not match the header's total size {expected}"))]
+    LengthMismatch { expected: usize, got: usize },
+    
+    #[cfg_attr(feature = "std", error("Buffer layout does not match the editor's schema"))]
+    SchemaMismatch,
+    
+    #[cfg_attr(feature = "std", error("Field {field_id} holds a NaN or infinite value"))]
+    NonFiniteFloat { field_id: u32 },
+    
+    #[cfg_attr(feature = "std", error("Reader for field {field_id} produced more than {reserve} bytes"))]
+    SourceTooLarge { field_id: u32, reserve: usize },
+    
+    #[cfg_attr(feature = "std", error("Field {field_id} is stored in non-native byte order; read it by value"))]
+    ForeignByteOrder { field_id: u32 },
+    
+    #[cfg_attr(feature = "std", error("Offset table is flagged sorted but field {field_id} is out of order"))]
+    UnsortedTable { field_id: u32 },
+    
+    #[cfg_attr(feature = "std", error("Fields from {field_id} on are not equally laid out records"))]
+    NonUniformRecords { field_id: u32 },
+    
+    #[cfg_attr(feature = "std", error("Var section is compressed; read var fields with get_string or get_blob_cow"))]
+    CompressedVarSection,
+    
+    #[cfg_attr(feature = "std", error("Batch buffers can't be restructured; split the batch first"))]
+    BatchBuffer,
+    
+    #[cfg(feature = "serde")]
+    #[error("serde: {message}")]
+    Serde { message: alloc::string::String },
+    
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "std", error("I/O error: {0}"))]
+    Io(#[from] std::io::Error),
 }
 
-pub type Result<T> = std::result::Result<T, SerializationError>;
+pub type Result<T> = core::result::Result<T, SerializationError>;
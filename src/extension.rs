@@ -0,0 +1,220 @@
+//! Optional tagged sections stored after the var section
+//!
+//! The extension area is a sequence of `(tag: u32, len: u32, payload)`
+//! records. Each kind of section also has a header flag so readers can
+//! check for it without scanning.
+
+use core::ops::Range;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Tag of the field-id-to-name table
+pub(crate) const TAG_FIELD_NAMES: u32 = 1;
+/// Tag of the per-field CRC-32 table
+pub(crate) const TAG_FIELD_CRCS: u32 = 2;
+/// Tag of the `Enum` field value dictionaries
+pub(crate) const TAG_ENUM_DICTIONARY: u32 = 3;
+/// Tag of the string field length table
+pub(crate) const TAG_STRING_LENGTHS: u32 = 4;
+// Tag 5 is the HMAC signature, defined in `signature` behind the `hmac` feature
+/// Tag of the field default value table
+pub(crate) const TAG_FIELD_DEFAULTS: u32 = 6;
+/// Tag of the application key-value metadata section
+pub(crate) const TAG_METADATA: u32 = 7;
+/// Tag of the `Array` field element type table
+pub(crate) const TAG_ARRAY_ELEMENTS: u32 = 8;
+/// Tag of the blob field length table
+pub(crate) const TAG_BLOB_LENGTHS: u32 = 9;
+
+/// Append a section record to `out`
+pub(crate) fn write_section(out: &mut Vec<u8>, tag: u32, payload: &[u8]) {
+    out.extend_from_slice(&tag.to_le_bytes());
+    out.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    out.extend_from_slice(payload);
+}
+
+/// Find a section's payload by tag; malformed records end the search
+pub(crate) fn find_section(area: &[u8], tag: u32) -> Option<&[u8]> {
+    find_section_range(area, tag).map(|range| &area[range])
+}
+
+/// Byte range of a section's payload within `area`
+pub(crate) fn find_section_range(area: &[u8], tag: u32) -> Option<Range<usize>> {
+    let mut start = 0;
+    while area.len() - start >= 8 {
+        let section_tag = u32::from_le_bytes(area[start..start + 4].try_into().unwrap());
+        let len = u32::from_le_bytes(area[start + 4..start + 8].try_into().unwrap()) as usize;
+        let payload = start + 8..start + 8 + len;
+        area.get(payload.clone())?;
+        if section_tag == tag {
+            return Some(payload);
+        }
+        start = payload.end;
+    }
+    None
+}
+
+/// Encode `(field_id: u32, len: u16, utf8 bytes)` records
+pub(crate) fn encode_names(names: &[(u32, String)]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for (field_id, name) in names {
+        out.extend_from_slice(&field_id.to_le_bytes());
+        out.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        out.extend_from_slice(name.as_bytes());
+    }
+    out
+}
+
+/// Look up a field's name in an encoded name table
+pub(crate) fn lookup_name(table: &[u8], field_id: u32) -> Option<&str> {
+    let mut rest = table;
+    while rest.len() >= 6 {
+        let id = u32::from_le_bytes(rest[0..4].try_into().unwrap());
+        let len = u16::from_le_bytes(rest[4..6].try_into().unwrap()) as usize;
+        let name = rest.get(6..6 + len)?;
+        if id == field_id {
+            return core::str::from_utf8(name).ok();
+        }
+        rest = &rest[6 + len..];
+    }
+    None
+}
+
+/// Encode `(field_id: u32, len: u16, bytes)` default value records
+pub(crate) fn encode_defaults(defaults: &[(u32, Vec<u8>)]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for (field_id, bytes) in defaults {
+        out.extend_from_slice(&field_id.to_le_bytes());
+        out.extend_from_slice(&(bytes.len() as u16).to_le_bytes());
+        out.extend_from_slice(bytes);
+    }
+    out
+}
+
+/// Look up a field's default bytes in an encoded default table
+pub(crate) fn lookup_default(table: &[u8], field_id: u32) -> Option<&[u8]> {
+    let mut rest = table;
+    while rest.len() >= 6 {
+        let id = u32::from_le_bytes(rest[0..4].try_into().unwrap());
+        let len = u16::from_le_bytes(rest[4..6].try_into().unwrap()) as usize;
+        let bytes = rest.get(6..6 + len)?;
+        if id == field_id {
+            return Some(bytes);
+        }
+        rest = &rest[6 + len..];
+    }
+    None
+}
+
+/// Encode `(key_len: u16, key, value_len: u16, value)` utf8 records
+pub(crate) fn encode_metadata(pairs: &[(String, String)]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for (key, value) in pairs {
+        for text in [key, value] {
+            out.extend_from_slice(&(text.len() as u16).to_le_bytes());
+            out.extend_from_slice(text.as_bytes());
+        }
+    }
+    out
+}
+
+/// Decode metadata pairs in stored order; malformed data ends the iteration
+pub(crate) fn metadata_pairs(section: &[u8]) -> impl Iterator<Item = (&str, &str)> {
+    fn take<'a>(rest: &mut &'a [u8]) -> Option<&'a str> {
+        let len = u16::from_le_bytes(rest.get(0..2)?.try_into().unwrap()) as usize;
+        let text = core::str::from_utf8(rest.get(2..2 + len)?).ok()?;
+        *rest = &rest[2 + len..];
+        Some(text)
+    }
+    let mut rest = section;
+    core::iter::from_fn(move || Some((take(&mut rest)?, take(&mut rest)?)))
+}
+
+/// Encode `(field_id: u32, value: u32)` records, as used by the CRC, string length and array element tables
+pub(crate) fn encode_id_values(values: &[(u32, u32)]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(values.len() * 8);
+    for (field_id, value) in values {
+        out.extend_from_slice(&field_id.to_le_bytes());
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+    out
+}
+
+/// Offset of a field's value within an encoded `(field_id, value)` table
+pub(crate) fn value_position(table: &[u8], field_id: u32) -> Option<usize> {
+    table
+        .chunks_exact(8)
+        .position(|record| u32::from_le_bytes(record[0..4].try_into().unwrap()) == field_id)
+        .map(|index| index * 8 + 4)
+}
+
+/// Offset of a field's record id within a section keyed by field id
+///
+/// Walks the record layout of `tag`; `None` for sections not keyed by field
+/// id, when the field has no record, or once a malformed record is reached.
+pub(crate) fn id_position(tag: u32, table: &[u8], field_id: u32) -> Option<usize> {
+    let len_width = match tag {
+        TAG_FIELD_CRCS | TAG_STRING_LENGTHS | TAG_ARRAY_ELEMENTS | TAG_BLOB_LENGTHS => {
+            return value_position(table, field_id).map(|position| position - 4);
+        }
+        TAG_FIELD_NAMES | TAG_FIELD_DEFAULTS => 2,
+        TAG_ENUM_DICTIONARY => 4,
+        _ => return None,
+    };
+    let mut start = 0;
+    while table.len().saturating_sub(start) >= 4 + len_width {
+        let id = u32::from_le_bytes(table[start..start + 4].try_into().unwrap());
+        let mut len = [0u8; 4];
+        len[..len_width].copy_from_slice(&table[start + 4..start + 4 + len_width]);
+        if id == field_id {
+            return Some(start);
+        }
+        start += 4 + len_width + u32::from_le_bytes(len) as usize;
+    }
+    None
+}
+
+/// A field's value in an encoded `(field_id, value)` table
+pub(crate) fn lookup_value(table: &[u8], field_id: u32) -> Option<u32> {
+    let position = value_position(table, field_id)?;
+    Some(u32::from_le_bytes(table[position..position + 4].try_into().unwrap()))
+}
+
+/// Encode `(field_id: u32, len: u32, values)` records, each value a `(len: u16, utf8 bytes)` pair
+pub(crate) fn encode_dictionaries(dictionaries: &[(u32, Vec<String>)]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for (field_id, values) in dictionaries {
+        let mut record = Vec::new();
+        for value in values {
+            record.extend_from_slice(&(value.len() as u16).to_le_bytes());
+            record.extend_from_slice(value.as_bytes());
+        }
+        out.extend_from_slice(&field_id.to_le_bytes());
+        out.extend_from_slice(&(record.len() as u32).to_le_bytes());
+        out.extend_from_slice(&record);
+    }
+    out
+}
+
+/// Decode a field's dictionary values in index order; malformed data ends the list
+pub(crate) fn lookup_dictionary(section: &[u8], field_id: u32) -> Option<Vec<&str>> {
+    let mut rest = section;
+    while rest.len() >= 8 {
+        let id = u32::from_le_bytes(rest[0..4].try_into().unwrap());
+        let len = u32::from_le_bytes(rest[4..8].try_into().unwrap()) as usize;
+        let record = rest.get(8..8 + len)?;
+        if id == field_id {
+            let mut values = Vec::new();
+            let mut entries = record;
+            while entries.len() >= 2 {
+                let value_len = u16::from_le_bytes(entries[0..2].try_into().unwrap()) as usize;
+                let Some(value) = entries.get(2..2 + value_len) else { break };
+                values.push(core::str::from_utf8(value).ok()?);
+                entries = &entries[2 + value_len..];
+            }
+            return Some(values);
+        }
+        rest = &rest[8 + len..];
+    }
+    None
+}
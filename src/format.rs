@@ -1,5 +1,7 @@
 use bytemuck::{Pod, Zeroable};
+use crate::checksum::ChecksumAlgo;
 use crate::error::{Result, SerializationError};
+use alloc::borrow::Cow;
 
 pub const MAGIC: u32 = 0x42495345; // "BISE" in ASCII
 pub const VERSION: u32 = 1;
@@ -7,6 +9,70 @@ pub const VERSION: u32 = 1;
 // + 4 (data_size) + 4 (var_size) + 8 (checksum) + 48 (reserved[6]) = 80 bytes
 pub const HEADER_SIZE: usize = 80;
 
+/// Upper 32 bits of a `reserved` slot whose low half holds a `u32`
+const HIGH_HALF: u64 = 0xffff_ffff_0000_0000;
+
+/// Header flag: the extension area holds a field-id-to-name table
+pub const FLAG_FIELD_NAMES: u64 = 1 << 0;
+/// Header flag: every fixed field is naturally aligned relative to the buffer start
+pub const FLAG_ALIGNED_LAYOUT: u64 = 1 << 1;
+/// Header flag: the buffer holds only the fields that changed relative to a base
+pub const FLAG_DELTA: u64 = 1 << 2;
+/// Header flag: `checksum` holds a whole-buffer FNV-1a checksum
+pub const FLAG_CHECKSUM: u64 = 1 << 3;
+/// Header flag: the sections hold several fixed-stride records sharing one offset table
+pub const FLAG_BATCH: u64 = 1 << 4;
+/// Header flag: `reserved[5]` holds the number of offset table entries
+pub const FLAG_ENTRY_COUNT: u64 = 1 << 5;
+/// Header flag: no offset table is stored; readers supply the schema out-of-band
+pub const FLAG_SCHEMA_EXTERNAL: u64 = 1 << 6;
+/// Header flag: the extension area holds a per-field CRC-32 table
+pub const FLAG_FIELD_CRCS: u64 = 1 << 7;
+/// Header flag: the extension area holds value dictionaries for `Enum` fields
+pub const FLAG_ENUM_DICTIONARY: u64 = 1 << 8;
+/// Header flag: the whole-buffer checksum is CRC-32 rather than FNV-1a
+pub const FLAG_CHECKSUM_CRC32: u64 = 1 << 9;
+/// Header flag: multi-byte fixed field values are stored big-endian
+pub const FLAG_BIG_ENDIAN: u64 = 1 << 10;
+/// Header flag: the extension area holds the used length of each string field
+pub const FLAG_STRING_LENGTHS: u64 = 1 << 11;
+/// Header flag: the extension area ends with an HMAC-SHA256 signature
+pub const FLAG_SIGNED: u64 = 1 << 12;
+/// Header flag: the extension area holds declared field default values
+pub const FLAG_FIELD_DEFAULTS: u64 = 1 << 13;
+/// Header flag: the extension area holds application key-value metadata
+pub const FLAG_METADATA: u64 = 1 << 14;
+/// Header flag: the high halves of `reserved[2]` and `reserved[5]` hold a creation time
+pub const FLAG_CREATION_TIME: u64 = 1 << 15;
+/// Header flag: offset table entries are in strictly ascending `field_id` order
+pub const FLAG_SORTED_TABLE: u64 = 1 << 16;
+/// Header flag: the extension area holds the element type of each `Array` field
+pub const FLAG_ARRAY_ELEMENTS: u64 = 1 << 17;
+/// Header flag: the extension area holds the written length of each blob field
+pub const FLAG_BLOB_LENGTHS: u64 = 1 << 18;
+/// Header flag: the whole-buffer checksum is CRC-32C rather than FNV-1a
+pub const FLAG_CHECKSUM_CRC32C: u64 = 1 << 19;
+/// Header flag: the var section is zstd-compressed; `reserved[4]` holds its uncompressed size
+pub const FLAG_VAR_ZSTD: u64 = 1 << 20;
+
+/// Byte order of a buffer's fixed field values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+impl Endianness {
+    /// Byte order of the host
+    pub fn native() -> Self {
+        if cfg!(target_endian = "big") {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        }
+    }
+}
+
 #[repr(C, packed)]
 #[derive(Debug, Clone, Copy, Pod, Zeroable)]
 pub struct FormatHeader {
@@ -45,6 +111,8 @@ pub enum FieldType {
     Bool = 11,
     String = 12,    // Variable length
     Blob = 13,      // Variable length binary
+    Enum = 14,      // u16 index into the field's value dictionary
+    Array = 15,     // Fixed-width elements; element type in the array element table
 }
 
 impl FormatHeader {
@@ -61,6 +129,50 @@ impl FormatHeader {
         }
     }
     
+    /// Copy and validate a header received on its own, ahead of the rest of the buffer
+    ///
+    /// Headers are stored little-endian; the returned copy is in host order.
+    /// Checks magic, version and the offset table size like `validate`. The
+    /// returned copy can be read without packed-alignment concerns, and its
+    /// `total_size()` says how many bytes the whole buffer spans.
+    pub fn parse(header_bytes: &[u8; HEADER_SIZE]) -> Result<Self> {
+        let header = bytemuck::pod_read_unaligned::<FormatHeader>(header_bytes).to_le();
+        header.validate()?;
+        Ok(header)
+    }
+    
+    /// Copy and validate the header at the start of `buffer`, in host order
+    pub(crate) fn read_from(buffer: &[u8]) -> Result<Self> {
+        let header_bytes = buffer.first_chunk::<HEADER_SIZE>().ok_or(SerializationError::BufferTooSmall {
+            needed: HEADER_SIZE,
+            have: buffer.len(),
+        })?;
+        Self::parse(header_bytes)
+    }
+    
+    /// Store the header at the start of `buffer` in its little-endian layout
+    pub(crate) fn write_to(&self, buffer: &mut [u8]) {
+        buffer[..HEADER_SIZE].copy_from_slice(bytemuck::bytes_of(&self.to_le()));
+    }
+    
+    /// Convert between host order and the little-endian layout headers are stored in
+    ///
+    /// An involution, and a no-op on little-endian hosts. `BinaryView` and
+    /// `BinaryViewMut` read the header in place, so on a big-endian host they
+    /// reject stored headers with `InvalidMagic` rather than misread them.
+    pub(crate) fn to_le(self) -> Self {
+        Self {
+            magic: self.magic.to_le(),
+            version: self.version.to_le(),
+            header_size: self.header_size.to_le(),
+            offset_table_size: self.offset_table_size.to_le(),
+            data_size: self.data_size.to_le(),
+            var_size: self.var_size.to_le(),
+            checksum: self.checksum.to_le(),
+            reserved: { self.reserved }.map(u64::to_le),
+        }
+    }
+    
     pub fn validate(&self) -> Result<()> {
         if self.magic != MAGIC {
             return Err(SerializationError::InvalidMagic {
@@ -75,11 +187,196 @@ impl FormatHeader {
             });
         }
         
+        // The table must hold whole entries, and as many as the producer recorded
+        let offset_table_size = self.offset_table_size;
+        let entry_size = core::mem::size_of::<OffsetEntry>() as u32;
+        let entry_count = self.entry_count().unwrap_or(offset_table_size / entry_size);
+        if entry_count as u64 * entry_size as u64 != offset_table_size as u64 {
+            return Err(SerializationError::InconsistentHeader {
+                offset_table_size,
+                entry_count,
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// Feature flags (`FLAG_*`), stored in `reserved[0]`
+    pub fn flags(&self) -> u64 {
+        let reserved = self.reserved;
+        reserved[0]
+    }
+    
+    pub fn has_flag(&self, flag: u64) -> bool {
+        self.flags() & flag != 0
+    }
+    
+    pub fn set_flag(&mut self, flag: u64) {
+        self.reserved[0] = self.flags() | flag;
+    }
+    
+    pub fn clear_flag(&mut self, flag: u64) {
+        self.reserved[0] = self.flags() & !flag;
+    }
+    
+    /// Algorithm of the whole-buffer `checksum`; FNV-1a unless `FLAG_CHECKSUM_CRC32`
+    /// or `FLAG_CHECKSUM_CRC32C`
+    pub fn checksum_algo(&self) -> ChecksumAlgo {
+        if self.has_flag(FLAG_CHECKSUM_CRC32) {
+            ChecksumAlgo::Crc32
+        } else if self.has_flag(FLAG_CHECKSUM_CRC32C) {
+            ChecksumAlgo::Crc32c
+        } else {
+            ChecksumAlgo::Fnv1a64
+        }
+    }
+    
+    pub fn set_checksum_algo(&mut self, algo: ChecksumAlgo) {
+        self.clear_flag(FLAG_CHECKSUM_CRC32 | FLAG_CHECKSUM_CRC32C);
+        match algo {
+            ChecksumAlgo::Fnv1a64 => {}
+            ChecksumAlgo::Crc32 => self.set_flag(FLAG_CHECKSUM_CRC32),
+            ChecksumAlgo::Crc32c => self.set_flag(FLAG_CHECKSUM_CRC32C),
+        }
+    }
+    
+    /// Byte order of fixed field values; little-endian unless `FLAG_BIG_ENDIAN`
+    pub fn endianness(&self) -> Endianness {
+        if self.has_flag(FLAG_BIG_ENDIAN) {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        }
+    }
+    
+    pub fn set_endianness(&mut self, endianness: Endianness) {
+        match endianness {
+            Endianness::Little => self.clear_flag(FLAG_BIG_ENDIAN),
+            Endianness::Big => self.set_flag(FLAG_BIG_ENDIAN),
+        }
+    }
+    
+    /// Size of the optional extension area after the var section, stored in `reserved[2]`
+    pub fn extension_size(&self) -> u32 {
+        let reserved = self.reserved;
+        reserved[2] as u32
+    }
+    
+    pub fn set_extension_size(&mut self, size: u32) {
+        self.reserved[2] = (self.reserved[2] & HIGH_HALF) | size as u64;
+    }
+    
+    /// Application-defined schema epoch, independent of the format `version`
+    pub fn schema_epoch(&self) -> u64 {
+        let reserved = self.reserved;
+        reserved[1]
+    }
+    
+    pub fn set_schema_epoch(&mut self, epoch: u64) {
+        self.reserved[1] = epoch;
+    }
+    
+    /// Checksum over a selected subset of fields, stored in `reserved[3]`
+    pub fn fields_checksum(&self) -> u64 {
+        let reserved = self.reserved;
+        reserved[3]
+    }
+    
+    pub fn set_fields_checksum(&mut self, checksum: u64) {
+        self.reserved[3] = checksum;
+    }
+    
+    /// Number of records in a batch buffer, stored in `reserved[4]`
+    pub fn record_count(&self) -> u64 {
+        let reserved = self.reserved;
+        reserved[4]
+    }
+    
+    pub fn set_record_count(&mut self, count: u64) {
+        self.reserved[4] = count;
+    }
+    
+    /// Uncompressed size of the var section, if `FLAG_VAR_ZSTD` is set
+    ///
+    /// Shares `reserved[4]` with the batch record count; batch buffers are
+    /// never compressed. `var_size` stays the compressed size on disk.
+    pub fn uncompressed_var_size(&self) -> Option<u32> {
+        let reserved = self.reserved;
+        self.has_flag(FLAG_VAR_ZSTD).then_some(reserved[4] as u32)
+    }
+    
+    /// Record a zstd-compressed var section's uncompressed size and set `FLAG_VAR_ZSTD`
+    pub fn set_var_compressed(&mut self, uncompressed_size: u32) {
+        self.reserved[4] = uncompressed_size as u64;
+        self.set_flag(FLAG_VAR_ZSTD);
+    }
+    
+    /// Size of the var section that var field offsets address
+    ///
+    /// `var_size`, or the uncompressed size when the section is compressed.
+    pub fn var_extent(&self) -> u32 {
+        self.uncompressed_var_size().unwrap_or(self.var_size)
+    }
+    
+    /// Offset table entry count recorded by the producer, if `FLAG_ENTRY_COUNT` is set
+    pub fn entry_count(&self) -> Option<u32> {
+        let reserved = self.reserved;
+        self.has_flag(FLAG_ENTRY_COUNT).then_some(reserved[5] as u32)
+    }
+    
+    /// Record the entry count (low 32 bits of `reserved[5]`) and set `FLAG_ENTRY_COUNT`
+    pub fn set_entry_count(&mut self, count: u32) {
+        self.reserved[5] = (self.reserved[5] & HIGH_HALF) | count as u64;
+        self.set_flag(FLAG_ENTRY_COUNT);
+    }
+    
+    /// Creation time in Unix nanoseconds, if `FLAG_CREATION_TIME` is set
+    ///
+    /// Split across the otherwise unused high 32 bits of `reserved[2]` (high
+    /// word) and `reserved[5]` (low word).
+    pub fn creation_time(&self) -> Option<i64> {
+        let reserved = self.reserved;
+        let nanos = (reserved[2] & HIGH_HALF) | (reserved[5] >> 32);
+        self.has_flag(FLAG_CREATION_TIME).then_some(nanos as i64)
+    }
+    
+    /// Record a creation time in Unix nanoseconds and set `FLAG_CREATION_TIME`
+    pub fn set_creation_time(&mut self, nanos: i64) {
+        let nanos = nanos as u64;
+        self.reserved[2] = (self.reserved[2] & !HIGH_HALF) | (nanos & HIGH_HALF);
+        self.reserved[5] = (self.reserved[5] & !HIGH_HALF) | (nanos << 32);
+        self.set_flag(FLAG_CREATION_TIME);
+    }
+    
+    /// Check that an entry stays within its own section
+    ///
+    /// Fixed fields must not reach into the var section, and var fields must not
+    /// reach into the extension area. Spans past the end of the buffer are left
+    /// to the accessors, which report `InvalidOffset`. In a compressed var
+    /// section var fields are placed against the uncompressed size.
+    pub fn check_placement(&self, entry: &OffsetEntry) -> Result<()> {
+        let start = entry.offset as usize;
+        let end = start + entry.size as usize;
+        let (section_size, following_size) = if entry.is_variable() && self.has_flag(FLAG_VAR_ZSTD) {
+            (self.var_extent() as usize, 0)
+        } else if entry.is_variable() {
+            (self.var_size as usize, self.extension_size() as usize)
+        } else {
+            (self.data_size as usize, self.var_size as usize + self.extension_size() as usize)
+        };
+        
+        if end > section_size && start < section_size + following_size {
+            return Err(SerializationError::MisplacedField {
+                field_id: entry.field_id,
+            });
+        }
+        
         Ok(())
     }
     
     pub fn total_size(&self) -> usize {
         (self.header_size + self.offset_table_size + self.data_size + self.var_size) as usize
+            + self.extension_size() as usize
     }
     
     pub fn data_section_offset(&self) -> usize {
@@ -89,4 +386,173 @@ impl FormatHeader {
     pub fn var_section_offset(&self) -> usize {
         self.data_section_offset() + self.data_size as usize
     }
+    
+    pub fn extension_offset(&self) -> usize {
+        self.var_section_offset() + self.var_size as usize
+    }
+}
+
+impl FieldType {
+    /// Byte width of a fixed-size type, or `None` for variable-length types
+    /// and `Array`, whose width depends on its element count
+    pub fn fixed_size(self) -> Option<usize> {
+        match self {
+            FieldType::Int8 | FieldType::Uint8 | FieldType::Bool => Some(1),
+            FieldType::Int16 | FieldType::Uint16 | FieldType::Enum => Some(2),
+            FieldType::Int32 | FieldType::Uint32 | FieldType::Float32 => Some(4),
+            FieldType::Int64 | FieldType::Uint64 | FieldType::Float64 => Some(8),
+            FieldType::String | FieldType::Blob | FieldType::Array => None,
+        }
+    }
+    
+    /// Whether the field lives in the variable-length section
+    pub fn is_variable(self) -> bool {
+        matches!(self, FieldType::String | FieldType::Blob)
+    }
+}
+
+impl TryFrom<u16> for FieldType {
+    type Error = SerializationError;
+    
+    fn try_from(value: u16) -> Result<Self> {
+        Ok(match value {
+            1 => FieldType::Int8,
+            2 => FieldType::Int16,
+            3 => FieldType::Int32,
+            4 => FieldType::Int64,
+            5 => FieldType::Uint8,
+            6 => FieldType::Uint16,
+            7 => FieldType::Uint32,
+            8 => FieldType::Uint64,
+            9 => FieldType::Float32,
+            10 => FieldType::Float64,
+            11 => FieldType::Bool,
+            12 => FieldType::String,
+            13 => FieldType::Blob,
+            14 => FieldType::Enum,
+            15 => FieldType::Array,
+            _ => return Err(SerializationError::UnknownFieldType { value }),
+        })
+    }
+}
+
+/// Header fields read without the version check, for diagnostics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeaderInfo {
+    pub version: u32,
+    pub header_size: u32,
+    pub offset_table_size: u32,
+    pub data_size: u32,
+    pub var_size: u32,
+    pub checksum: u64,
+    pub reserved: [u64; 6],
+}
+
+impl HeaderInfo {
+    /// Whether this build can parse the buffer's format version
+    pub fn is_supported(&self) -> bool {
+        self.version == VERSION
+    }
+}
+
+/// Read the header of a buffer of any format version
+///
+/// Only the length and magic are checked, so tools can report what a buffer
+/// is even when `view` rejects its version. Nothing past the header is read.
+pub fn peek_header(buffer: &[u8]) -> Result<HeaderInfo> {
+    if buffer.len() < HEADER_SIZE {
+        return Err(SerializationError::BufferTooSmall {
+            needed: HEADER_SIZE,
+            have: buffer.len(),
+        });
+    }
+    
+    let header = bytemuck::pod_read_unaligned::<FormatHeader>(&buffer[0..HEADER_SIZE]).to_le();
+    if header.magic != MAGIC {
+        return Err(SerializationError::InvalidMagic {
+            expected: MAGIC,
+            found: header.magic,
+        });
+    }
+    
+    Ok(HeaderInfo {
+        version: header.version,
+        header_size: header.header_size,
+        offset_table_size: header.offset_table_size,
+        data_size: header.data_size,
+        var_size: header.var_size,
+        checksum: header.checksum,
+        reserved: header.reserved,
+    })
+}
+
+/// Rust types with a fixed-size `FieldType` counterpart
+///
+/// Sealed: implemented for `u8`..`u64`, `i8`..`i64`, `f32`, `f64` and `bool`.
+pub trait AsFieldType: Copy + sealed::Sealed {
+    const FIELD_TYPE: FieldType;
+    /// Stored width in bytes
+    const SIZE: usize = core::mem::size_of::<Self>();
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_as_field_type {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $ty {}
+            impl AsFieldType for $ty {
+                const FIELD_TYPE: FieldType = FieldType::$variant;
+            }
+        )*
+    };
+}
+
+impl_as_field_type! {
+    i8 => Int8,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    u8 => Uint8,
+    u16 => Uint16,
+    u32 => Uint32,
+    u64 => Uint64,
+    f32 => Float32,
+    f64 => Float64,
+    bool => Bool,
+}
+
+impl OffsetEntry {
+    /// Whether the entry's bytes live in the variable-length section
+    pub fn is_variable(&self) -> bool {
+        self.field_type == FieldType::String as u16 || self.field_type == FieldType::Blob as u16
+    }
+    
+    /// Re-encode a stored value from one byte order to another
+    ///
+    /// Every `width`-byte value is reversed in place: the whole field for a
+    /// scalar, each element for an `Array`, so element order is kept. Var
+    /// fields and single-byte values are returned unchanged.
+    pub fn convert_bytes<'b>(&self, bytes: &'b [u8], width: usize, from: Endianness, to: Endianness) -> Cow<'b, [u8]> {
+        if from == to || self.is_variable() || width < 2 {
+            return Cow::Borrowed(bytes);
+        }
+        let mut swapped = bytes.to_vec();
+        for value in swapped.chunks_exact_mut(width) {
+            value.reverse();
+        }
+        Cow::Owned(swapped)
+    }
+    
+    /// `convert_bytes` for a value already read out as `T`
+    pub(crate) fn convert_value<T: Pod>(&self, mut value: T, width: usize, from: Endianness, to: Endianness) -> T {
+        if from != to && !self.is_variable() && width > 1 {
+            for chunk in bytemuck::bytes_of_mut(&mut value).chunks_exact_mut(width) {
+                chunk.reverse();
+            }
+        }
+        value
+    }
 }
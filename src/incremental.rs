@@ -0,0 +1,171 @@
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::{ErrorKind, Read};
+
+use crate::error::{Result, SerializationError};
+use crate::format::{FieldType, FormatHeader, OffsetEntry};
+use crate::schema::{check_fixed, check_var};
+use crate::serializer::BinarySerializer;
+
+/// Streaming producer that assigns offsets as fields are pushed
+///
+/// Unlike `SchemaBuilder`, nothing needs to be declared up front: the offset
+/// table, data section and var section grow independently and are only
+/// stitched together behind a header in `finish()`. Pushing an id a second
+/// time fails with `DuplicateFieldId`.
+#[derive(Debug, Clone, Default)]
+pub struct IncrementalSerializer {
+    entries: Vec<OffsetEntry>,
+    ids: BTreeSet<u32>,
+    data: Vec<u8>,
+    var_data: Vec<u8>,
+}
+
+impl IncrementalSerializer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Append a fixed-size field at the end of the data section
+    pub fn push_fixed(&mut self, field_id: u32, field_type: FieldType, value: &[u8]) -> Result<()> {
+        self.check_new(field_id)?;
+        let size = check_fixed(field_type, value)?;
+        
+        self.ids.insert(field_id);
+        self.entries.push(OffsetEntry {
+            field_id,
+            offset: self.data.len() as u32,
+            field_type: field_type as u16,
+            size,
+        });
+        self.data.extend_from_slice(value);
+        Ok(())
+    }
+    
+    /// Append a var field reserving `reserve` bytes at the end of the var section
+    pub fn push_var(&mut self, field_id: u32, field_type: FieldType, value: &[u8], reserve: u16) -> Result<()> {
+        self.check_new(field_id)?;
+        check_var(field_type, value, reserve, true)?;
+        
+        let offset = self.var_data.len();
+        self.ids.insert(field_id);
+        self.entries.push(OffsetEntry {
+            field_id,
+            offset: offset as u32,
+            field_type: field_type as u16,
+            size: reserve,
+        });
+        self.var_data.extend_from_slice(value);
+        self.var_data.resize(offset + reserve as usize, 0);
+        Ok(())
+    }
+    
+    /// Append a field of a type only known at runtime
+    ///
+    /// Fixed types go to the data section and must be exactly their type's
+    /// width; `reserve` is ignored for them. String and blob values go to the
+    /// var section with `reserve` bytes, or just enough for the value (plus a
+    /// string's terminator) when `None`.
+    pub fn push_field(&mut self, field_id: u32, field_type: FieldType, value: &[u8], reserve: Option<u16>) -> Result<()> {
+        if !field_type.is_variable() {
+            return self.push_fixed(field_id, field_type, value);
+        }
+        
+        let needed = match field_type {
+            FieldType::String => value.len() + 1,
+            _ => value.len(),
+        };
+        let reserve = reserve.unwrap_or(needed.min(u16::MAX as usize) as u16);
+        self.push_var(field_id, field_type, value, reserve)
+    }
+    
+    /// Append a blob field reserving `reserve` bytes, streaming its content from `reader`
+    ///
+    /// Bytes are read straight into the var section, so a large source is never
+    /// buffered separately. Returns the number of bytes read; the rest of the
+    /// reservation is zero. A reader with more than `reserve` bytes is cut off
+    /// when `truncate` is set and otherwise rejected with `SourceTooLarge`,
+    /// leaving the serializer unchanged.
+    #[cfg(feature = "std")]
+    pub fn push_var_from_reader<R: Read>(
+        &mut self,
+        field_id: u32,
+        mut reader: R,
+        reserve: u16,
+        truncate: bool,
+    ) -> Result<usize> {
+        self.check_new(field_id)?;
+        let offset = self.var_data.len();
+        self.var_data.resize(offset + reserve as usize, 0);
+        
+        let filled = match read_into(&mut reader, &mut self.var_data[offset..]) {
+            Ok(filled) if filled == reserve as usize && !truncate => match read_into(&mut reader, &mut [0u8]) {
+                Ok(0) => Ok(filled),
+                Ok(_) => Err(SerializationError::SourceTooLarge {
+                    field_id,
+                    reserve: reserve as usize,
+                }),
+                Err(err) => Err(err.into()),
+            },
+            result => result.map_err(Into::into),
+        };
+        let filled = filled.inspect_err(|_| self.var_data.truncate(offset))?;
+        
+        self.ids.insert(field_id);
+        self.entries.push(OffsetEntry {
+            field_id,
+            offset: offset as u32,
+            field_type: FieldType::Blob as u16,
+            size: reserve,
+        });
+        Ok(filled)
+    }
+    
+    fn check_new(&self, field_id: u32) -> Result<()> {
+        if self.ids.contains(&field_id) {
+            return Err(SerializationError::DuplicateFieldId { field_id });
+        }
+        Ok(())
+    }
+    
+    /// Number of fields pushed so far
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    
+    /// Write the header and concatenate table, data and var sections
+    pub fn finish(self) -> Vec<u8> {
+        let header = FormatHeader::new(
+            core::mem::size_of_val(self.entries.as_slice()) as u32,
+            self.data.len() as u32,
+            self.var_data.len() as u32,
+        );
+        
+        let mut serializer = BinarySerializer::new();
+        serializer.write_header(header);
+        serializer.write_offset_table_unchecked(&self.entries);
+        serializer.write_data(&self.data);
+        serializer.write_var_data(&self.var_data);
+        serializer.into_buffer()
+    }
+}
+
+/// Read until `buf` is full or the reader is exhausted, returning the count read
+#[cfg(feature = "std")]
+fn read_into<R: Read>(reader: &mut R, buf: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(err) if err.kind() == ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(filled)
+}
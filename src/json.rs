@@ -0,0 +1,89 @@
+use crate::error::Result;
+use crate::format::{AsFieldType, FieldType};
+use crate::serializer::BinaryView;
+use alloc::format;
+use alloc::string::ToString;
+use bytemuck::Pod;
+use serde_json::{Map, Number, Value};
+
+/// Largest integer magnitude a JSON number can carry without precision loss (2^53)
+const MAX_SAFE_INTEGER: u64 = 1 << 53;
+
+impl BinaryView<'_> {
+    /// Decode every field into a JSON object keyed by field id
+    ///
+    /// Integers and floats become numbers, bools become booleans, strings are
+    /// emitted as-is and blobs as lowercase hex. Arrays become JSON arrays of
+    /// their elements. Integers outside +/-2^53 and
+    /// non-finite floats are emitted as strings so no consumer loses precision.
+    pub fn to_value(&self) -> Result<Value> {
+        let mut object = Map::new();
+        
+        for entry in self.entries() {
+            let field_id = entry.field_id;
+            let value = match FieldType::try_from(entry.field_type)? {
+                FieldType::Int8 => int_value(self.read_fixed::<i8>(entry)? as i64),
+                FieldType::Int16 => int_value(self.read_fixed::<i16>(entry)? as i64),
+                FieldType::Int32 => int_value(self.read_fixed::<i32>(entry)? as i64),
+                FieldType::Int64 => int_value(self.read_fixed::<i64>(entry)?),
+                FieldType::Uint8 => uint_value(self.read_fixed::<u8>(entry)? as u64),
+                FieldType::Uint16 => uint_value(self.read_fixed::<u16>(entry)? as u64),
+                FieldType::Uint32 => uint_value(self.read_fixed::<u32>(entry)? as u64),
+                FieldType::Uint64 => uint_value(self.read_fixed::<u64>(entry)?),
+                FieldType::Float32 => float_value(self.read_fixed::<f32>(entry)? as f64),
+                FieldType::Float64 => float_value(self.read_fixed::<f64>(entry)?),
+                FieldType::Bool => Value::Bool(self.read_fixed::<u8>(entry)? != 0),
+                FieldType::String => Value::String(self.get_string(field_id)?.to_string()),
+                FieldType::Enum => Value::String(self.get_enum(field_id)?.to_string()),
+                FieldType::Blob => hex_value(&self.get_blob_cow(field_id)?),
+                FieldType::Array => match self.array_element_type(field_id) {
+                    Some(FieldType::Int8) => self.array_value(field_id, |v: i8| int_value(v as i64))?,
+                    Some(FieldType::Int16) => self.array_value(field_id, |v: i16| int_value(v as i64))?,
+                    Some(FieldType::Int32) => self.array_value(field_id, |v: i32| int_value(v as i64))?,
+                    Some(FieldType::Int64) => self.array_value(field_id, int_value)?,
+                    Some(FieldType::Uint8) => self.array_value(field_id, |v: u8| uint_value(v as u64))?,
+                    Some(FieldType::Uint16) => self.array_value(field_id, |v: u16| uint_value(v as u64))?,
+                    Some(FieldType::Uint32) => self.array_value(field_id, |v: u32| uint_value(v as u64))?,
+                    Some(FieldType::Uint64) => self.array_value(field_id, uint_value)?,
+                    Some(FieldType::Float32) => self.array_value(field_id, |v: f32| float_value(v as f64))?,
+                    Some(FieldType::Float64) => self.array_value(field_id, float_value)?,
+                    // No readable element type: fall back to the raw bytes
+                    _ => hex_value(self.entry_bytes(entry)?),
+                },
+            };
+            object.insert(field_id.to_string(), value);
+        }
+        
+        Ok(Value::Object(object))
+    }
+    
+    fn array_value<T: AsFieldType + Pod>(&self, field_id: u32, convert: impl Fn(T) -> Value) -> Result<Value> {
+        Ok(Value::Array(self.get_array_copy::<T>(field_id)?.into_iter().map(convert).collect()))
+    }
+}
+
+fn hex_value(bytes: &[u8]) -> Value {
+    Value::String(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+fn int_value(value: i64) -> Value {
+    if value.unsigned_abs() > MAX_SAFE_INTEGER {
+        Value::String(value.to_string())
+    } else {
+        Value::Number(value.into())
+    }
+}
+
+fn uint_value(value: u64) -> Value {
+    if value > MAX_SAFE_INTEGER {
+        Value::String(value.to_string())
+    } else {
+        Value::Number(value.into())
+    }
+}
+
+fn float_value(value: f64) -> Value {
+    Number::from_f64(value)
+        .map(Value::Number)
+        .unwrap_or_else(|| Value::String(value.to_string()))
+}
@@ -1,7 +1,63 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod batch;
+pub mod checksum;
+#[cfg(feature = "zstd")]
+pub mod compression;
+pub mod document;
+pub mod editor;
 pub mod error;
+mod extension;
 pub mod format;
+pub mod incremental;
+#[cfg(feature = "serde_json")]
+mod json;
+pub mod ops;
+pub mod schema;
 pub mod serializer;
+#[cfg(feature = "serde")]
+pub mod serde_compat;
+#[cfg(feature = "hmac")]
+mod signature;
+pub mod validated;
+pub mod value;
 
+pub use batch::{BatchSerializer, RecordView};
+#[cfg(feature = "derive")]
+pub use bisere_macros::{schema, BiSere};
+pub use checksum::ChecksumAlgo;
+#[cfg(feature = "zstd")]
+pub use compression::Codec;
+#[cfg(feature = "memmap")]
+pub use memmap2;
+pub use document::OwnedDocument;
+pub use editor::{BoundEditor, MutEditor};
 pub use error::{Result, SerializationError};
-pub use format::{FieldType, FormatHeader, OffsetEntry};
-pub use serializer::{BinarySerializer, BinaryView, BinaryViewMut};
+pub use format::{peek_header, AsFieldType, Endianness, FieldType, FormatHeader, HeaderInfo, OffsetEntry};
+pub use incremental::IncrementalSerializer;
+pub use ops::{
+    apply_delta, is_compatible, iter_records, make_delta, redact, remap, resize_var_field, sort_fields, split, swap_endianness,
+    truncate_to_logical, upgrade, Compatibility,
+};
+pub use schema::{build, Schema, SchemaBuilder};
+#[cfg(feature = "serde")]
+pub use serde_compat::{from_bytes, to_bytes};
+pub use serializer::{
+    is_valid, BinarySerializer, BinaryView, BinaryViewMut, FieldDiff, FieldInfo, FinishedBuffer, StackSerializer, Utilization,
+};
+#[cfg(feature = "std")]
+pub use serializer::WriteSerializer;
+#[cfg(feature = "profiling")]
+pub use serializer::AccessStats;
+pub use validated::{ValidatedIter, ValidatedView};
+pub use value::FieldValue;
+
+/// Paths used by the code `bisere-macros` generates, so it also builds in `no_std` crates
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec;
+    pub use alloc::vec::Vec;
+}
@@ -0,0 +1,667 @@
+//! Whole-buffer operations that work on owned or borrowed buffers
+
+use crate::error::{Result, SerializationError};
+use crate::format::{
+    peek_header, Endianness, FieldType, FormatHeader, OffsetEntry, FLAG_ALIGNED_LAYOUT, FLAG_ARRAY_ELEMENTS, FLAG_BATCH,
+    FLAG_BIG_ENDIAN, FLAG_DELTA, FLAG_ENUM_DICTIONARY, HEADER_SIZE, VERSION,
+};
+use crate::extension;
+use crate::schema::Schema;
+use crate::serializer::{BinarySerializer, BinaryView, BinaryViewMut};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Drop any bytes past the header's declared `total_size()`
+///
+/// Buffers read from a stream into an over-sized `Vec` may carry trailing
+/// junk; call this before storing, hashing or comparing them.
+pub fn truncate_to_logical(buffer: &mut Vec<u8>) -> Result<()> {
+    let header = FormatHeader::read_from(buffer)?;
+    let total_size = header.total_size();
+    if buffer.len() < total_size {
+        return Err(SerializationError::BufferTooSmall {
+            needed: total_size,
+            have: buffer.len(),
+        });
+    }
+    
+    buffer.truncate(total_size);
+    Ok(())
+}
+
+/// Copy a buffer from an older format revision into the current header layout
+///
+/// The offset table and all sections are copied verbatim; only the header is
+/// rebuilt. Header metadata that older producers didn't record is filled in
+/// (currently the offset table entry count), and a stored whole-buffer
+/// checksum is recomputed. Versions newer than `VERSION` are rejected.
+pub fn upgrade(buffer: &[u8]) -> Result<Vec<u8>> {
+    let info = peek_header(buffer)?;
+    if info.version != VERSION {
+        // Version 1 is the only layout so far; older layouts would be mapped here
+        return Err(SerializationError::UnsupportedVersion {
+            version: info.version,
+        });
+    }
+    
+    let mut header = FormatHeader::new(info.offset_table_size, info.data_size, info.var_size);
+    header.checksum = info.checksum;
+    header.reserved = info.reserved;
+    if header.entry_count().is_none() {
+        header.set_entry_count(info.offset_table_size / core::mem::size_of::<OffsetEntry>() as u32);
+    }
+    header.validate()?;
+    
+    let total_size = header.total_size();
+    let body = buffer.get(HEADER_SIZE..total_size).ok_or(SerializationError::BufferTooSmall {
+        needed: total_size,
+        have: buffer.len(),
+    })?;
+    
+    let mut upgraded = bytemuck::bytes_of(&header.to_le()).to_vec();
+    upgraded.extend_from_slice(body);
+    
+    let had_checksum = BinaryView::view(&upgraded)?.has_checksum();
+    if had_checksum {
+        BinaryViewMut::view_mut(&mut upgraded)?.store_checksum()?;
+    }
+    Ok(upgraded)
+}
+
+/// Iterate over complete buffers stored back to back, such as an append-only log
+///
+/// Each record is viewed over exactly its header's `total_size()` bytes. The
+/// iterator ends after the last record; a truncated or corrupt trailing
+/// record yields one `Err` and then stops.
+pub fn iter_records(buffer: &[u8]) -> impl Iterator<Item = Result<BinaryView<'_>>> {
+    let mut rest = buffer;
+    core::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        
+        let record = FormatHeader::read_from(rest).and_then(|header| {
+            let total_size = header.total_size();
+            let bytes = rest.get(..total_size).ok_or(SerializationError::BufferTooSmall {
+                needed: total_size,
+                have: rest.len(),
+            })?;
+            Ok((BinaryView::view(bytes)?, total_size))
+        });
+        
+        match record {
+            Ok((view, total_size)) => {
+                rest = &rest[total_size..];
+                Some(Ok(view))
+            }
+            Err(err) => {
+                rest = &[];
+                Some(Err(err))
+            }
+        }
+    })
+}
+
+/// Split a batch buffer or a concatenated stream into standalone buffers
+///
+/// Plain buffers in the stream are copied as they are. Each record of a
+/// `FLAG_BATCH` buffer becomes its own buffer with a copy of the shared
+/// offset table and a fresh header keeping the schema epoch, byte order and
+/// aligned-layout flag, plus the batch's `Array` element type table; a
+/// batch's whole-buffer checksum is not carried over.
+pub fn split(buffer: &[u8]) -> Result<Vec<Vec<u8>>> {
+    let mut buffers = Vec::new();
+    let mut rest = buffer;
+    while !rest.is_empty() {
+        let header = FormatHeader::read_from(rest)?;
+        let total_size = header.total_size();
+        let bytes = rest.get(..total_size).ok_or(SerializationError::BufferTooSmall {
+            needed: total_size,
+            have: rest.len(),
+        })?;
+        
+        let view = BinaryView::view(bytes)?;
+        if header.has_flag(FLAG_BATCH) {
+            let elements: Vec<(u32, u32)> = view
+                .entries()
+                .iter()
+                .filter_map(|entry| Some((entry.field_id, view.array_element_type(entry.field_id)? as u32)))
+                .collect();
+            for index in 0..view.record_count() {
+                let (data, var_data) = view.record(index)?.sections();
+                let mut record_header = FormatHeader::new(
+                    header.offset_table_size,
+                    data.len() as u32,
+                    var_data.len() as u32,
+                );
+                record_header.set_schema_epoch(header.schema_epoch());
+                record_header.set_flag(header.flags() & (FLAG_ALIGNED_LAYOUT | FLAG_BIG_ENDIAN));
+                let extension_area = element_table_area(&mut record_header, &elements);
+                
+                let mut serializer = BinarySerializer::new();
+                serializer.write_header(record_header);
+                serializer.write_offset_table(view.entries())?;
+                serializer.write_data(data);
+                serializer.write_var_data(var_data);
+                serializer.write_extension(&extension_area);
+                buffers.push(serializer.into_buffer());
+            }
+        } else {
+            buffers.push(bytes.to_vec());
+        }
+        rest = &rest[total_size..];
+    }
+    Ok(buffers)
+}
+
+/// Copy `buffer` with the listed fields zeroed, for logging or sharing without PII
+///
+/// The layout is untouched so the copy still views cleanly: fixed fields read
+/// as 0, strings as empty and blobs as all-zero bytes (see
+/// `BinaryViewMut::zero_field`). Stored field CRCs and the whole-buffer
+/// checksum are recomputed for the redacted bytes.
+pub fn redact(buffer: &[u8], field_ids: &[u32]) -> Result<Vec<u8>> {
+    let mut copy = buffer.to_vec();
+    let had_checksum = BinaryView::view(&copy)?.has_checksum();
+    
+    let mut view = BinaryViewMut::view_mut(&mut copy)?;
+    for &field_id in field_ids {
+        view.zero_field(field_id)?;
+        view.update_field_crc(field_id)?;
+    }
+    if had_checksum {
+        view.store_checksum()?;
+    }
+    
+    Ok(copy)
+}
+
+/// Copy `buffer` with a var field's reservation changed to `new_size` bytes
+///
+/// Var data after the field shifts to match and their offsets, `var_size`
+/// and any stored checksums are updated. `new_size` must still cover the
+/// content: a string's text plus terminator, or a blob up to its last
+/// nonzero byte (trailing zeros are indistinguishable from padding). The
+/// records of a batch buffer share one layout: `BatchBuffer`.
+pub fn resize_var_field(buffer: &[u8], field_id: u32, new_size: u16) -> Result<Vec<u8>> {
+    let view = BinaryView::view(buffer)?;
+    if FormatHeader::read_from(buffer)?.has_flag(FLAG_BATCH) {
+        return Err(SerializationError::BatchBuffer);
+    }
+    let target = *view.find_entry(field_id)
+        .ok_or(SerializationError::FieldNotFound { field_id })?;
+    if !target.is_variable() {
+        return Err(SerializationError::FieldSizeMismatch {
+            expected: FieldType::Blob as usize,
+            got: target.field_type as usize,
+        });
+    }
+    
+    let old_bytes = view.entry_bytes(&target)?;
+    let used = view.used_len(&target)?;
+    if (new_size as usize) < used {
+        return Err(SerializationError::FieldSizeMismatch {
+            expected: used,
+            got: new_size as usize,
+        });
+    }
+    
+    let mut header = FormatHeader::read_from(buffer)?;
+    let var_start = header.var_section_offset();
+    let var_section = &buffer[var_start..var_start + header.var_size as usize];
+    let extension_area = &buffer[header.extension_offset()..header.total_size()];
+    let field_start = target.offset as usize;
+    let field_end = field_start + target.size as usize;
+    
+    let mut var_data = var_section[..field_start].to_vec();
+    var_data.extend_from_slice(&old_bytes[..used.min(old_bytes.len())]);
+    var_data.resize(field_start + new_size as usize, 0);
+    var_data.extend_from_slice(&var_section[field_end..]);
+    
+    let shift = new_size as i64 - target.size as i64;
+    let entries: Vec<OffsetEntry> = view.entries()
+        .iter()
+        .map(|entry| {
+            let mut entry = *entry;
+            if entry.field_id == field_id {
+                entry.size = new_size;
+            } else if entry.is_variable() && entry.offset as usize >= field_end {
+                entry.offset = (entry.offset as i64 + shift) as u32;
+            }
+            entry
+        })
+        .collect();
+    
+    header.var_size = var_data.len() as u32;
+    let data_start = header.data_section_offset();
+    
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(header);
+    serializer.write_offset_table(&entries)?;
+    serializer.write_data(&buffer[data_start..var_start]);
+    serializer.write_var_data(&var_data);
+    serializer.write_extension(extension_area);
+    let mut resized = serializer.into_buffer();
+    
+    let had_checksum = view.has_checksum();
+    let mut view = BinaryViewMut::view_mut(&mut resized)?;
+    view.update_field_crc(field_id)?;
+    if had_checksum {
+        view.store_checksum()?;
+    }
+    Ok(resized)
+}
+
+/// How a candidate buffer's schema relates to a base buffer's, from `is_compatible`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compatibility {
+    /// Same field ids with the same types
+    Identical,
+    /// Every base field with the same type, plus fields the base lacks
+    Superset,
+    /// A base field is missing or has a different type
+    Incompatible,
+}
+
+/// Compare the `(field_id, field_type)` sets of two buffers
+///
+/// Answers whether a consumer written for `base` can read `candidate`.
+/// Offsets, table order and var reservations are ignored; fixed sizes
+/// follow from the type.
+pub fn is_compatible(base: &[u8], candidate: &[u8]) -> Result<Compatibility> {
+    let base = BinaryView::view(base)?;
+    let candidate = BinaryView::view(candidate)?;
+    
+    for entry in base.entries() {
+        match candidate.find_entry(entry.field_id) {
+            Some(found) if found.field_type == entry.field_type => {}
+            _ => return Ok(Compatibility::Incompatible),
+        }
+    }
+    
+    let extra = candidate.entries().iter().any(|entry| base.find_entry(entry.field_id).is_none());
+    Ok(if extra { Compatibility::Superset } else { Compatibility::Identical })
+}
+
+/// Copy fields of `source` into a new buffer laid out by `target_schema`, renaming ids
+///
+/// Each `(source_id, target_id)` pair copies one field; both ids must exist
+/// and the types must match. Fixed values are converted to little-endian;
+/// strings and blobs must fit the target reservation (strings with their
+/// terminator, blobs up to their last nonzero byte). Target fields left
+/// unmapped hold their schema default, or zeros. The only extension section
+/// written is the `Array` element type table, taking each type from the
+/// target schema or else from the mapped source field.
+pub fn remap(source: &[u8], mapping: &[(u32, u32)], target_schema: &Schema) -> Result<Vec<u8>> {
+    let view = BinaryView::view(source)?;
+    let mut data = vec![0u8; target_schema.data_size() as usize];
+    let mut var_data = vec![0u8; target_schema.var_size() as usize];
+    
+    for entry in target_schema.entries() {
+        if let Some(default) = target_schema.field_default(entry.field_id) {
+            let start = entry.offset as usize;
+            if !entry.is_variable() && default.len() == entry.size as usize {
+                data[start..start + default.len()].copy_from_slice(default);
+            }
+        }
+    }
+    
+    for &(source_id, target_id) in mapping {
+        let from = view.find_entry(source_id)
+            .ok_or(SerializationError::FieldNotFound { field_id: source_id })?;
+        let to = target_schema.find_entry(target_id)
+            .ok_or(SerializationError::FieldNotFound { field_id: target_id })?;
+        if from.field_type != to.field_type {
+            return Err(SerializationError::FieldTypeMismatch {
+                field_id: target_id,
+                expected: to.field_type,
+                found: from.field_type,
+            });
+        }
+        
+        let stored = view.entry_bytes(from)?;
+        let (bytes, needed) = if from.field_type == FieldType::String as u16 {
+            let content = view.string_content(from)?;
+            (Cow::Borrowed(content), content.len() + 1)
+        } else if from.is_variable() {
+            let used = view.used_len(from)?.min(stored.len());
+            (Cow::Borrowed(&stored[..used]), used)
+        } else {
+            (from.convert_bytes(stored, view.value_width(from), view.endianness(), Endianness::Little), stored.len())
+        };
+        if needed > to.size as usize {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: to.size as usize,
+                got: needed,
+            });
+        }
+        
+        let section = if to.is_variable() { &mut var_data } else { &mut data };
+        let start = to.offset as usize;
+        section[start..start + to.size as usize].fill(0);
+        section[start..start + bytes.len()].copy_from_slice(&bytes);
+    }
+    
+    let mut elements = target_schema.array_elements().to_vec();
+    for &(source_id, target_id) in mapping {
+        let is_array = target_schema.find_entry(target_id).is_some_and(|to| to.field_type == FieldType::Array as u16);
+        if is_array && target_schema.array_element_type(target_id).is_none() {
+            if let Some(element_type) = view.array_element_type(source_id) {
+                elements.push((target_id, element_type as u32));
+            }
+        }
+    }
+    
+    let mut header = FormatHeader::new(
+        target_schema.offset_table_size(),
+        target_schema.data_size(),
+        target_schema.var_size(),
+    );
+    let extension_area = element_table_area(&mut header, &elements);
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(header);
+    serializer.write_offset_table(target_schema.entries())?;
+    serializer.write_data(&data);
+    serializer.write_var_data(&var_data);
+    serializer.write_extension(&extension_area);
+    Ok(serializer.into_buffer())
+}
+
+/// Copy `buffer` with its offset table and both sections ordered by ascending field id
+///
+/// Fields are packed in id order within each section (fixed fields padded to
+/// their width under `FLAG_ALIGNED_LAYOUT`, as `SchemaBuilder` does), so
+/// buffers holding the same fields come out byte-identical however they were
+/// built. Each record of a batch is reordered the same way. The header and
+/// extension area are kept, except that section sizes and a stored
+/// whole-buffer checksum are updated; an HMAC signature no longer verifies.
+/// A compressed var section fails with `CompressedVarSection`.
+pub fn sort_fields(buffer: &[u8]) -> Result<Vec<u8>> {
+    let view = BinaryView::view(buffer)?;
+    let mut header = FormatHeader::read_from(buffer)?;
+    if header.uncompressed_var_size().is_some() {
+        return Err(SerializationError::CompressedVarSection);
+    }
+    let count = view.record_count();
+    let data_start = header.data_section_offset();
+    let var_start = header.var_section_offset();
+    let old_data_stride = (header.data_size as usize).checked_div(count).unwrap_or(0);
+    let old_var_stride = (header.var_size as usize).checked_div(count).unwrap_or(0);
+    
+    let mut entries = view.entries().to_vec();
+    entries.sort_by_key(|entry| entry.field_id);
+    
+    let aligned = view.is_aligned_layout();
+    let mut data_stride = 0;
+    let mut var_stride = 0;
+    let mut widest = 1;
+    let sources: Vec<OffsetEntry> = entries
+        .iter_mut()
+        .map(|entry| {
+            let source = *entry;
+            let cursor = if entry.is_variable() {
+                &mut var_stride
+            } else {
+                let width = entry.size.max(1) as usize;
+                if aligned {
+                    data_stride += (width - (data_start + data_stride) % width) % width;
+                    widest = widest.max(width);
+                }
+                &mut data_stride
+            };
+            entry.offset = *cursor as u32;
+            *cursor += entry.size as usize;
+            source
+        })
+        .collect();
+    if aligned && count > 1 {
+        // Keep every record's fields aligned, not just the first record's
+        data_stride = data_stride.next_multiple_of(widest);
+    }
+    
+    let mut data = vec![0u8; data_stride * count];
+    let mut var_data = vec![0u8; var_stride * count];
+    for record in 0..count {
+        for (entry, source) in entries.iter().zip(&sources) {
+            let (old_start, new_section, new_start) = if entry.is_variable() {
+                (var_start + record * old_var_stride, &mut var_data, record * var_stride)
+            } else {
+                (data_start + record * old_data_stride, &mut data, record * data_stride)
+            };
+            let from = old_start + source.offset as usize;
+            let to = new_start + entry.offset as usize;
+            let len = entry.size as usize;
+            let bytes = buffer.get(from..from + len)
+                .ok_or(SerializationError::InvalidOffset { offset: from + len, size: buffer.len() })?;
+            new_section[to..to + len].copy_from_slice(bytes);
+        }
+    }
+    
+    let extension_area = &buffer[header.extension_offset()..header.total_size()];
+    header.data_size = data.len() as u32;
+    header.var_size = var_data.len() as u32;
+    
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(header);
+    serializer.write_offset_table(&entries)?;
+    serializer.write_data(&data);
+    serializer.write_var_data(&var_data);
+    serializer.write_extension(extension_area);
+    let mut sorted = serializer.into_buffer();
+    
+    if view.has_checksum() {
+        BinaryViewMut::view_mut(&mut sorted)?.store_checksum()?;
+    }
+    Ok(sorted)
+}
+
+/// Convert a buffer's fixed field values to the opposite byte order in place
+///
+/// Every fixed field wider than one byte is byte-swapped (in each record of
+/// a batch; element by element for arrays) and `FLAG_BIG_ENDIAN` is
+/// toggled, so a consumer of the other byte order can read values natively.
+/// The header (stored little-endian, see `FormatHeader::parse`), the offset
+/// table and the little-endian extension area describe the layout rather
+/// than values and are left as they are, as are string and blob bytes.
+/// Stored field CRCs and a stored whole-buffer checksum are recomputed.
+pub fn swap_endianness(buffer: &mut [u8]) -> Result<()> {
+    let view = BinaryView::view(buffer)?;
+    let mut header = FormatHeader::read_from(buffer)?;
+    let count = view.record_count();
+    let stride = (header.data_size as usize).checked_div(count).unwrap_or(0);
+    let data_start = header.data_section_offset();
+    let had_checksum = view.has_checksum();
+    
+    // Each swapped field with the width of the values it holds
+    let swapped: Vec<(OffsetEntry, usize)> = view.entries()
+        .iter()
+        .filter(|entry| !entry.is_variable())
+        .map(|entry| (*entry, view.value_width(entry)))
+        .filter(|&(_, width)| width > 1)
+        .collect();
+    
+    let size = buffer.len();
+    for record in 0..count {
+        for (entry, width) in &swapped {
+            let start = data_start + record * stride + entry.offset as usize;
+            let end = start + entry.size as usize;
+            let bytes = buffer.get_mut(start..end)
+                .ok_or(SerializationError::InvalidOffset { offset: end, size })?;
+            for value in bytes.chunks_exact_mut(*width) {
+                value.reverse();
+            }
+        }
+    }
+    
+    header.set_endianness(match header.endianness() {
+        Endianness::Little => Endianness::Big,
+        Endianness::Big => Endianness::Little,
+    });
+    header.write_to(buffer);
+    
+    let mut view = BinaryViewMut::view_mut(buffer)?;
+    for (entry, _) in &swapped {
+        view.update_field_crc(entry.field_id)?;
+    }
+    if had_checksum {
+        view.store_checksum()?;
+    }
+    Ok(())
+}
+
+/// Build a delta holding only the fields of `new` that differ from `base`
+///
+/// A field is included when it is missing from `base` or its type, size or
+/// stored bytes changed. The result is a regular buffer flagged with
+/// `FLAG_DELTA` and stamped with `new`'s schema epoch and byte order; fixed
+/// values are compared across byte orders. Fields removed in
+/// `new` cannot be expressed and are carried through by `apply_delta`.
+/// Of the extension sections only the element types of included `Array`
+/// fields are kept; others (such as field names) are not included.
+pub fn make_delta(base: &[u8], new: &[u8]) -> Result<Vec<u8>> {
+    let base_view = BinaryView::view(base)?;
+    let new_view = BinaryView::view(new)?;
+    let base_order = base_view.endianness();
+    let new_order = new_view.endianness();
+    
+    let mut changed = Vec::new();
+    for entry in new_view.entries() {
+        let bytes = new_view.entry_bytes(entry)?;
+        let unchanged = match base_view.find_entry(entry.field_id) {
+            Some(old) => {
+                old.field_type == entry.field_type
+                    && old.size == entry.size
+                    && old.convert_bytes(base_view.entry_bytes(old)?, base_view.value_width(old), base_order, new_order) == bytes
+            }
+            None => false,
+        };
+        if !unchanged {
+            changed.push((*entry, Cow::Borrowed(bytes)));
+        }
+    }
+    
+    assemble(&changed, &[&new_view], new_view.schema_epoch(), FLAG_DELTA | order_flag(new_order))
+}
+
+/// Rebuild a full buffer from `base` and a delta produced by `make_delta`
+///
+/// Base fields keep their table order, taking the delta's version where one
+/// exists; fields only in the delta are appended. Delta values are converted
+/// to the base's byte order, which the result keeps. The result carries the
+/// delta's schema epoch and, of the extension sections, only the element
+/// types of its `Array` fields.
+pub fn apply_delta(base: &[u8], delta: &[u8]) -> Result<Vec<u8>> {
+    let base_view = BinaryView::view(base)?;
+    let delta_view = BinaryView::view(delta)?;
+    if !FormatHeader::read_from(delta)?.has_flag(FLAG_DELTA) {
+        return Err(SerializationError::NotADelta);
+    }
+    
+    let base_order = base_view.endianness();
+    let delta_order = delta_view.endianness();
+    let from_delta = |entry: &OffsetEntry| -> Result<(OffsetEntry, Cow<'_, [u8]>)> {
+        let bytes = delta_view.entry_bytes(entry)?;
+        Ok((*entry, entry.convert_bytes(bytes, delta_view.value_width(entry), delta_order, base_order)))
+    };
+    
+    let mut fields = Vec::new();
+    for entry in base_view.entries() {
+        match delta_view.find_entry(entry.field_id) {
+            Some(updated) => fields.push(from_delta(updated)?),
+            None => fields.push((*entry, Cow::Borrowed(base_view.entry_bytes(entry)?))),
+        }
+    }
+    for entry in delta_view.entries() {
+        if base_view.find_entry(entry.field_id).is_none() {
+            fields.push(from_delta(entry)?);
+        }
+    }
+    
+    assemble(&fields, &[&delta_view, &base_view], delta_view.schema_epoch(), order_flag(base_order))
+}
+
+fn order_flag(endianness: Endianness) -> u64 {
+    match endianness {
+        Endianness::Little => 0,
+        Endianness::Big => FLAG_BIG_ENDIAN,
+    }
+}
+
+/// Pack `fields` into a fresh buffer, recording each `Array` field's element
+/// type and each `Enum` field's dictionary from the first of `sources` that
+/// knows it
+fn assemble(fields: &[(OffsetEntry, Cow<'_, [u8]>)], sources: &[&BinaryView<'_>], epoch: u64, flags: u64) -> Result<Vec<u8>> {
+    let mut entries = Vec::with_capacity(fields.len());
+    let mut data = Vec::new();
+    let mut var_data = Vec::new();
+    
+    for (entry, bytes) in fields {
+        let section = if entry.is_variable() { &mut var_data } else { &mut data };
+        entries.push(OffsetEntry {
+            offset: section.len() as u32,
+            ..*entry
+        });
+        section.extend_from_slice(bytes);
+    }
+    
+    let mut header = FormatHeader::new(
+        core::mem::size_of_val(entries.as_slice()) as u32,
+        data.len() as u32,
+        var_data.len() as u32,
+    );
+    header.set_schema_epoch(epoch);
+    header.set_flag(flags);
+    
+    let elements: Vec<(u32, u32)> = entries
+        .iter()
+        .filter(|entry| entry.field_type == FieldType::Array as u16)
+        .filter_map(|entry| {
+            let element_type = sources.iter().find_map(|view| view.array_element_type(entry.field_id))?;
+            Some((entry.field_id, element_type as u32))
+        })
+        .collect();
+    let dictionaries: Vec<(u32, Vec<String>)> = entries
+        .iter()
+        .filter(|entry| entry.field_type == FieldType::Enum as u16)
+        .filter_map(|entry| {
+            let values = sources.iter().find_map(|view| view.enum_dictionary(entry.field_id))?;
+            Some((entry.field_id, values.into_iter().map(String::from).collect()))
+        })
+        .collect();
+    let mut extension_area = element_table_area(&mut header, &elements);
+    push_section(
+        &mut header,
+        &mut extension_area,
+        FLAG_ENUM_DICTIONARY,
+        extension::TAG_ENUM_DICTIONARY,
+        &extension::encode_dictionaries(&dictionaries),
+    );
+    
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(header);
+    serializer.write_offset_table(&entries)?;
+    serializer.write_data(&data);
+    serializer.write_var_data(&var_data);
+    serializer.write_extension(&extension_area);
+    Ok(serializer.into_buffer())
+}
+
+/// An extension area holding only an `Array` element type table, flagged in `header`
+///
+/// Empty, and `header` untouched, when there are no element types to record.
+pub(crate) fn element_table_area(header: &mut FormatHeader, elements: &[(u32, u32)]) -> Vec<u8> {
+    let mut area = Vec::new();
+    push_section(header, &mut area, FLAG_ARRAY_ELEMENTS, extension::TAG_ARRAY_ELEMENTS, &extension::encode_id_values(elements));
+    area
+}
+
+/// Append a section to `area` and flag it in `header`; does nothing for an empty `payload`
+pub(crate) fn push_section(header: &mut FormatHeader, area: &mut Vec<u8>, flag: u64, tag: u32, payload: &[u8]) {
+    if !payload.is_empty() {
+        extension::write_section(area, tag, payload);
+        header.set_flag(flag);
+        header.set_extension_size(area.len() as u32);
+    }
+}
@@ -0,0 +1,777 @@
+use crate::error::{Result, SerializationError};
+use crate::checksum;
+use crate::extension;
+use crate::format::{AsFieldType, Endianness, FieldType, FormatHeader, OffsetEntry, FLAG_ALIGNED_LAYOUT, FLAG_ARRAY_ELEMENTS, FLAG_BLOB_LENGTHS, FLAG_ENUM_DICTIONARY, FLAG_FIELD_CRCS, FLAG_FIELD_NAMES,
+    FLAG_FIELD_DEFAULTS, FLAG_METADATA, FLAG_STRING_LENGTHS, HEADER_SIZE,
+};
+use crate::serializer::{BinarySerializer, BinaryView};
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use bytemuck::Pod;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Declarative builder that assigns offsets and sizes the sections automatically
+#[derive(Debug, Clone, Default)]
+pub struct SchemaBuilder {
+    fields: Vec<FieldSpec>,
+    names: Vec<(u32, String)>,
+    dictionaries: Vec<(u32, Vec<String>)>,
+    array_elements: Vec<(u32, u32)>,
+    defaults: Vec<(u32, Vec<u8>)>,
+    metadata: Vec<(String, String)>,
+    epoch: u64,
+    optimize_layout: bool,
+    align_fields: bool,
+    field_crcs: bool,
+    string_lengths: bool,
+    blob_lengths: bool,
+    #[cfg(feature = "std")]
+    creation_time: bool,
+}
+
+/// A declared field and the bytes it is initialized with
+#[derive(Debug, Clone)]
+struct FieldSpec {
+    field_id: u32,
+    field_type: FieldType,
+    size: u16,
+    bytes: Vec<u8>,
+}
+
+impl SchemaBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seed a builder with every field of an existing buffer and its current bytes
+    ///
+    /// Fields keep their table order, types and reservations; field names,
+    /// defaults, array element types and metadata, the schema epoch and the
+    /// aligned-layout, field-CRC, string-length and blob-length options carry
+    /// over. Fixed values are converted to native byte order, which `build()`
+    /// writes. Offsets are reassigned by `build()`.
+    pub fn from_view(view: &BinaryView) -> Result<Self> {
+        let mut builder = Self::new();
+        builder
+            .with_epoch(view.schema_epoch())
+            .align_fields(view.is_aligned_layout())
+            .field_crcs(view.has_field_crcs())
+            .string_lengths(view.has_string_lengths())
+            .blob_lengths(view.has_blob_lengths());
+        for (key, value) in view.metadata_iter() {
+            builder.add_metadata(key, value)?;
+        }
+
+        for entry in view.entries() {
+            let field_id = entry.field_id;
+            let field_type = FieldType::try_from(entry.field_type)?;
+            let mut bytes = view.field_raw_bytes(field_id)?;
+            if field_type != FieldType::Array && !field_type.is_variable() {
+                check_fixed(field_type, bytes)?;
+            }
+            if field_type == FieldType::String {
+                // Keep only the content so a rebuilt length table matches it
+                bytes = view.string_content(entry)?;
+            } else if field_type == FieldType::Blob && view.has_blob_lengths() {
+                bytes = view.blob_content(entry)?;
+            }
+            let bytes = entry.convert_bytes(bytes, view.value_width(entry), view.endianness(), Endianness::native());
+
+            builder.fields.push(FieldSpec {
+                field_id,
+                field_type,
+                size: entry.size,
+                bytes: bytes.into_owned(),
+            });
+            if let Some(name) = view.field_name(field_id) {
+                builder.add_named(field_id, name)?;
+            }
+            if let Some(default) = view.field_default(field_id) {
+                builder.defaults.push((field_id, default.to_vec()));
+            }
+            if let Some(values) = view.enum_dictionary(field_id) {
+                builder.dictionaries.push((field_id, values.into_iter().map(String::from).collect()));
+            }
+            if let Some(element_type) = view.array_element_type(field_id) {
+                builder.array_elements.push((field_id, element_type as u32));
+            }
+        }
+
+        Ok(builder)
+    }
+
+    /// Seed a builder with the fixed fields of a `#[repr(C, packed)]` struct
+    ///
+    /// `descriptors` lists the struct's fields in declaration order; each is
+    /// given the next `fixed_size()` bytes of `struct_bytes`, so the data
+    /// section reproduces the struct byte for byte (as long as
+    /// `optimize_layout` and `align_fields` stay off). Var types are rejected,
+    /// and the widths must add up to `struct_bytes.len()`.
+    pub fn from_packed_struct(descriptors: &[(u32, FieldType)], struct_bytes: &[u8]) -> Result<Self> {
+        let total: usize = descriptors.iter().map(|(_, field_type)| field_type.fixed_size().unwrap_or(0)).sum();
+        if total != struct_bytes.len() {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: total,
+                got: struct_bytes.len(),
+            });
+        }
+
+        let mut builder = Self::new();
+        let mut offset = 0;
+        for &(field_id, field_type) in descriptors {
+            // Var types have no width and fail in `add_fixed`
+            let width = field_type.fixed_size().unwrap_or(0);
+            builder.add_fixed(field_id, field_type, &struct_bytes[offset..offset + width])?;
+            offset += width;
+        }
+        Ok(builder)
+    }
+
+    /// Stamp the built buffer with an application schema epoch
+    pub fn with_epoch(&mut self, epoch: u64) -> &mut Self {
+        self.epoch = epoch;
+        self
+    }
+
+    /// Stamp each built buffer with the time `build()` ran, read back by `BinaryView::created_at`
+    ///
+    /// Needs the `std` feature for the system clock.
+    #[cfg(feature = "std")]
+    pub fn with_creation_time(&mut self) -> &mut Self {
+        self.creation_time = true;
+        self
+    }
+
+    /// Group fixed fields by width (largest first) in the data section
+    ///
+    /// Only physical placement changes: field ids, the offset table order and
+    /// var fields are untouched, so reads are unaffected.
+    pub fn optimize_layout(&mut self) -> &mut Self {
+        self.optimize_layout = true;
+        self
+    }
+
+    /// Pad the data section so each fixed field is naturally aligned to its width
+    ///
+    /// Alignment is relative to the start of the buffer, so a buffer that is
+    /// itself 8-byte aligned (any `Vec<u8>` from the global allocator on
+    /// mainstream platforms, or a mapped page) yields aligned `&T` references.
+    /// The built header carries `FLAG_ALIGNED_LAYOUT`.
+    pub fn align_fields(&mut self, enabled: bool) -> &mut Self {
+        self.align_fields = enabled;
+        self
+    }
+
+    /// Store a CRC-32 of every field in an optional table, for `BinaryView::verify_field`
+    pub fn field_crcs(&mut self, enabled: bool) -> &mut Self {
+        self.field_crcs = enabled;
+        self
+    }
+
+    /// Store each string field's used length in an optional table
+    ///
+    /// `BinaryView::get_string` then slices by the length instead of scanning
+    /// for the terminator, and embedded NULs are kept. Strings added after
+    /// enabling this need no terminator and may fill their reservation
+    /// exactly; shorter ones are still NUL-terminated for readers that ignore
+    /// the table.
+    pub fn string_lengths(&mut self, enabled: bool) -> &mut Self {
+        self.string_lengths = enabled;
+        self
+    }
+
+    /// Store each blob field's written length in an optional table
+    ///
+    /// `BinaryView::get_blob` then returns exactly the bytes written, rather
+    /// than the whole reservation with its zero padding, and
+    /// `BinaryViewMut::modify_blob` keeps the length current.
+    pub fn blob_lengths(&mut self, enabled: bool) -> &mut Self {
+        self.blob_lengths = enabled;
+        self
+    }
+
+    /// Attach an application key-value pair, stored in an optional metadata section
+    ///
+    /// Metadata sits outside the field schema: it has no offset table entry
+    /// and is read back with `BinaryView::metadata`. Adding a key again
+    /// replaces its value.
+    pub fn add_metadata(&mut self, key: &str, value: &str) -> Result<&mut Self> {
+        if let Some(text) = [key, value].into_iter().find(|text| text.len() > u16::MAX as usize) {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: u16::MAX as usize,
+                got: text.len(),
+            });
+        }
+
+        match self.metadata.iter_mut().find(|(existing, _)| existing == key) {
+            Some((_, existing)) => *existing = value.to_string(),
+            None => self.metadata.push((key.to_string(), value.to_string())),
+        }
+        Ok(self)
+    }
+
+    /// Record a human-readable name for a field, stored in an optional name table
+    pub fn add_named(&mut self, field_id: u32, name: &str) -> Result<&mut Self> {
+        if name.len() > u16::MAX as usize {
+            return Err(SerializationError::FieldTooLarge {
+                field_id,
+                requested: name.len(),
+            });
+        }
+
+        self.names.push((field_id, name.to_string()));
+        Ok(self)
+    }
+
+    /// Add a fixed-size field; `value` must match the type's width
+    pub fn add_fixed(&mut self, field_id: u32, field_type: FieldType, value: &[u8]) -> Result<&mut Self> {
+        let size = check_fixed(field_type, value)?;
+
+        self.fields.push(FieldSpec {
+            field_id,
+            field_type,
+            size,
+            bytes: value.to_vec(),
+        });
+        Ok(self)
+    }
+
+    /// Add a fixed-size field from a typed value
+    ///
+    /// `size_of::<T>()` must match the type's width, otherwise `FieldSizeMismatch`.
+    pub fn add_fixed_field<T: Pod>(&mut self, field_id: u32, field_type: FieldType, value: &T) -> Result<&mut Self> {
+        self.add_fixed(field_id, field_type, bytemuck::bytes_of(value))
+    }
+
+    /// Add a string field reserving `max_size` bytes, including its NUL terminator
+    pub fn add_string_field(&mut self, field_id: u32, max_size: u16, value: &str) -> Result<&mut Self> {
+        self.add_var_with_capacity(field_id, FieldType::String, value.as_bytes(), max_size)
+    }
+
+    /// Add a fixed-size field initialized to, and recording, a default value
+    ///
+    /// The default is stored in an optional table and carried by `schema()`,
+    /// so readers using `BinaryView::get_field_copy_or_default` get it for
+    /// buffers that predate the field.
+    pub fn add_fixed_with_default(&mut self, field_id: u32, field_type: FieldType, default: &[u8]) -> Result<&mut Self> {
+        self.add_fixed(field_id, field_type, default)?;
+        self.defaults.push((field_id, default.to_vec()));
+        Ok(self)
+    }
+
+    /// Add a zero-initialized fixed field whose type and width come from `T`
+    pub fn add<T: AsFieldType>(&mut self, field_id: u32) -> &mut Self {
+        self.fields.push(FieldSpec {
+            field_id,
+            field_type: T::FIELD_TYPE,
+            size: T::SIZE as u16,
+            bytes: vec![0; T::SIZE],
+        });
+        self
+    }
+
+    /// Add an `Enum` field storing `initial` as an index into `values`
+    ///
+    /// The dictionary is kept once in an optional section; the field itself is
+    /// a 2-byte index, so repeated strings cost no var-section space.
+    pub fn add_enum(&mut self, field_id: u32, values: &[&str], initial: &str) -> Result<&mut Self> {
+        if values.len() > u16::MAX as usize {
+            return Err(SerializationError::FieldTooLarge {
+                field_id,
+                requested: values.len(),
+            });
+        }
+        if let Some(value) = values.iter().find(|v| v.len() > u16::MAX as usize) {
+            return Err(SerializationError::FieldTooLarge {
+                field_id,
+                requested: value.len(),
+            });
+        }
+        let index = values.iter().position(|v| *v == initial)
+            .ok_or(SerializationError::UnknownEnumValue { field_id })?;
+
+        self.fields.push(FieldSpec {
+            field_id,
+            field_type: FieldType::Enum,
+            size: 2,
+            bytes: (index as u16).to_le_bytes().to_vec(),
+        });
+        self.dictionaries.push((field_id, values.iter().map(|v| v.to_string()).collect()));
+        Ok(self)
+    }
+
+    /// Add an `Array` field holding `values`, one entry for all of its elements
+    ///
+    /// The element type is recorded in an optional table so readers can tell
+    /// `[Float32; N]` from `[Uint32; N]`; read back with `BinaryView::get_array`.
+    /// The elements must fit in `u16::MAX` bytes.
+    pub fn add_array<T: AsFieldType + Pod>(&mut self, field_id: u32, values: &[T]) -> Result<&mut Self> {
+        let bytes: &[u8] = bytemuck::cast_slice(values);
+        let size = u16::try_from(bytes.len()).map_err(|_| SerializationError::FieldTooLarge {
+            field_id,
+            requested: bytes.len(),
+        })?;
+
+        self.fields.push(FieldSpec {
+            field_id,
+            field_type: FieldType::Array,
+            size,
+            bytes: bytes.to_vec(),
+        });
+        self.array_elements.push((field_id, T::FIELD_TYPE as u32));
+        Ok(self)
+    }
+
+    /// Add a variable-length field reserving exactly enough space for `value`
+    /// (plus a NUL terminator for strings)
+    pub fn add_var(&mut self, field_id: u32, field_type: FieldType, value: &[u8]) -> Result<&mut Self> {
+        let capacity = exact_capacity(field_id, field_type, value)?;
+        self.add_var_with_capacity(field_id, field_type, value, capacity)
+    }
+
+    /// Add a variable-length field reserving `capacity` bytes, with `initial` written
+    /// at the front and the rest zero-padded as headroom for in-place edits
+    pub fn add_var_with_capacity(
+        &mut self,
+        field_id: u32,
+        field_type: FieldType,
+        initial: &[u8],
+        capacity: u16,
+    ) -> Result<&mut Self> {
+        check_var(field_type, initial, capacity, !self.string_lengths)?;
+
+        self.fields.push(FieldSpec {
+            field_id,
+            field_type,
+            size: capacity,
+            bytes: initial.to_vec(),
+        });
+        Ok(self)
+    }
+
+    /// Drop a declared field, along with any name, default, dictionary or element type recorded for it
+    pub fn remove_field(&mut self, field_id: u32) -> Result<&mut Self> {
+        let index = self.fields.iter().position(|f| f.field_id == field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+
+        self.fields.remove(index);
+        self.names.retain(|(id, _)| *id != field_id);
+        self.defaults.retain(|(id, _)| *id != field_id);
+        self.dictionaries.retain(|(id, _)| *id != field_id);
+        self.array_elements.retain(|(id, _)| *id != field_id);
+        Ok(self)
+    }
+
+    /// Field layout the builder will produce, without any data
+    pub fn schema(&self) -> Schema {
+        // Placement order; the offset table itself always keeps declaration order
+        let mut placement: Vec<usize> = (0..self.fields.len()).collect();
+        if self.optimize_layout {
+            placement.sort_by_key(|&i| {
+                let field = &self.fields[i];
+                let width = if field.field_type.is_variable() { 0 } else { field.size };
+                (field.field_type.is_variable(), core::cmp::Reverse(width))
+            });
+        }
+
+        let mut offsets = vec![0u32; self.fields.len()];
+        let mut data_size = 0u32;
+        let mut var_size = 0u32;
+        let data_start = (HEADER_SIZE + self.fields.len() * core::mem::size_of::<OffsetEntry>()) as u32;
+
+        for i in placement {
+            let field = &self.fields[i];
+            let cursor = if field.field_type.is_variable() {
+                &mut var_size
+            } else {
+                if self.align_fields {
+                    let width = self.alignment(field);
+                    let absolute = data_start + data_size;
+                    data_size += (width - absolute % width) % width;
+                }
+                &mut data_size
+            };
+            offsets[i] = *cursor;
+            *cursor += field.size as u32;
+        }
+
+        let entries = self.fields
+            .iter()
+            .zip(offsets)
+            .map(|(field, offset)| OffsetEntry {
+                field_id: field.field_id,
+                offset,
+                field_type: field.field_type as u16,
+                size: field.size,
+            })
+            .collect();
+
+        Schema {
+            entries,
+            data_size,
+            var_size,
+            defaults: self.defaults.clone(),
+            array_elements: self.array_elements.clone(),
+        }
+    }
+
+    /// Lay out the declared fields in order and serialize them
+    pub fn build(&self) -> Result<Vec<u8>> {
+        let schema = self.schema();
+        let mut data = vec![0u8; schema.data_size as usize];
+        let mut var_data = vec![0u8; schema.var_size as usize];
+
+        for (field, entry) in self.fields.iter().zip(schema.entries()) {
+            let section = if field.field_type.is_variable() {
+                &mut var_data
+            } else {
+                &mut data
+            };
+            let start = entry.offset as usize;
+            section[start..start + field.bytes.len()].copy_from_slice(&field.bytes);
+        }
+
+        let crcs: Vec<(u32, u32)> = self.fields
+            .iter()
+            .zip(schema.entries())
+            .map(|(field, entry)| {
+                let section = if field.field_type.is_variable() { &var_data } else { &data };
+                let start = entry.offset as usize;
+                (field.field_id, checksum::crc32(&section[start..start + field.size as usize]))
+            })
+            .collect();
+
+        let extension_area = self.extension_area(&crcs);
+        let mut header = FormatHeader::new(schema.offset_table_size(), schema.data_size, schema.var_size);
+        header.set_schema_epoch(self.epoch);
+        #[cfg(feature = "std")]
+        if self.creation_time {
+            header.set_creation_time(unix_nanos(SystemTime::now()));
+        }
+        if self.align_fields {
+            header.set_flag(FLAG_ALIGNED_LAYOUT);
+        }
+        if !self.names.is_empty() {
+            header.set_flag(FLAG_FIELD_NAMES);
+        }
+        if self.field_crcs {
+            header.set_flag(FLAG_FIELD_CRCS);
+        }
+        if self.string_lengths {
+            header.set_flag(FLAG_STRING_LENGTHS);
+        }
+        if self.blob_lengths {
+            header.set_flag(FLAG_BLOB_LENGTHS);
+        }
+        if !self.dictionaries.is_empty() {
+            header.set_flag(FLAG_ENUM_DICTIONARY);
+        }
+        if !self.array_elements.is_empty() {
+            header.set_flag(FLAG_ARRAY_ELEMENTS);
+        }
+        if !self.defaults.is_empty() {
+            header.set_flag(FLAG_FIELD_DEFAULTS);
+        }
+        if !self.metadata.is_empty() {
+            header.set_flag(FLAG_METADATA);
+        }
+        header.set_extension_size(extension_area.len() as u32);
+
+        for entry in schema.entries() {
+            header.check_placement(entry)?;
+        }
+
+        let mut serializer = BinarySerializer::new();
+        serializer.write_header(header);
+        serializer.write_offset_table(schema.entries())?;
+        serializer.write_data(&data);
+        serializer.write_var_data(&var_data);
+        serializer.write_extension(&extension_area);
+        Ok(serializer.into_buffer())
+    }
+
+    /// Exact length of the buffer `build()` will produce, for preallocation
+    pub fn total_size(&self) -> usize {
+        let crcs = vec![(0, 0); self.fields.len()];
+        self.schema().total_size() + self.extension_area(&crcs).len()
+    }
+
+    /// Boundary a fixed field is aligned to: its width, or its element width for arrays
+    fn alignment(&self, field: &FieldSpec) -> u32 {
+        if field.field_type != FieldType::Array {
+            return field.size as u32;
+        }
+        self.array_elements
+            .iter()
+            .find(|(id, _)| *id == field.field_id)
+            .and_then(|&(_, code)| FieldType::try_from(code as u16).ok()?.fixed_size())
+            .unwrap_or(1) as u32
+    }
+
+    /// Optional sections written after the var section
+    fn extension_area(&self, crcs: &[(u32, u32)]) -> Vec<u8> {
+        let mut area = Vec::new();
+        if !self.names.is_empty() {
+            let table = extension::encode_names(&self.names);
+            extension::write_section(&mut area, extension::TAG_FIELD_NAMES, &table);
+        }
+        if !self.dictionaries.is_empty() {
+            let section = extension::encode_dictionaries(&self.dictionaries);
+            extension::write_section(&mut area, extension::TAG_ENUM_DICTIONARY, &section);
+        }
+        if !self.array_elements.is_empty() {
+            let table = extension::encode_id_values(&self.array_elements);
+            extension::write_section(&mut area, extension::TAG_ARRAY_ELEMENTS, &table);
+        }
+        if !self.defaults.is_empty() {
+            let table = extension::encode_defaults(&self.defaults);
+            extension::write_section(&mut area, extension::TAG_FIELD_DEFAULTS, &table);
+        }
+        if self.field_crcs {
+            extension::write_section(&mut area, extension::TAG_FIELD_CRCS, &extension::encode_id_values(crcs));
+        }
+        if self.string_lengths {
+            let lengths: Vec<(u32, u32)> = self.fields
+                .iter()
+                .filter(|field| field.field_type == FieldType::String)
+                .map(|field| (field.field_id, field.bytes.len() as u32))
+                .collect();
+            let table = extension::encode_id_values(&lengths);
+            extension::write_section(&mut area, extension::TAG_STRING_LENGTHS, &table);
+        }
+        if self.blob_lengths {
+            let lengths: Vec<(u32, u32)> = self.fields
+                .iter()
+                .filter(|field| field.field_type == FieldType::Blob)
+                .map(|field| (field.field_id, field.bytes.len() as u32))
+                .collect();
+            let table = extension::encode_id_values(&lengths);
+            extension::write_section(&mut area, extension::TAG_BLOB_LENGTHS, &table);
+        }
+        if !self.metadata.is_empty() {
+            let section = extension::encode_metadata(&self.metadata);
+            extension::write_section(&mut area, extension::TAG_METADATA, &section);
+        }
+        area
+    }
+}
+
+/// Serialize `(field_id, type, bytes)` tuples in order
+///
+/// Fixed-type bytes must match the type's width; string and blob bytes are the
+/// content, reserved exactly (strings get a terminator). Layout is as for
+/// `SchemaBuilder::build`. Repeated ids fail with `DuplicateFieldId`.
+pub fn build(fields: &[(u32, FieldType, Vec<u8>)]) -> Result<Vec<u8>> {
+    let mut builder = SchemaBuilder::new();
+    let mut seen = BTreeSet::new();
+
+    for (field_id, field_type, bytes) in fields {
+        if !seen.insert(*field_id) {
+            return Err(SerializationError::DuplicateFieldId { field_id: *field_id });
+        }
+        if field_type.is_variable() {
+            builder.add_var(*field_id, *field_type, bytes)?;
+        } else {
+            builder.add_fixed(*field_id, *field_type, bytes)?;
+        }
+    }
+
+    builder.build()
+}
+
+/// A field layout (offset table plus section sizes) that buffers can be checked against
+#[derive(Debug, Clone)]
+pub struct Schema {
+    entries: Vec<OffsetEntry>,
+    data_size: u32,
+    var_size: u32,
+    defaults: Vec<(u32, Vec<u8>)>,
+    array_elements: Vec<(u32, u32)>,
+}
+
+impl Schema {
+    /// Wrap an offset table; section sizes are derived from the furthest field in each
+    ///
+    /// A field ending past `u32::MAX`, which no section can hold, fails with
+    /// `InvalidOffset`.
+    pub fn new(entries: Vec<OffsetEntry>) -> Result<Self> {
+        let mut data_size = 0u32;
+        let mut var_size = 0u32;
+
+        for entry in &entries {
+            let end = entry.offset.checked_add(entry.size as u32).ok_or(SerializationError::InvalidOffset {
+                offset: entry.offset as usize + entry.size as usize,
+                size: u32::MAX as usize,
+            })?;
+            let cursor = if entry.is_variable() {
+                &mut var_size
+            } else {
+                &mut data_size
+            };
+            *cursor = (*cursor).max(end);
+        }
+
+        Ok(Self {
+            entries,
+            data_size,
+            var_size,
+            defaults: Vec::new(),
+            array_elements: Vec::new(),
+        })
+    }
+
+    pub fn entries(&self) -> &[OffsetEntry] {
+        &self.entries
+    }
+
+    pub fn find_entry(&self, field_id: u32) -> Option<&OffsetEntry> {
+        self.entries.iter().find(|e| e.field_id == field_id)
+    }
+
+    /// Default value declared for a field (see `SchemaBuilder::add_fixed_with_default`)
+    pub fn field_default(&self, field_id: u32) -> Option<&[u8]> {
+        self.defaults.iter().find(|(id, _)| *id == field_id).map(|(_, bytes)| bytes.as_slice())
+    }
+
+    /// Element type of an `Array` field declared with `SchemaBuilder::add_array`
+    pub fn array_element_type(&self, field_id: u32) -> Option<FieldType> {
+        let &(_, code) = self.array_elements.iter().find(|(id, _)| *id == field_id)?;
+        FieldType::try_from(code as u16).ok()
+    }
+
+    /// The `(field_id, element type)` pairs behind `array_element_type`
+    pub(crate) fn array_elements(&self) -> &[(u32, u32)] {
+        &self.array_elements
+    }
+
+    pub fn offset_table_size(&self) -> u32 {
+        core::mem::size_of_val(self.entries.as_slice()) as u32
+    }
+
+    pub fn data_size(&self) -> u32 {
+        self.data_size
+    }
+
+    pub fn var_size(&self) -> u32 {
+        self.var_size
+    }
+
+    /// Header, offset table, data and var section bytes for this layout
+    pub fn total_size(&self) -> usize {
+        HEADER_SIZE + self.offset_table_size() as usize + self.data_size as usize + self.var_size as usize
+    }
+
+    /// Check that every declared field is present in `buffer` with the expected type and size
+    ///
+    /// Fields in the buffer that the schema doesn't declare are ignored, and
+    /// fields with a declared default may be absent.
+    pub fn conforms(&self, buffer: &[u8]) -> Result<()> {
+        let view = BinaryView::view(buffer)?;
+
+        for expected in &self.entries {
+            let field_id = expected.field_id;
+            let Some(found) = view.find_entry(field_id) else {
+                if self.field_default(field_id).is_some() {
+                    continue;
+                }
+                return Err(SerializationError::MissingField { field_id });
+            };
+
+            if found.field_type != expected.field_type {
+                return Err(SerializationError::FieldTypeMismatch {
+                    field_id,
+                    expected: expected.field_type,
+                    found: found.field_type,
+                });
+            }
+
+            if found.size != expected.size {
+                return Err(SerializationError::FieldSizeMismatch {
+                    expected: expected.size as usize,
+                    got: found.size as usize,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Like `conforms`, but also rejects fields the schema doesn't declare
+    pub fn conforms_exact(&self, buffer: &[u8]) -> Result<()> {
+        self.conforms(buffer)?;
+
+        let view = BinaryView::view(buffer)?;
+        if let Some(extra) = view.entries().iter().find(|e| self.find_entry(e.field_id).is_none()) {
+            return Err(SerializationError::UnexpectedField {
+                field_id: extra.field_id,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Validate a fixed field's bytes against its type, returning the entry size
+pub(crate) fn check_fixed(field_type: FieldType, value: &[u8]) -> Result<u16> {
+    let width = field_type.fixed_size().ok_or(SerializationError::FieldSizeMismatch {
+        expected: FieldType::Blob as usize,
+        got: field_type as usize,
+    })?;
+
+    if value.len() != width {
+        return Err(SerializationError::FieldSizeMismatch {
+            expected: width,
+            got: value.len(),
+        });
+    }
+
+    Ok(width as u16)
+}
+
+/// Nanoseconds since the Unix epoch, negative for earlier times
+#[cfg(feature = "std")]
+fn unix_nanos(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => since.as_nanos() as i64,
+        Err(before) => -(before.duration().as_nanos() as i64),
+    }
+}
+
+/// Smallest reservation that holds `value` (plus a NUL terminator for strings)
+pub(crate) fn exact_capacity(field_id: u32, field_type: FieldType, value: &[u8]) -> Result<u16> {
+    let requested = match field_type {
+        FieldType::String => value.len() + 1,
+        _ => value.len(),
+    };
+    u16::try_from(requested).map_err(|_| SerializationError::FieldTooLarge { field_id, requested })
+}
+
+/// Validate that a var field's initial content fits its reservation
+///
+/// `terminated` strings need a byte for their NUL; pass `false` when the
+/// buffer records string lengths.
+pub(crate) fn check_var(field_type: FieldType, initial: &[u8], capacity: u16, terminated: bool) -> Result<()> {
+    if !field_type.is_variable() {
+        return Err(SerializationError::FieldSizeMismatch {
+            expected: FieldType::Blob as usize,
+            got: field_type as usize,
+        });
+    }
+
+    // Strings keep room for their terminator, matching `modify_string`
+    let needed = match field_type {
+        FieldType::String if terminated => initial.len() + 1,
+        _ => initial.len(),
+    };
+    if needed > capacity as usize {
+        return Err(SerializationError::FieldSizeMismatch {
+            expected: capacity as usize,
+            got: needed,
+        });
+    }
+
+    Ok(())
+}
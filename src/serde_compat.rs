@@ -0,0 +1,452 @@
+//! serde adapters: any flat `Serialize`/`Deserialize` struct as a biSere buffer
+//!
+//! Each struct field becomes one buffer field whose id is its 1-based
+//! declaration position, as with `#[derive(BiSere)]`. Integers, floats and
+//! `bool` map to their fixed types, `char` and `&str`/`String` to exactly
+//! reserved strings, byte slices (`serde_bytes`) to blobs, and unit enum
+//! variants to their name as a string. `None` fields are left out and read
+//! back as `None`. Nested structs, sequences and maps are rejected.
+
+use crate::error::{Result, SerializationError};
+use crate::format::FieldType;
+use crate::incremental::IncrementalSerializer;
+use crate::serializer::BinaryView;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use serde::de::{self, DeserializeSeed, IntoDeserializer, MapAccess, Visitor};
+use serde::ser::{self, Impossible, Serialize};
+
+impl ser::Error for SerializationError {
+    fn custom<T: Display>(msg: T) -> Self {
+        SerializationError::Serde { message: msg.to_string() }
+    }
+}
+
+impl de::Error for SerializationError {
+    fn custom<T: Display>(msg: T) -> Self {
+        SerializationError::Serde { message: msg.to_string() }
+    }
+}
+
+fn unsupported(what: &str) -> SerializationError {
+    SerializationError::Serde {
+        message: alloc::format!("{} is not supported; only flat structs of primitives and strings are", what),
+    }
+}
+
+/// Serialize a struct into a biSere buffer
+pub fn to_bytes<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>> {
+    let mut out = IncrementalSerializer::new();
+    value.serialize(RecordSerializer { out: &mut out })?;
+    Ok(out.finish())
+}
+
+/// Deserialize a struct from a biSere buffer, borrowing strings and bytes from it
+///
+/// Fields of a compressed var section are copied out of the decompressed
+/// section instead, so they deserialize into owned types such as `String`.
+pub fn from_bytes<'de, T: de::Deserialize<'de>>(bytes: &'de [u8]) -> Result<T> {
+    T::deserialize(RecordDeserializer { view: BinaryView::view(bytes)? })
+}
+
+/// Top-level serializer; only structs (and newtypes around them) are records
+struct RecordSerializer<'a> {
+    out: &'a mut IncrementalSerializer,
+}
+
+/// Struct fields in declaration order, each pushed under its 1-based position
+struct StructSerializer<'a> {
+    out: &'a mut IncrementalSerializer,
+    next_id: u32,
+}
+
+/// Writes one field value under a fixed id
+struct FieldSerializer<'a> {
+    out: &'a mut IncrementalSerializer,
+    field_id: u32,
+}
+
+macro_rules! reject {
+    ($($method:ident($($arg:ty),*) $what:literal;)*) => {
+        $(fn $method(self, $(_: $arg),*) -> Result<Self::Ok> {
+            Err(unsupported($what))
+        })*
+    };
+}
+
+impl<'a> ser::Serializer for RecordSerializer<'a> {
+    type Ok = ();
+    type Error = SerializationError;
+    type SerializeSeq = Impossible<(), SerializationError>;
+    type SerializeTuple = Impossible<(), SerializationError>;
+    type SerializeTupleStruct = Impossible<(), SerializationError>;
+    type SerializeTupleVariant = Impossible<(), SerializationError>;
+    type SerializeMap = Impossible<(), SerializationError>;
+    type SerializeStruct = StructSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), SerializationError>;
+    
+    reject! {
+        serialize_bool(bool) "a top-level bool";
+        serialize_i8(i8) "a top-level integer";
+        serialize_i16(i16) "a top-level integer";
+        serialize_i32(i32) "a top-level integer";
+        serialize_i64(i64) "a top-level integer";
+        serialize_u8(u8) "a top-level integer";
+        serialize_u16(u16) "a top-level integer";
+        serialize_u32(u32) "a top-level integer";
+        serialize_u64(u64) "a top-level integer";
+        serialize_f32(f32) "a top-level float";
+        serialize_f64(f64) "a top-level float";
+        serialize_char(char) "a top-level char";
+        serialize_str(&str) "a top-level string";
+        serialize_bytes(&[u8]) "top-level bytes";
+        serialize_none() "a top-level Option";
+        serialize_unit() "a top-level unit";
+        serialize_unit_struct(&'static str) "a unit struct";
+        serialize_unit_variant(&'static str, u32, &'static str) "a top-level enum";
+    }
+    
+    fn serialize_some<T: Serialize + ?Sized>(self, _: &T) -> Result<()> {
+        Err(unsupported("a top-level Option"))
+    }
+    
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _: &'static str, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+    
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _: &'static str, _: u32, _: &'static str, _: &T) -> Result<()> {
+        Err(unsupported("a top-level enum"))
+    }
+    
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq> {
+        Err(unsupported("a top-level sequence"))
+    }
+    
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple> {
+        Err(unsupported("a top-level tuple"))
+    }
+    
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeTupleStruct> {
+        Err(unsupported("a tuple struct"))
+    }
+    
+    fn serialize_tuple_variant(self, _: &'static str, _: u32, _: &'static str, _: usize) -> Result<Self::SerializeTupleVariant> {
+        Err(unsupported("a top-level enum"))
+    }
+    
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(unsupported("a top-level map"))
+    }
+    
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct> {
+        Ok(StructSerializer { out: self.out, next_id: 1 })
+    }
+    
+    fn serialize_struct_variant(self, _: &'static str, _: u32, _: &'static str, _: usize) -> Result<Self::SerializeStructVariant> {
+        Err(unsupported("a top-level enum"))
+    }
+}
+
+impl ser::SerializeStruct for StructSerializer<'_> {
+    type Ok = ();
+    type Error = SerializationError;
+    
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, _: &'static str, value: &T) -> Result<()> {
+        let field_id = self.next_id;
+        self.next_id += 1;
+        value.serialize(FieldSerializer { out: self.out, field_id })
+    }
+    
+    fn skip_field(&mut self, _: &'static str) -> Result<()> {
+        // Keep later fields at their declaration position
+        self.next_id += 1;
+        Ok(())
+    }
+    
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl FieldSerializer<'_> {
+    fn fixed(self, field_type: FieldType, bytes: &[u8]) -> Result<()> {
+        self.out.push_fixed(self.field_id, field_type, bytes)
+    }
+    
+    fn var(self, field_type: FieldType, bytes: &[u8]) -> Result<()> {
+        self.out.push_field(self.field_id, field_type, bytes, None)
+    }
+}
+
+impl ser::Serializer for FieldSerializer<'_> {
+    type Ok = ();
+    type Error = SerializationError;
+    type SerializeSeq = Impossible<(), SerializationError>;
+    type SerializeTuple = Impossible<(), SerializationError>;
+    type SerializeTupleStruct = Impossible<(), SerializationError>;
+    type SerializeTupleVariant = Impossible<(), SerializationError>;
+    type SerializeMap = Impossible<(), SerializationError>;
+    type SerializeStruct = Impossible<(), SerializationError>;
+    type SerializeStructVariant = Impossible<(), SerializationError>;
+    
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.fixed(FieldType::Bool, &[v as u8])
+    }
+    
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.fixed(FieldType::Int8, &v.to_le_bytes())
+    }
+    
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.fixed(FieldType::Int16, &v.to_le_bytes())
+    }
+    
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.fixed(FieldType::Int32, &v.to_le_bytes())
+    }
+    
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.fixed(FieldType::Int64, &v.to_le_bytes())
+    }
+    
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.fixed(FieldType::Uint8, &v.to_le_bytes())
+    }
+    
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.fixed(FieldType::Uint16, &v.to_le_bytes())
+    }
+    
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.fixed(FieldType::Uint32, &v.to_le_bytes())
+    }
+    
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.fixed(FieldType::Uint64, &v.to_le_bytes())
+    }
+    
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.fixed(FieldType::Float32, &v.to_le_bytes())
+    }
+    
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.fixed(FieldType::Float64, &v.to_le_bytes())
+    }
+    
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+    
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.var(FieldType::String, v.as_bytes())
+    }
+    
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.var(FieldType::Blob, v)
+    }
+    
+    fn serialize_none(self) -> Result<()> {
+        Ok(())
+    }
+    
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+    
+    fn serialize_unit(self) -> Result<()> {
+        Err(unsupported("a unit field"))
+    }
+    
+    fn serialize_unit_struct(self, _: &'static str) -> Result<()> {
+        Err(unsupported("a unit struct field"))
+    }
+    
+    fn serialize_unit_variant(self, _: &'static str, _: u32, variant: &'static str) -> Result<()> {
+        self.serialize_str(variant)
+    }
+    
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _: &'static str, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+    
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _: &'static str, _: u32, _: &'static str, _: &T) -> Result<()> {
+        Err(unsupported("an enum variant with data"))
+    }
+    
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq> {
+        Err(unsupported("a sequence field"))
+    }
+    
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple> {
+        Err(unsupported("a tuple field"))
+    }
+    
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeTupleStruct> {
+        Err(unsupported("a tuple struct field"))
+    }
+    
+    fn serialize_tuple_variant(self, _: &'static str, _: u32, _: &'static str, _: usize) -> Result<Self::SerializeTupleVariant> {
+        Err(unsupported("an enum variant with data"))
+    }
+    
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(unsupported("a map field"))
+    }
+    
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct> {
+        Err(unsupported("a nested struct"))
+    }
+    
+    fn serialize_struct_variant(self, _: &'static str, _: u32, _: &'static str, _: usize) -> Result<Self::SerializeStructVariant> {
+        Err(unsupported("an enum variant with data"))
+    }
+}
+
+/// Top-level deserializer; only structs (and newtypes around them) are records
+struct RecordDeserializer<'de> {
+    view: BinaryView<'de>,
+}
+
+impl<'de> de::Deserializer<'de> for RecordDeserializer<'de> {
+    type Error = SerializationError;
+    
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value> {
+        Err(unsupported("deserializing anything but a struct"))
+    }
+    
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+    
+    fn deserialize_struct<V: Visitor<'de>>(self, _: &'static str, fields: &'static [&'static str], visitor: V) -> Result<V::Value> {
+        visitor.visit_map(FieldsAccess { view: self.view, fields, index: 0 })
+    }
+    
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+/// Struct fields present in the buffer, keyed by name and found by position
+struct FieldsAccess<'de> {
+    view: BinaryView<'de>,
+    fields: &'static [&'static str],
+    index: usize,
+}
+
+impl<'de> MapAccess<'de> for FieldsAccess<'de> {
+    type Error = SerializationError;
+    
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        // Absent fields (a `None`, or one added to the struct later) are skipped
+        while let Some(name) = self.fields.get(self.index) {
+            if self.view.has_field(self.index as u32 + 1) {
+                return seed.deserialize((*name).into_deserializer()).map(Some);
+            }
+            self.index += 1;
+        }
+        Ok(None)
+    }
+    
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let field_id = self.index as u32 + 1;
+        self.index += 1;
+        seed.deserialize(FieldDeserializer { view: &self.view, field_id })
+    }
+}
+
+/// Reads one field, choosing the visitor call from its stored type
+struct FieldDeserializer<'a, 'de> {
+    view: &'a BinaryView<'de>,
+    field_id: u32,
+}
+
+impl<'de> FieldDeserializer<'_, 'de> {
+    /// The field's text, borrowed from the buffer unless the var section is compressed
+    fn string(&self) -> Result<Cow<'de, str>> {
+        let entry = self.view.find_entry(self.field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id: self.field_id })?;
+        if entry.field_type == FieldType::Enum as u16 {
+            return self.view.get_enum(self.field_id).map(Cow::Borrowed);
+        }
+        match self.view.string_content(entry) {
+            Err(SerializationError::CompressedVarSection) => {
+                Ok(Cow::Owned(self.view.get_string(self.field_id)?.to_string()))
+            }
+            content => core::str::from_utf8(content?).map(Cow::Borrowed).map_err(|_| SerializationError::FieldSizeMismatch {
+                expected: 0,
+                got: 0,
+            }),
+        }
+    }
+}
+
+fn visit_text<'de, V: Visitor<'de>>(text: Cow<'de, str>, visitor: V) -> Result<V::Value> {
+    match text {
+        Cow::Borrowed(text) => visitor.visit_borrowed_str(text),
+        Cow::Owned(text) => visitor.visit_string(text),
+    }
+}
+
+impl<'de> de::Deserializer<'de> for FieldDeserializer<'_, 'de> {
+    type Error = SerializationError;
+    
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let view = self.view;
+        let field_id = self.field_id;
+        match view.field_type(field_id)? {
+            FieldType::Int8 => visitor.visit_i8(view.get_field_copy(field_id)?),
+            FieldType::Int16 => visitor.visit_i16(view.get_field_copy(field_id)?),
+            FieldType::Int32 => visitor.visit_i32(view.get_field_copy(field_id)?),
+            FieldType::Int64 => visitor.visit_i64(view.get_field_copy(field_id)?),
+            FieldType::Uint8 => visitor.visit_u8(view.get_field_copy(field_id)?),
+            FieldType::Uint16 => visitor.visit_u16(view.get_field_copy(field_id)?),
+            FieldType::Uint32 => visitor.visit_u32(view.get_field_copy(field_id)?),
+            FieldType::Uint64 => visitor.visit_u64(view.get_field_copy(field_id)?),
+            FieldType::Float32 => visitor.visit_f32(view.get_field_copy(field_id)?),
+            FieldType::Float64 => visitor.visit_f64(view.get_field_copy(field_id)?),
+            FieldType::Bool => visitor.visit_bool(view.get_bool(field_id)?),
+            FieldType::String | FieldType::Enum => visit_text(self.string()?, visitor),
+            FieldType::Blob => match view.get_blob_cow(field_id)? {
+                Cow::Borrowed(bytes) => visitor.visit_borrowed_bytes(bytes),
+                Cow::Owned(bytes) => visitor.visit_byte_buf(bytes),
+            },
+            FieldType::Array => Err(unsupported("an Array field")),
+        }
+    }
+    
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        // Only present fields get here; absent ones are a missing `None`
+        visitor.visit_some(self)
+    }
+    
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let text = self.string()?;
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => visit_text(text, visitor),
+        }
+    }
+    
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+    
+    fn deserialize_enum<V: Visitor<'de>>(self, _: &'static str, _: &'static [&'static str], visitor: V) -> Result<V::Value> {
+        match self.string()? {
+            Cow::Borrowed(text) => visitor.visit_enum(de::value::BorrowedStrDeserializer::new(text)),
+            Cow::Owned(text) => visitor.visit_enum(text.into_deserializer()),
+        }
+    }
+    
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
@@ -1,10 +1,58 @@
+use crate::batch::RecordView;
+use crate::checksum::{self, ChecksumAlgo};
 use crate::error::{Result, SerializationError};
-use crate::format::{FieldType, FormatHeader, OffsetEntry, HEADER_SIZE};
+use crate::extension;
+use crate::schema::Schema;
+use crate::format::{
+    AsFieldType, Endianness, FieldType, FormatHeader, OffsetEntry, FLAG_ALIGNED_LAYOUT, FLAG_ARRAY_ELEMENTS, FLAG_BATCH, FLAG_BLOB_LENGTHS, FLAG_CHECKSUM, FLAG_ENUM_DICTIONARY, FLAG_FIELD_CRCS, FLAG_FIELD_NAMES,
+    FLAG_FIELD_DEFAULTS, FLAG_METADATA, FLAG_SCHEMA_EXTERNAL, FLAG_SORTED_TABLE, FLAG_STRING_LENGTHS, HEADER_SIZE,
+};
 use bytemuck::Pod;
+use alloc::borrow::Cow;
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+use core::num::FpCategory;
+use core::ops::Range;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "zstd")]
+use crate::compression::{self, Codec};
 
 /// High-performance binary serializer with in-place modification support
 pub struct BinarySerializer {
     buffer: Vec<u8>,
+    endianness: Option<Endianness>,
+    sort_table: bool,
+    checksum: Option<ChecksumAlgo>,
+    #[cfg(feature = "zstd")]
+    var_compression: Option<Codec>,
+}
+
+/// Serializer that writes into a caller-provided slice instead of a `Vec`
+///
+/// Mirrors `BinarySerializer` for targets that can't allocate: each write
+/// fails with `BufferTooSmall` once the slice is full, and `finish` returns
+/// the number of bytes written.
+pub struct StackSerializer<'a> {
+    out: &'a mut [u8],
+    len: usize,
+}
+
+/// Serializer that streams each section straight into a `std::io::Write` sink
+///
+/// The header goes out first, so `new` takes the complete header and offset
+/// table up front and checks them as `BinaryView::view` would. Data, var and
+/// extension bytes are then forwarded as they are written, and `finish`
+/// checks that they add up to `total_size()`. Nothing can be patched once
+/// sent: values are written as given (no endianness conversion) and no
+/// checksum is stored, so `FLAG_CHECKSUM` is cleared. Use
+/// `BinarySerializer::finalize` and `write_to` when either is needed.
+#[cfg(feature = "std")]
+pub struct WriteSerializer<W: Write> {
+    writer: W,
+    total_size: usize,
+    written: usize,
 }
 
 /// Zero-copy view into a serialized buffer
@@ -12,6 +60,21 @@ pub struct BinaryView<'a> {
     buffer: &'a [u8],
     header: &'a FormatHeader,
     offset_table: &'a [OffsetEntry],
+    #[cfg(feature = "profiling")]
+    stats: core::cell::RefCell<AccessStats>,
+    /// The decompressed var section, filled on first var field access
+    #[cfg(feature = "zstd")]
+    var_scratch: core::cell::OnceCell<Vec<u8>>,
+}
+
+/// Field lookup counters collected by a view, from `BinaryView::access_stats`
+#[cfg(feature = "profiling")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccessStats {
+    /// Lookups per field id, including ids that were not found
+    pub accesses: std::collections::HashMap<u32, u64>,
+    /// Total offset table entries compared across all lookups
+    pub scan_distance: u64,
 }
 
 /// Mutable view for in-place modification
@@ -21,20 +84,161 @@ pub struct BinaryViewMut<'a> {
     offset_table: &'a mut [OffsetEntry],
 }
 
+/// Space accounting for a buffer, from `BinaryView::utilization`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utilization {
+    /// Logical buffer size, `FormatHeader::total_size()`
+    pub total_size: usize,
+    /// Field content: every fixed field's size plus each var field's used length
+    pub used_bytes: usize,
+    /// Sum of var field reservations
+    pub reserved_var_bytes: usize,
+    /// Reserved var bytes past each field's content
+    pub unused_var_bytes: usize,
+}
+
+/// One offset table entry as yielded by `BinaryView::fields`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldInfo {
+    pub field_id: u32,
+    /// `None` for a type code this version doesn't know
+    pub field_type: Option<FieldType>,
+    /// Absolute byte offset in the buffer, as from `BinaryView::field_offset`
+    pub offset: usize,
+    /// Stored size: the value width, or a var field's full reservation
+    pub size: u16,
+}
+
+/// How one field differs between two buffers, from `BinaryView::diff`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldDiff {
+    /// Only in the view `diff` was called on
+    OnlyInA { field_id: u32 },
+    /// Only in the view passed to `diff`
+    OnlyInB { field_id: u32 },
+    /// In both, with different stored bytes
+    Changed { field_id: u32 },
+}
+
+/// A completed buffer from `BinarySerializer::finish`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinishedBuffer {
+    pub bytes: Vec<u8>,
+    /// The stored whole-buffer checksum, or `None` when the header lacks `FLAG_CHECKSUM`
+    pub checksum: Option<u64>,
+}
+
 impl BinarySerializer {
     pub fn new() -> Self {
         Self {
             buffer: Vec::new(),
+            endianness: None,
+            sort_table: false,
+            checksum: None,
+            #[cfg(feature = "zstd")]
+            var_compression: None,
+        }
+    }
+    
+    /// Produce a buffer whose fixed field values are stored in `endianness`
+    ///
+    /// `write_header` records the byte order, and data written in host order
+    /// (as `bytemuck::bytes_of` produces) is converted by `finish` and
+    /// `finalize`; `into_buffer` returns the bytes as written. Strings, blobs,
+    /// the header and the offset table are never swapped.
+    pub fn with_endianness(mut self, endianness: Endianness) -> Self {
+        self.endianness = Some(endianness);
+        self
+    }
+    
+    /// Write the offset table sorted by `field_id` and flag it `FLAG_SORTED_TABLE`
+    ///
+    /// Readers then find fields by binary search instead of a linear scan,
+    /// which pays off for tables with many entries. Ids must be unique.
+    pub fn with_sorted_table(mut self) -> Self {
+        self.sort_table = true;
+        self
+    }
+    
+    /// Store a whole-buffer checksum computed with `algo` in `finish`
+    ///
+    /// Sets `FLAG_CHECKSUM` and records `algo` in the header, so readers
+    /// verify with the same algorithm. Without this, `finish` stores no
+    /// checksum unless the header already asks for one.
+    pub fn with_checksum(mut self, algo: ChecksumAlgo) -> Self {
+        self.checksum = Some(algo);
+        self
+    }
+    
+    /// Compress the var section with `codec` in `finish`
+    ///
+    /// The header records the codec and the uncompressed size, and `var_size`
+    /// becomes the compressed size on disk. Fixed fields stay in place and
+    /// are read zero-copy as before; `BinaryView` decompresses the var section
+    /// on first var field access. An empty var section, and a batch buffer,
+    /// are left uncompressed. A whole-buffer checksum covers the compressed bytes.
+    #[cfg(feature = "zstd")]
+    pub fn with_var_compression(mut self, codec: Codec) -> Self {
+        self.var_compression = Some(codec);
+        self
+    }
+    
+    pub fn write_header(&mut self, mut header: FormatHeader) {
+        if let Some(endianness) = self.endianness {
+            header.set_endianness(endianness);
+        }
+        if self.sort_table {
+            header.set_flag(FLAG_SORTED_TABLE);
+        }
+        if let Some(algo) = self.checksum {
+            header.set_flag(FLAG_CHECKSUM);
+            header.set_checksum_algo(algo);
         }
+        self.buffer.extend_from_slice(bytemuck::bytes_of(&header.to_le()));
     }
     
-    pub fn write_header(&mut self, header: FormatHeader) {
-        let header_bytes = bytemuck::bytes_of(&header);
-        self.buffer.extend_from_slice(header_bytes);
+    /// Write a header for a buffer without an offset table
+    ///
+    /// Forces `offset_table_size` to 0 and sets `FLAG_SCHEMA_EXTERNAL`; follow
+    /// with `write_data`/`write_var_data` and read back via
+    /// `BinaryView::view_with_schema`.
+    pub fn write_header_external(&mut self, mut header: FormatHeader) {
+        header.offset_table_size = 0;
+        header.set_flag(FLAG_SCHEMA_EXTERNAL);
+        self.write_header(header);
     }
     
-    pub fn write_offset_table(&mut self, entries: &[OffsetEntry]) {
-        let table_bytes = bytemuck::cast_slice(entries);
+    /// Write the offset table; when it directly follows the header, the header
+    /// also records the entry count so readers can cross-check `offset_table_size`
+    ///
+    /// Fails with `DuplicateFieldId`, writing nothing, if an id appears twice.
+    pub fn write_offset_table(&mut self, entries: &[OffsetEntry]) -> Result<()> {
+        if self.sort_table {
+            let mut sorted = entries.to_vec();
+            sorted.sort_by_key(|entry| entry.field_id);
+            if let Some(pair) = sorted.windows(2).find(|pair| pair[0].field_id == pair[1].field_id) {
+                return Err(SerializationError::DuplicateFieldId { field_id: pair[0].field_id });
+            }
+            self.write_table_bytes(entries.len(), bytemuck::cast_slice(&sorted));
+            return Ok(());
+        }
+        
+        check_unique(entries)?;
+        self.write_table_bytes(entries.len(), bytemuck::cast_slice(entries));
+        Ok(())
+    }
+    
+    /// `write_offset_table` for tables whose ids were already checked
+    pub(crate) fn write_offset_table_unchecked(&mut self, entries: &[OffsetEntry]) {
+        self.write_table_bytes(entries.len(), bytemuck::cast_slice(entries));
+    }
+    
+    fn write_table_bytes(&mut self, entry_count: usize, table_bytes: &[u8]) {
+        if self.buffer.len() == HEADER_SIZE {
+            let mut header = bytemuck::pod_read_unaligned::<FormatHeader>(&self.buffer[..HEADER_SIZE]).to_le();
+            header.set_entry_count(entry_count as u32);
+            header.write_to(&mut self.buffer);
+        }
         self.buffer.extend_from_slice(table_bytes);
     }
     
@@ -46,83 +250,1361 @@ impl BinarySerializer {
         self.buffer.extend_from_slice(data);
     }
     
+    /// Write the optional extension area (sized by `FormatHeader::set_extension_size`)
+    pub fn write_extension(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+    
+    /// Check the written sections against the header and seal the buffer
+    ///
+    /// The bytes written must add up to exactly `total_size()`, otherwise
+    /// `LengthMismatch`, and the offset table must pass the checks of
+    /// `BinaryView::view` (skipped for `FLAG_SCHEMA_EXTERNAL` buffers). When
+    /// the header carries `FLAG_CHECKSUM`, the checksum is computed with the
+    /// recorded algorithm and stored.
+    pub fn finish(self) -> Result<FinishedBuffer> {
+        let mut bytes = self.buffer;
+        let mut header = FormatHeader::read_from(&bytes)?;
+        let total_size = header.total_size();
+        if bytes.len() != total_size {
+            return Err(SerializationError::LengthMismatch {
+                expected: total_size,
+                got: bytes.len(),
+            });
+        }
+        if !header.has_flag(FLAG_SCHEMA_EXTERNAL) {
+            BinaryView::checked_sections(&bytes)?;
+            
+            // Values were written in host order; `swap_endianness` flips them to the recorded one
+            if self.endianness.is_some_and(|endianness| endianness != Endianness::native()) {
+                let mut native = header;
+                native.set_endianness(Endianness::native());
+                native.write_to(&mut bytes);
+                crate::ops::swap_endianness(&mut bytes)?;
+            }
+        }
+        #[cfg(feature = "zstd")]
+        if let Some(codec) = self.var_compression {
+            compress_var_section(&mut header, &mut bytes, codec)?;
+        }
+        
+        let checksum = header.has_flag(FLAG_CHECKSUM).then(|| buffer_checksum(&header, &bytes));
+        if let Some(checksum) = checksum {
+            header.checksum = checksum;
+            header.write_to(&mut bytes);
+        }
+        Ok(FinishedBuffer { bytes, checksum })
+    }
+    
+    /// Like `finish`, always storing a whole-buffer checksum
+    ///
+    /// Sets `FLAG_CHECKSUM` on the written header, keeping its recorded
+    /// algorithm (FNV-1a by default). Read back with `BinaryView::view_verified`.
+    pub fn finalize(mut self) -> Result<Vec<u8>> {
+        let mut header = FormatHeader::read_from(&self.buffer)?;
+        header.set_flag(FLAG_CHECKSUM);
+        header.write_to(&mut self.buffer);
+        Ok(self.finish()?.bytes)
+    }
+    
     pub fn into_buffer(self) -> Vec<u8> {
         self.buffer
     }
     
+    /// Write the bytes built so far to `writer`, returning how many were written
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<usize> {
+        writer.write_all(&self.buffer)?;
+        Ok(self.buffer.len())
+    }
+    
     pub fn buffer(&self) -> &[u8] {
         &self.buffer
     }
 }
 
+#[cfg(feature = "std")]
+impl<W: Write> WriteSerializer<W> {
+    /// Write the header and offset table, recording the entry count in the header
+    ///
+    /// `offset_table_size` must hold exactly `entries`, otherwise
+    /// `InconsistentHeader`, and every entry must fit its section.
+    pub fn new(mut writer: W, mut header: FormatHeader, entries: &[OffsetEntry]) -> Result<Self> {
+        header.set_entry_count(entries.len() as u32);
+        header.clear_flag(FLAG_CHECKSUM);
+        header.validate()?;
+        check_unique(entries)?;
+        check_var_section(&header, entries)?;
+        check_sorted(&header, entries)?;
+        for entry in entries {
+            header.check_placement(entry)?;
+        }
+        
+        writer.write_all(bytemuck::bytes_of(&header.to_le()))?;
+        writer.write_all(bytemuck::cast_slice(entries))?;
+        Ok(Self {
+            writer,
+            total_size: header.total_size(),
+            written: HEADER_SIZE + core::mem::size_of_val(entries),
+        })
+    }
+    
+    pub fn write_data(&mut self, data: &[u8]) -> Result<()> {
+        self.write(data)
+    }
+    
+    pub fn write_var_data(&mut self, data: &[u8]) -> Result<()> {
+        self.write(data)
+    }
+    
+    pub fn write_extension(&mut self, data: &[u8]) -> Result<()> {
+        self.write(data)
+    }
+    
+    /// Flush the writer and hand it back
+    ///
+    /// Fails with `LengthMismatch` unless exactly `total_size()` bytes were written.
+    pub fn finish(mut self) -> Result<W> {
+        if self.written != self.total_size {
+            return Err(SerializationError::LengthMismatch {
+                expected: self.total_size,
+                got: self.written,
+            });
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+    
+    fn write(&mut self, bytes: &[u8]) -> Result<()> {
+        let end = self.written + bytes.len();
+        if end > self.total_size {
+            return Err(SerializationError::LengthMismatch {
+                expected: self.total_size,
+                got: end,
+            });
+        }
+        self.writer.write_all(bytes)?;
+        self.written = end;
+        Ok(())
+    }
+}
+
+impl<'a> StackSerializer<'a> {
+    pub fn new(out: &'a mut [u8]) -> Self {
+        Self { out, len: 0 }
+    }
+    
+    pub fn write_header(&mut self, header: FormatHeader) -> Result<()> {
+        self.write(bytemuck::bytes_of(&header.to_le()))
+    }
+    
+    /// Same as `BinarySerializer::write_header_external`
+    pub fn write_header_external(&mut self, mut header: FormatHeader) -> Result<()> {
+        header.offset_table_size = 0;
+        header.set_flag(FLAG_SCHEMA_EXTERNAL);
+        self.write_header(header)
+    }
+    
+    /// Same as `BinarySerializer::write_offset_table`, including the entry
+    /// count patched into a header written just before
+    pub fn write_offset_table(&mut self, entries: &[OffsetEntry]) -> Result<()> {
+        check_unique(entries)?;
+        if self.len == HEADER_SIZE {
+            let mut header = bytemuck::pod_read_unaligned::<FormatHeader>(&self.out[..HEADER_SIZE]).to_le();
+            header.set_entry_count(entries.len() as u32);
+            header.write_to(self.out);
+        }
+        
+        self.write(bytemuck::cast_slice(entries))
+    }
+    
+    pub fn write_data(&mut self, data: &[u8]) -> Result<()> {
+        self.write(data)
+    }
+    
+    pub fn write_var_data(&mut self, data: &[u8]) -> Result<()> {
+        self.write(data)
+    }
+    
+    pub fn write_extension(&mut self, data: &[u8]) -> Result<()> {
+        self.write(data)
+    }
+    
+    /// Bytes written so far
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    
+    /// The serialized prefix of the output slice
+    pub fn buffer(&self) -> &[u8] {
+        &self.out[..self.len]
+    }
+    
+    /// Finish writing and return the number of bytes used
+    pub fn finish(self) -> usize {
+        self.len
+    }
+    
+    fn write(&mut self, bytes: &[u8]) -> Result<()> {
+        let end = self.len + bytes.len();
+        let have = self.out.len();
+        let dest = self.out.get_mut(self.len..end).ok_or(SerializationError::BufferTooSmall {
+            needed: end,
+            have,
+        })?;
+        dest.copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+}
+
 impl<'a> BinaryView<'a> {
     /// Create a view into an existing buffer (zero-copy)
+    ///
+    /// A buffer whose table declares non-empty var fields but whose header
+    /// has `var_size = 0` (a producer that dropped or truncated the var
+    /// section) fails here with `BufferTooSmall` rather than on first access.
     pub fn view(buffer: &'a [u8]) -> Result<Self> {
-        if buffer.len() < HEADER_SIZE {
-            return Err(SerializationError::BufferTooSmall {
-                needed: HEADER_SIZE,
-                have: buffer.len(),
-            });
+        let (header, offset_table) = Self::checked_sections(buffer)?;
+        Ok(BinaryView {
+            buffer,
+            header,
+            offset_table,
+            #[cfg(feature = "profiling")]
+            stats: Default::default(),
+            #[cfg(feature = "zstd")]
+            var_scratch: Default::default(),
+        })
+    }
+    
+    /// Create a view and verify the whole-buffer checksum
+    ///
+    /// `view` followed by `verify`: fails with `ChecksumMismatch` on a corrupted
+    /// buffer. Like `verify`, a buffer without a stored checksum is accepted.
+    pub fn view_verified(buffer: &'a [u8]) -> Result<Self> {
+        let view = Self::view(buffer)?;
+        view.verify()?;
+        Ok(view)
+    }
+    
+    /// Create a view, also rejecting out-of-bounds and overlapping fields up front
+    ///
+    /// `view` leaves a span that runs past the end of the buffer to the
+    /// accessors. This strict pass, meant for untrusted input, additionally
+    /// requires every entry to end within its own section and no two fixed
+    /// fields to share bytes, failing with `InvalidOffset` otherwise. It costs
+    /// a sort of the fixed entries, so `view` stays the cheap default.
+    pub fn view_validated(buffer: &'a [u8]) -> Result<Self> {
+        let view = Self::view(buffer)?;
+        check_spans(view.header, view.offset_table)?;
+        Ok(view)
+    }
+    
+    /// Create a view over a memory-mapped file without reading it into memory
+    ///
+    /// Same checks as `view`: a mapping longer than `total_size()` (a record
+    /// file padded or preallocated past the buffer) is fine and the tail is
+    /// ignored, while a truncated file fails with `BufferTooSmall`. The view
+    /// borrows the mapping; the file must not be modified while it is mapped.
+    #[cfg(feature = "memmap")]
+    pub fn from_mmap(mmap: &'a memmap2::Mmap) -> Result<Self> {
+        Self::view(mmap)
+    }
+    
+    /// Create a view checking only the header and sizes, for hot paths
+    ///
+    /// Skips the per-entry offset table checks of `view` and never verifies a
+    /// checksum. Accessors stay bounds-checked, so a malformed table surfaces
+    /// as errors on access instead of here. Only use it on trusted buffers.
+    pub fn view_unchecked(buffer: &'a [u8]) -> Result<Self> {
+        let header = Self::checked_header(buffer)?;
+        if header.has_flag(FLAG_SCHEMA_EXTERNAL) {
+            return Err(SerializationError::ExternalSchemaRequired);
         }
         
-        let header = bytemuck::from_bytes::<FormatHeader>(&buffer[0..HEADER_SIZE]);
-        header.validate()?;
-        
-        let total_size = header.total_size();
-        if buffer.len() < total_size {
+        let offset_table_start = header.header_size as usize;
+        let offset_table_end = offset_table_start + header.offset_table_size as usize;
+        Ok(BinaryView {
+            buffer,
+            header,
+            offset_table: bytemuck::cast_slice(&buffer[offset_table_start..offset_table_end]),
+            #[cfg(feature = "profiling")]
+            stats: Default::default(),
+            #[cfg(feature = "zstd")]
+            var_scratch: Default::default(),
+        })
+    }
+    
+    /// Every check `view` makes, returning the header and offset table
+    fn checked_sections(buffer: &'a [u8]) -> Result<(&'a FormatHeader, &'a [OffsetEntry])> {
+        let header = Self::checked_header(buffer)?;
+        let offset_table = Self::checked_table(header, buffer)?;
+        Ok((header, offset_table))
+    }
+    
+    /// Create a view over a buffer that may still be growing (tailing)
+    ///
+    /// Only the header and the full offset table need to be present; the
+    /// buffer may stop anywhere after that. Fields whose bytes have not fully
+    /// arrived are reported as `FieldNotFound`, as are sections past the end
+    /// of `buffer`. Re-create the view as more bytes arrive. A complete
+    /// buffer behaves exactly as under `view`.
+    pub fn view_partial(buffer: &'a [u8]) -> Result<Self> {
+        let header = Self::parsed_header(buffer)?;
+        let table_end = header.header_size as usize + header.offset_table_size as usize;
+        if buffer.len() < table_end {
             return Err(SerializationError::BufferTooSmall {
-                needed: total_size,
+                needed: table_end,
                 have: buffer.len(),
             });
         }
         
+        let offset_table = Self::checked_table(header, buffer)?;
+        Ok(BinaryView {
+            buffer,
+            header,
+            offset_table,
+            #[cfg(feature = "profiling")]
+            stats: Default::default(),
+            #[cfg(feature = "zstd")]
+            var_scratch: Default::default(),
+        })
+    }
+    
+    /// In-buffer offset table of a validated header, with every entry placed
+    fn checked_table(header: &'a FormatHeader, buffer: &'a [u8]) -> Result<&'a [OffsetEntry]> {
+        if header.has_flag(FLAG_SCHEMA_EXTERNAL) {
+            return Err(SerializationError::ExternalSchemaRequired);
+        }
+        
         let offset_table_start = header.header_size as usize;
         let offset_table_end = offset_table_start + header.offset_table_size as usize;
         let offset_table = bytemuck::cast_slice::<u8, OffsetEntry>(
             &buffer[offset_table_start..offset_table_end]
         );
+        check_var_section(header, offset_table)?;
+        check_sorted(header, offset_table)?;
+        for entry in offset_table {
+            header.check_placement(entry)?;
+        }
+        
+        Ok(offset_table)
+    }
+    
+    /// Create a view using an offset table supplied out-of-band
+    ///
+    /// Meant for `FLAG_SCHEMA_EXTERNAL` buffers, which store no table; any
+    /// in-buffer table is ignored. Every entry must lie entirely within the
+    /// header's `data_size` or `var_size`, otherwise `MisplacedField`.
+    pub fn view_with_schema(buffer: &'a [u8], entries: &'a [OffsetEntry]) -> Result<Self> {
+        let header = Self::checked_header(buffer)?;
+        check_var_section(header, entries)?;
+        check_sorted(header, entries)?;
+        
+        for entry in entries {
+            let section_size = if entry.is_variable() { header.var_size } else { header.data_size };
+            if entry.offset as u64 + entry.size as u64 > section_size as u64 {
+                return Err(SerializationError::MisplacedField {
+                    field_id: entry.field_id,
+                });
+            }
+        }
         
         Ok(BinaryView {
             buffer,
             header,
-            offset_table,
+            offset_table: entries,
+            #[cfg(feature = "profiling")]
+            stats: Default::default(),
+            #[cfg(feature = "zstd")]
+            var_scratch: Default::default(),
+        })
+    }
+    
+    /// Validated header of a buffer at least `total_size()` long
+    fn checked_header(buffer: &'a [u8]) -> Result<&'a FormatHeader> {
+        let header = Self::parsed_header(buffer)?;
+        let total_size = header.total_size();
+        if buffer.len() < total_size {
+            return Err(SerializationError::BufferTooSmall {
+                needed: total_size,
+                have: buffer.len(),
+            });
+        }
+        
+        Ok(header)
+    }
+    
+    /// Validated header, whatever follows it
+    fn parsed_header(buffer: &'a [u8]) -> Result<&'a FormatHeader> {
+        if buffer.len() < HEADER_SIZE {
+            return Err(SerializationError::BufferTooSmall {
+                needed: HEADER_SIZE,
+                have: buffer.len(),
+            });
+        }
+        
+        let header = bytemuck::from_bytes::<FormatHeader>(&buffer[0..HEADER_SIZE]);
+        header.validate()?;
+        Ok(header)
+    }
+    
+    /// Offset table in stored order
+    pub(crate) fn entries(&self) -> &'a [OffsetEntry] {
+        self.offset_table
+    }
+    
+    #[cfg(feature = "hmac")]
+    pub(crate) fn header(&self) -> &'a FormatHeader {
+        self.header
+    }
+    
+    /// The whole underlying buffer, including any bytes past `total_size()`
+    #[cfg(feature = "hmac")]
+    pub(crate) fn bytes(&self) -> &'a [u8] {
+        self.buffer
+    }
+    
+    /// Application schema epoch recorded by the producer (0 if unset)
+    pub fn schema_epoch(&self) -> u64 {
+        self.header.schema_epoch()
+    }
+    
+    /// Creation time stamped by `SchemaBuilder::with_creation_time`, in Unix nanoseconds
+    pub fn created_at(&self) -> Option<i64> {
+        self.header.creation_time()
+    }
+    
+    /// Byte order of fixed field values recorded in the header
+    pub fn endianness(&self) -> Endianness {
+        self.header.endianness()
+    }
+    
+    /// FNV-1a hash of the buffer's `(field_id, field_type, size)` set
+    ///
+    /// Entries are sorted by id first and offsets are ignored, so buffers with
+    /// the same fields in a different table order or physical layout match.
+    pub fn schema_fingerprint(&self) -> u64 {
+        let mut fields: Vec<(u32, u16, u16)> = self.offset_table
+            .iter()
+            .map(|entry| (entry.field_id, entry.field_type, entry.size))
+            .collect();
+        fields.sort_unstable();
+        
+        fields.iter().fold(checksum::FNV1A64_INIT, |hash, &(field_id, field_type, size)| {
+            let hash = checksum::fnv1a64_update(hash, &field_id.to_le_bytes());
+            let hash = checksum::fnv1a64_update(hash, &field_type.to_le_bytes());
+            checksum::fnv1a64_update(hash, &size.to_le_bytes())
+        })
+    }
+    
+    /// Whether the producer laid out fixed fields naturally aligned
+    ///
+    /// When set and the buffer itself is 8-byte aligned, `get_field_cow`
+    /// always takes the borrowed path.
+    pub fn is_aligned_layout(&self) -> bool {
+        self.header.has_flag(FLAG_ALIGNED_LAYOUT)
+    }
+    
+    /// Find offset entry for a field
+    pub fn find_entry(&self, field_id: u32) -> Option<&OffsetEntry> {
+        let sorted = self.header.has_flag(FLAG_SORTED_TABLE);
+        let position = entry_position(self.offset_table, sorted, field_id);
+        #[cfg(feature = "profiling")]
+        {
+            let len = self.offset_table.len();
+            let mut stats = self.stats.borrow_mut();
+            *stats.accesses.entry(field_id).or_insert(0) += 1;
+            stats.scan_distance += if sorted {
+                // Binary search probes at most this many entries
+                (usize::BITS - len.leading_zeros()) as u64
+            } else {
+                position.map_or(len, |index| index + 1) as u64
+            };
+        }
+        // Only a partial view can be shorter than `total_size()`; hide fields that haven't arrived
+        let complete = self.buffer.len() >= self.header.total_size();
+        position
+            .map(|index| &self.offset_table[index])
+            .filter(|entry| complete || self.field_range(entry).is_ok())
+    }
+    
+    /// Whether the offset table has an entry for `field_id`
+    pub fn has_field(&self, field_id: u32) -> bool {
+        self.find_entry(field_id).is_some()
+    }
+    
+    /// Number of entries in the offset table
+    pub fn field_count(&self) -> usize {
+        self.offset_table.len()
+    }
+    
+    /// Lookups made through `find_entry` (and so every by-id accessor) since the view was created
+    ///
+    /// High counts with a large scan distance point at fields worth moving to
+    /// the front of the offset table.
+    #[cfg(feature = "profiling")]
+    pub fn access_stats(&self) -> AccessStats {
+        self.stats.borrow().clone()
+    }
+    
+    /// Raw bytes of the optional extension area
+    pub(crate) fn extension_area(&self) -> &'a [u8] {
+        let start = self.header.extension_offset();
+        self.section(start, self.header.extension_size() as usize)
+    }
+    
+    /// Human-readable name recorded for a field, if the buffer carries a name table
+    pub fn field_name(&self, field_id: u32) -> Option<&'a str> {
+        if !self.header.has_flag(FLAG_FIELD_NAMES) {
+            return None;
+        }
+        let table = extension::find_section(self.extension_area(), extension::TAG_FIELD_NAMES)?;
+        extension::lookup_name(table, field_id)
+    }
+    
+    /// Default value recorded for a field, if the buffer carries a default table
+    pub fn field_default(&self, field_id: u32) -> Option<&'a [u8]> {
+        if !self.header.has_flag(FLAG_FIELD_DEFAULTS) {
+            return None;
+        }
+        let table = extension::find_section(self.extension_area(), extension::TAG_FIELD_DEFAULTS)?;
+        extension::lookup_default(table, field_id)
+    }
+    
+    /// Value of an application metadata key, if the buffer carries a metadata section
+    pub fn metadata(&self, key: &str) -> Option<&'a str> {
+        self.metadata_iter().find(|(k, _)| *k == key).map(|(_, value)| value)
+    }
+    
+    /// All application metadata pairs in the order they were added
+    pub fn metadata_iter(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        let section = if self.header.has_flag(FLAG_METADATA) {
+            extension::find_section(self.extension_area(), extension::TAG_METADATA)
+        } else {
+            None
+        };
+        extension::metadata_pairs(section.unwrap_or(&[]))
+    }
+    
+    /// Fixed fields sorted by their data-section offset
+    ///
+    /// Useful for sequential scans and spotting gaps or overlaps. Unlike a
+    /// plain walk of the offset table this collects and sorts, so it allocates.
+    pub fn fields_by_offset(&self) -> Vec<&OffsetEntry> {
+        let mut entries: Vec<&OffsetEntry> = self.offset_table
+            .iter()
+            .filter(|e| !e.is_variable())
+            .collect();
+        entries.sort_by_key(|e| e.offset);
+        entries
+    }
+    
+    /// Value dictionary of an `Enum` field, in index order
+    pub fn enum_dictionary(&self, field_id: u32) -> Option<Vec<&'a str>> {
+        if !self.header.has_flag(FLAG_ENUM_DICTIONARY) {
+            return None;
+        }
+        let section = extension::find_section(self.extension_area(), extension::TAG_ENUM_DICTIONARY)?;
+        extension::lookup_dictionary(section, field_id)
+    }
+    
+    /// Resolve an `Enum` field's stored index to its dictionary string
+    pub fn get_enum(&self, field_id: u32) -> Result<&'a str> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        
+        if entry.field_type != FieldType::Enum as u16 {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: FieldType::Enum as usize,
+                got: entry.field_type as usize,
+            });
+        }
+        
+        let index = self.read_fixed::<u16>(entry)?;
+        self.enum_dictionary(field_id)
+            .and_then(|values| values.get(index as usize).copied())
+            .ok_or(SerializationError::UnknownEnumValue { field_id })
+    }
+    
+    /// Read a `Bool` field; any nonzero byte is `true`
+    pub fn get_bool(&self, field_id: u32) -> Result<bool> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        
+        if entry.field_type != FieldType::Bool as u16 {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: FieldType::Bool as usize,
+                got: entry.field_type as usize,
+            });
+        }
+        
+        Ok(self.read_fixed::<u8>(entry)? != 0)
+    }
+    
+    /// Declared type of a field, or `UnknownFieldType` for a code this version doesn't know
+    pub fn field_type(&self, field_id: u32) -> Result<FieldType> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        FieldType::try_from(entry.field_type)
+    }
+    
+    /// Absolute byte offset of a field from the start of the buffer
+    ///
+    /// Fixed fields resolve against the data section and var fields against
+    /// the var section; the result indexes directly into the original buffer.
+    pub fn field_offset(&self, field_id: u32) -> Result<usize> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        
+        let section_start = if entry.is_variable() {
+            self.header.var_section_offset()
+        } else {
+            self.header.data_section_offset()
+        };
+        Ok(section_start + entry.offset as usize)
+    }
+    
+    /// Byte range of a field's full reservation within the buffer
+    ///
+    /// A compressed var section has no such range: `CompressedVarSection`.
+    fn field_range(&self, entry: &OffsetEntry) -> Result<Range<usize>> {
+        if entry.is_variable() && self.header.uncompressed_var_size().is_some() {
+            return Err(SerializationError::CompressedVarSection);
+        }
+        let section_start = if entry.is_variable() {
+            self.header.var_section_offset()
+        } else {
+            self.header.data_section_offset()
+        };
+        let start = section_start + entry.offset as usize;
+        let end = start + entry.size as usize;
+        
+        if end > self.buffer.len() {
+            return Err(SerializationError::InvalidOffset {
+                offset: end,
+                size: self.buffer.len(),
+            });
+        }
+        
+        Ok(start..end)
+    }
+    
+    /// A field's full stored bytes (fixed value or var reservation)
+    pub(crate) fn entry_bytes(&self, entry: &OffsetEntry) -> Result<&'a [u8]> {
+        let range = self.field_range(entry)?;
+        let buffer: &'a [u8] = self.buffer;
+        Ok(&buffer[range])
+    }
+    
+    /// Like `entry_bytes`, but reads var fields of a compressed var section
+    /// from the decompressed copy, borrowing from the view instead of the buffer
+    fn field_bytes(&self, entry: &OffsetEntry) -> Result<&[u8]> {
+        let Some(size) = self.header.uncompressed_var_size().filter(|_| entry.is_variable()) else {
+            return self.entry_bytes(entry);
+        };
+        let var_data = self.decompressed_var(size as usize)?;
+        let start = entry.offset as usize;
+        let end = start + entry.size as usize;
+        var_data.get(start..end).ok_or(SerializationError::InvalidOffset {
+            offset: end,
+            size: var_data.len(),
+        })
+    }
+    
+    /// The var section decompressed, once per view
+    #[cfg(feature = "zstd")]
+    fn decompressed_var(&self, size: usize) -> Result<&[u8]> {
+        if let Some(var_data) = self.var_scratch.get() {
+            return Ok(var_data);
+        }
+        let var_data = compression::decompress(self.var_section(), size)?;
+        Ok(self.var_scratch.get_or_init(|| var_data))
+    }
+    
+    #[cfg(not(feature = "zstd"))]
+    fn decompressed_var(&self, _size: usize) -> Result<&[u8]> {
+        Err(SerializationError::CompressedVarSection)
+    }
+    
+    /// Bytes of a field's reservation holding content
+    ///
+    /// Fixed fields use their whole size. Strings count their text plus
+    /// terminator; blobs count up to their last nonzero byte, since trailing
+    /// zeros are indistinguishable from padding.
+    pub(crate) fn used_len(&self, entry: &OffsetEntry) -> Result<usize> {
+        let bytes = self.field_bytes(entry)?;
+        Ok(if entry.field_type == FieldType::String as u16 {
+            self.trim_string(entry, bytes).len() + 1
+        } else if entry.field_type == FieldType::Blob as u16 && self.has_blob_lengths() {
+            self.trim_blob(entry, bytes).len()
+        } else if entry.field_type == FieldType::Blob as u16 {
+            bytes.iter().rposition(|&b| b != 0).map_or(0, |last| last + 1)
+        } else {
+            bytes.len()
+        })
+    }
+    
+    /// Absolute byte ranges in the data and var sections not holding field content
+    ///
+    /// Covers space between and after fields as well as the unused tail of
+    /// over-reserved var fields (past a string's terminator, or a blob's last
+    /// nonzero byte). Data-section gaps come first, each section's ascending.
+    /// A compressed var section is measured in its decompressed copy, so its
+    /// gaps are where they would lie in the buffer stored uncompressed.
+    pub fn gaps(&self) -> Vec<Range<usize>> {
+        let sections = [
+            (false, self.header.data_section_offset(), self.header.data_size as usize),
+            (true, self.header.var_section_offset(), self.header.var_extent() as usize),
+        ];
+        
+        let mut gaps = Vec::new();
+        for (variable, start, size) in sections {
+            let mut covered = Vec::new();
+            for entry in self.offset_table.iter().filter(|e| e.is_variable() == variable) {
+                let used = self.used_len(entry).map_or(entry.size as usize, |used| used.min(entry.size as usize));
+                let field_start = entry.offset as usize;
+                covered.push(field_start..(field_start + used).min(size));
+            }
+            covered.sort_by_key(|range| range.start);
+            
+            let mut cursor = 0;
+            for range in covered {
+                if range.start > cursor {
+                    gaps.push(start + cursor..start + range.start);
+                }
+                cursor = cursor.max(range.end);
+            }
+            if cursor < size {
+                gaps.push(start + cursor..start + size);
+            }
+        }
+        
+        gaps
+    }
+    
+    /// Report how much of the buffer holds field content versus unused var reservations
+    ///
+    /// Var fields count as used up to their used length, as for `gaps()`: a
+    /// string through its terminator, a blob through its last nonzero byte.
+    /// A compressed var section is measured in its decompressed copy, while
+    /// `total_size` stays the size on disk.
+    pub fn utilization(&self) -> Utilization {
+        let mut utilization = Utilization {
+            total_size: self.header.total_size(),
+            used_bytes: 0,
+            reserved_var_bytes: 0,
+            unused_var_bytes: 0,
+        };
+        
+        for entry in self.offset_table {
+            let size = entry.size as usize;
+            if entry.is_variable() {
+                let used = self.used_len(entry).map_or(size, |used| used.min(size));
+                utilization.used_bytes += used;
+                utilization.reserved_var_bytes += size;
+                utilization.unused_var_bytes += size - used;
+            } else {
+                utilization.used_bytes += size;
+            }
+        }
+        
+        utilization
+    }
+    
+    /// Literal stored bytes of any field (zero-copy), `entry.size` long
+    ///
+    /// Unlike `get_blob` this works for every field type, reading from the
+    /// data or var section as appropriate; no endian conversion is applied.
+    pub fn field_raw_bytes(&self, field_id: u32) -> Result<&'a [u8]> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        self.entry_bytes(entry)
+    }
+    
+    /// Raw pointer to a field's stored bytes and their length, for handing to C
+    ///
+    /// Covers the same bytes as `field_raw_bytes`. The pointer borrows from
+    /// the viewed buffer: it is valid only while that buffer is alive and not
+    /// mutated or moved, and nothing here enforces that once it crosses FFI.
+    pub fn field_ptr_len(&self, field_id: u32) -> Result<(*const u8, usize)> {
+        let bytes = self.field_raw_bytes(field_id)?;
+        Ok((bytes.as_ptr(), bytes.len()))
+    }
+    
+    /// Each field's id and literal stored bytes, in offset table order
+    ///
+    /// The same bytes as `field_raw_bytes` (fixed values or full var
+    /// reservations, no endian conversion), for transforms that treat every
+    /// field alike. Var fields of a compressed var section come from the
+    /// view's decompressed copy; a field that can't be read, such as one in
+    /// a section that fails to decompress, yields an `Err`.
+    pub fn raw_fields(&self) -> impl Iterator<Item = Result<(u32, &[u8])>> + '_ {
+        self.offset_table
+            .iter()
+            .map(|entry| Ok((entry.field_id, self.field_bytes(entry)?)))
+    }
+    
+    /// Every field's id, type, location and size, in offset table order
+    ///
+    /// Walks the table without allocating, for dumping buffers whose field
+    /// ids aren't known in advance.
+    pub fn fields(&self) -> impl Iterator<Item = FieldInfo> + '_ {
+        self.offset_table.iter().map(|entry| {
+            let section_start = if entry.is_variable() {
+                self.header.var_section_offset()
+            } else {
+                self.header.data_section_offset()
+            };
+            FieldInfo {
+                field_id: entry.field_id,
+                field_type: FieldType::try_from(entry.field_type).ok(),
+                offset: section_start + entry.offset as usize,
+                size: entry.size,
+            }
+        })
+    }
+    
+    /// Multi-line description of the header and every field, for debugging
+    ///
+    /// Lists the header sizes, checksum and flags, then one line per offset
+    /// table entry with its id, type, absolute offset, size and the first
+    /// bytes of its stored value in hex. Entries with a type code this version
+    /// doesn't know show the raw code; unreadable bytes show as `<unreadable>`.
+    pub fn debug_dump(&self) -> String {
+        BufferDump(self).to_string()
+    }
+    
+    /// Fields that differ from `other`, by id
+    ///
+    /// Compares the literal stored bytes (fixed values or full var
+    /// reservations, as `field_raw_bytes`) without interpreting types, so a
+    /// changed type, size or byte order counts as a change. A field that
+    /// can't be read on either side also counts as changed, since its bytes
+    /// can't be shown equal. Fields of this view come first in its table
+    /// order, then those only in `other` in its order; identical fields are
+    /// left out.
+    pub fn diff(&self, other: &BinaryView<'_>) -> Vec<FieldDiff> {
+        let mut diffs = Vec::new();
+        for entry in self.offset_table {
+            let field_id = entry.field_id;
+            let Some(theirs) = other.find_entry(field_id) else {
+                diffs.push(FieldDiff::OnlyInA { field_id });
+                continue;
+            };
+            let same = match (self.field_bytes(entry), other.field_bytes(theirs)) {
+                (Ok(ours), Ok(theirs)) => ours == theirs,
+                _ => false,
+            };
+            if !same {
+                diffs.push(FieldDiff::Changed { field_id });
+            }
+        }
+        for entry in other.offset_table {
+            if self.find_entry(entry.field_id).is_none() {
+                diffs.push(FieldDiff::OnlyInB { field_id: entry.field_id });
+            }
+        }
+        diffs
+    }
+    
+    /// Whether every byte of a field's stored `size` is zero
+    pub fn is_field_zero(&self, field_id: u32) -> Result<bool> {
+        Ok(self.field_raw_bytes(field_id)?.iter().all(|&b| b == 0))
+    }
+    
+    /// Copy a single field into a standalone buffer
+    ///
+    /// Var fields keep their full reservation, so in-place edits on the
+    /// extracted buffer have the same headroom as the original. The value
+    /// keeps its byte order, and an `Array` its recorded element type.
+    pub fn extract_field(&self, field_id: u32) -> Result<Vec<u8>> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        let bytes = self.field_bytes(entry)?;
+        
+        let extracted = OffsetEntry {
+            field_id,
+            offset: 0,
+            field_type: entry.field_type,
+            size: entry.size,
+        };
+        let (data, var_data) = if entry.is_variable() {
+            (&[][..], bytes)
+        } else {
+            (bytes, &[][..])
+        };
+        
+        let mut header = FormatHeader::new(
+            core::mem::size_of::<OffsetEntry>() as u32,
+            data.len() as u32,
+            var_data.len() as u32,
+        );
+        header.set_schema_epoch(self.header.schema_epoch());
+        header.set_endianness(self.endianness());
+        let elements: Vec<(u32, u32)> = self.element_table()
+            .and_then(|table| extension::lookup_value(table, field_id))
+            .map(|code| (field_id, code))
+            .into_iter()
+            .collect();
+        let dictionaries: Vec<(u32, Vec<String>)> = self.enum_dictionary(field_id)
+            .map(|values| (field_id, values.into_iter().map(String::from).collect()))
+            .into_iter()
+            .collect();
+        let mut extension_area = crate::ops::element_table_area(&mut header, &elements);
+        crate::ops::push_section(
+            &mut header,
+            &mut extension_area,
+            FLAG_ENUM_DICTIONARY,
+            extension::TAG_ENUM_DICTIONARY,
+            &extension::encode_dictionaries(&dictionaries),
+        );
+        let blob_lengths = if entry.field_type == FieldType::Blob as u16 && self.has_blob_lengths() {
+            vec![(field_id, self.blob_content(entry)?.len() as u32)]
+        } else {
+            Vec::new()
+        };
+        crate::ops::push_section(
+            &mut header,
+            &mut extension_area,
+            FLAG_BLOB_LENGTHS,
+            extension::TAG_BLOB_LENGTHS,
+            &extension::encode_id_values(&blob_lengths),
+        );
+        
+        let mut serializer = BinarySerializer::new();
+        serializer.write_header(header);
+        serializer.write_offset_table(&[extracted])?;
+        serializer.write_data(data);
+        serializer.write_var_data(var_data);
+        serializer.write_extension(&extension_area);
+        Ok(serializer.into_buffer())
+    }
+    
+    /// Get a reference to a fixed-size field (zero-copy)
+    ///
+    /// Fields in packed buffers are often not aligned for `T` (a `u64` after a
+    /// `u32`, say), and a misaligned `&T` is undefined behavior, so such reads
+    /// fail with `MisalignedAccess`. Prefer `get_field_copy`, which reads any
+    /// alignment, or `get_field_cow` to borrow only when possible.
+    pub fn get_field<T: Pod>(&self, field_id: u32) -> Result<&T> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        
+        let data_start = self.header.data_section_offset();
+        let field_offset = data_start + entry.offset as usize;
+        let field_end = field_offset + core::mem::size_of::<T>();
+        
+        if field_end > self.buffer.len() {
+            return Err(SerializationError::InvalidOffset {
+                offset: field_end,
+                size: self.buffer.len(),
+            });
+        }
+        
+        if self.endianness() != Endianness::native() && !entry.is_variable() && core::mem::size_of::<T>() > 1 {
+            return Err(SerializationError::ForeignByteOrder { field_id });
+        }
+        
+        bytemuck::try_from_bytes(&self.buffer[field_offset..field_end]).map_err(|_| {
+            SerializationError::MisalignedAccess {
+                offset: field_offset,
+                align: core::mem::align_of::<T>(),
+            }
         })
     }
     
-    /// Find offset entry for a field
-    pub fn find_entry(&self, field_id: u32) -> Option<&OffsetEntry> {
-        self.offset_table.iter().find(|e| e.field_id == field_id)
+    /// Read a fixed-size field by value, tolerating unaligned storage
+    pub fn get_field_copy<T: Pod>(&self, field_id: u32) -> Result<T> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        self.read_fixed(entry)
+    }
+    
+    /// Read a `Float64` field, rejecting NaN and infinities with `NonFiniteFloat`
+    ///
+    /// Every bit pattern is a valid `f64`, so type and width checks can't
+    /// catch a corrupted value; this catches the ones that are never a
+    /// legitimate measurement. Other field types fail with `FieldTypeMismatch`.
+    pub fn get_f64_finite(&self, field_id: u32) -> Result<f64> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        if entry.field_type != FieldType::Float64 as u16 {
+            return Err(SerializationError::FieldTypeMismatch {
+                field_id,
+                expected: FieldType::Float64 as u16,
+                found: entry.field_type,
+            });
+        }
+        
+        let value = self.read_fixed::<f64>(entry)?;
+        if !value.is_finite() {
+            return Err(SerializationError::NonFiniteFloat { field_id });
+        }
+        Ok(value)
+    }
+    
+    /// Classify a `Float32` or `Float64` field's value (NaN, infinite, zero, subnormal or normal)
+    pub fn float_class(&self, field_id: u32) -> Result<FpCategory> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        match FieldType::try_from(entry.field_type)? {
+            FieldType::Float32 => Ok(self.read_fixed::<f32>(entry)?.classify()),
+            FieldType::Float64 => Ok(self.read_fixed::<f64>(entry)?.classify()),
+            _ => Err(SerializationError::FieldTypeMismatch {
+                field_id,
+                expected: FieldType::Float64 as u16,
+                found: entry.field_type,
+            }),
+        }
+    }
+    
+    /// Read a fixed-size field, falling back to `schema`'s declared default
+    ///
+    /// For readers of evolving schemas: a field the buffer predates reads as
+    /// its default instead of failing with `FieldNotFound`. Fields with no
+    /// declared default still fail.
+    pub fn get_field_copy_or_default<T: Pod>(&self, schema: &Schema, field_id: u32) -> Result<T> {
+        if let Some(entry) = self.find_entry(field_id) {
+            return self.read_fixed(entry);
+        }
+        
+        let bytes = schema.field_default(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        if bytes.len() != core::mem::size_of::<T>() {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: bytes.len(),
+                got: core::mem::size_of::<T>(),
+            });
+        }
+        Ok(bytemuck::pod_read_unaligned(bytes))
+    }
+    
+    /// Read a fixed-size field, borrowing when possible
+    ///
+    /// Returns `Cow::Borrowed` when the field's address in the buffer is
+    /// aligned for `T`, and `Cow::Owned` (an unaligned copy) otherwise, so
+    /// the borrowed path costs nothing and the owned path one small copy.
+    pub fn get_field_cow<T: Pod>(&self, field_id: u32) -> Result<Cow<'a, T>> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        
+        let field_offset = self.header.data_section_offset() + entry.offset as usize;
+        let field_end = field_offset + core::mem::size_of::<T>();
+        let buffer: &'a [u8] = self.buffer;
+        let bytes = buffer.get(field_offset..field_end).ok_or(SerializationError::InvalidOffset {
+            offset: field_end,
+            size: buffer.len(),
+        })?;
+        
+        if self.endianness() != Endianness::native() {
+            return self.read_fixed(entry).map(Cow::Owned);
+        }
+        Ok(match bytemuck::try_from_bytes(bytes) {
+            Ok(value) => Cow::Borrowed(value),
+            Err(_) => Cow::Owned(bytemuck::pod_read_unaligned(bytes)),
+        })
+    }
+    
+    /// Copy a `T` out of the data section at an entry's offset
+    pub(crate) fn read_fixed<T: Pod>(&self, entry: &OffsetEntry) -> Result<T> {
+        let data_start = self.header.data_section_offset();
+        let field_offset = data_start + entry.offset as usize;
+        let field_end = field_offset + core::mem::size_of::<T>();
+        
+        if field_end > self.buffer.len() {
+            return Err(SerializationError::InvalidOffset {
+                offset: field_end,
+                size: self.buffer.len(),
+            });
+        }
+        
+        let value = bytemuck::pod_read_unaligned(&self.buffer[field_offset..field_end]);
+        Ok(entry.convert_value(value, self.value_width(entry), self.endianness(), Endianness::native()))
+    }
+    
+    /// Resolve several field ids with a single pass over the offset table
+    fn resolve_entries(&self, field_ids: &[u32]) -> Result<Vec<&'a OffsetEntry>> {
+        let mut resolved: Vec<Option<&'a OffsetEntry>> = vec![None; field_ids.len()];
+        for entry in self.offset_table {
+            for (slot, &field_id) in resolved.iter_mut().zip(field_ids) {
+                if slot.is_none() && entry.field_id == field_id {
+                    *slot = Some(entry);
+                }
+            }
+        }
+        
+        resolved
+            .into_iter()
+            .zip(field_ids)
+            .map(|(slot, &field_id)| slot.ok_or(SerializationError::FieldNotFound { field_id }))
+            .collect()
+    }
+    
+    /// Read several fixed fields of the same type, in the order requested
+    pub fn get_many<T: Pod>(&self, field_ids: &[u32]) -> Result<Vec<T>> {
+        self.resolve_entries(field_ids)?
+            .into_iter()
+            .map(|entry| self.read_fixed(entry))
+            .collect()
+    }
+    
+    /// Raw bytes of several fields (fixed or var), in the order requested
+    pub fn get_many_bytes(&self, field_ids: &[u32]) -> Result<Vec<&'a [u8]>> {
+        self.resolve_entries(field_ids)?
+            .into_iter()
+            .map(|entry| self.entry_bytes(entry))
+            .collect()
+    }
+    
+    /// FNV-1a checksum over the listed fields' bytes
+    ///
+    /// Fields are hashed in ascending field-id order regardless of the order
+    /// given, so producer and consumer only need to agree on the set of ids.
+    /// Each field contributes its full stored bytes (var fields include their
+    /// unused reservation).
+    pub fn checksum_fields(&self, field_ids: &[u32]) -> Result<u64> {
+        let mut sorted = field_ids.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        
+        Ok(self.get_many_bytes(&sorted)?
+            .into_iter()
+            .fold(checksum::FNV1A64_INIT, checksum::fnv1a64_update))
+    }
+    
+    /// Compare `checksum_fields(field_ids)` against the value stored by
+    /// `BinaryViewMut::store_fields_checksum`
+    pub fn verify_fields_checksum(&self, field_ids: &[u32]) -> Result<()> {
+        let expected = self.header.fields_checksum();
+        let found = self.checksum_fields(field_ids)?;
+        if expected != found {
+            return Err(SerializationError::ChecksumMismatch { expected, found });
+        }
+        Ok(())
+    }
+    
+    /// Checksum of the whole logical buffer, skipping the header's `checksum` word
+    ///
+    /// Uses the algorithm recorded in the header (FNV-1a by default).
+    pub fn compute_checksum(&self) -> u64 {
+        buffer_checksum(self.header, self.buffer)
+    }
+    
+    /// Stored whole-buffer checksum and its algorithm, without verifying it
+    pub fn checksum_info(&self) -> Option<(ChecksumAlgo, u64)> {
+        let stored = self.header.checksum;
+        self.has_checksum().then_some((self.header.checksum_algo(), stored))
+    }
+    
+    /// Whether a whole-buffer checksum was stored
+    ///
+    /// True when `FLAG_CHECKSUM` is set; buffers written before the flag existed
+    /// count as checksummed when the header's `checksum` is nonzero.
+    pub fn has_checksum(&self) -> bool {
+        let stored = self.header.checksum;
+        self.header.has_flag(FLAG_CHECKSUM) || stored != 0
+    }
+    
+    /// Verify the whole-buffer checksum, succeeding without a check when none is stored
+    ///
+    /// Use `has_checksum` to tell "no integrity check" apart from "check passed".
+    pub fn verify(&self) -> Result<()> {
+        if !self.has_checksum() {
+            return Ok(());
+        }
+        
+        let expected = self.header.checksum;
+        let found = self.compute_checksum();
+        if expected != found {
+            return Err(SerializationError::ChecksumMismatch { expected, found });
+        }
+        Ok(())
+    }
+    
+    /// Whether the buffer records each string field's used length
+    pub fn has_string_lengths(&self) -> bool {
+        self.header.has_flag(FLAG_STRING_LENGTHS)
+    }
+    
+    /// A string field's content without its terminator
+    ///
+    /// Slices by the stored length when the buffer carries a string length
+    /// table, which also keeps embedded NULs; otherwise scans the reservation
+    /// for the first NUL.
+    pub(crate) fn string_content(&self, entry: &OffsetEntry) -> Result<&'a [u8]> {
+        Ok(self.trim_string(entry, self.entry_bytes(entry)?))
+    }
+    
+    /// `string_content` for a reservation read from anywhere
+    fn trim_string<'b>(&self, entry: &OffsetEntry, bytes: &'b [u8]) -> &'b [u8] {
+        let stored = if self.has_string_lengths() {
+            extension::find_section(self.extension_area(), extension::TAG_STRING_LENGTHS)
+                .and_then(|table| extension::lookup_value(table, entry.field_id))
+        } else {
+            None
+        };
+        
+        let end = match stored {
+            Some(len) => (len as usize).min(bytes.len()),
+            None => bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len()),
+        };
+        &bytes[..end]
+    }
+    
+    /// Whether the buffer records each blob field's written length
+    pub fn has_blob_lengths(&self) -> bool {
+        self.header.has_flag(FLAG_BLOB_LENGTHS)
+    }
+    
+    /// A blob field's written bytes
+    ///
+    /// Slices by the stored length when the buffer carries a blob length
+    /// table; otherwise the whole reservation, trailing padding included.
+    pub(crate) fn blob_content(&self, entry: &OffsetEntry) -> Result<&'a [u8]> {
+        Ok(self.trim_blob(entry, self.entry_bytes(entry)?))
+    }
+    
+    /// `blob_content` for a reservation read from anywhere
+    fn trim_blob<'b>(&self, entry: &OffsetEntry, bytes: &'b [u8]) -> &'b [u8] {
+        let stored = if self.has_blob_lengths() {
+            extension::find_section(self.extension_area(), extension::TAG_BLOB_LENGTHS)
+                .and_then(|table| extension::lookup_value(table, entry.field_id))
+        } else {
+            None
+        };
+        
+        match stored {
+            Some(len) => &bytes[..(len as usize).min(bytes.len())],
+            None => bytes,
+        }
+    }
+    
+    /// Whether the buffer carries a per-field CRC table
+    pub fn has_field_crcs(&self) -> bool {
+        self.header.has_flag(FLAG_FIELD_CRCS)
     }
     
-    /// Get pointer to a field (zero-copy)
-    /// Note: For unaligned types like f64 in packed structs, this may require copying
-    pub fn get_field<T: Pod>(&self, field_id: u32) -> Result<&T> {
+    /// Check one field against its stored CRC-32, leaving the rest unread
+    ///
+    /// Succeeds without a check when the buffer has no CRC table or the
+    /// table has no record for the field; use `has_field_crcs` to tell these
+    /// apart. A mismatch reports the stored and recomputed CRCs.
+    pub fn verify_field(&self, field_id: u32) -> Result<()> {
         let entry = self.find_entry(field_id)
-            .ok_or_else(|| SerializationError::FieldNotFound { field_id })?;
-        
-        let data_start = self.header.data_section_offset();
-        let field_offset = data_start + entry.offset as usize;
-        let field_end = field_offset + std::mem::size_of::<T>();
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        if !self.has_field_crcs() {
+            return Ok(());
+        }
+        let Some(table) = extension::find_section(self.extension_area(), extension::TAG_FIELD_CRCS) else {
+            return Ok(());
+        };
+        let Some(expected) = extension::lookup_value(table, field_id) else {
+            return Ok(());
+        };
         
-        if field_end > self.buffer.len() {
-            return Err(SerializationError::InvalidOffset {
-                offset: field_end,
-                size: self.buffer.len(),
+        // Only now read the field, which may mean decompressing the var section
+        let found = checksum::crc32(self.field_bytes(entry)?);
+        if expected != found {
+            return Err(SerializationError::ChecksumMismatch {
+                expected: expected as u64,
+                found: found as u64,
             });
         }
+        Ok(())
+    }
+    
+    /// Raw bytes of the fixed data section
+    fn data_section(&self) -> &'a [u8] {
+        let start = self.header.data_section_offset();
+        self.section(start, self.header.data_size as usize)
+    }
+    
+    /// Raw bytes of the variable-length section
+    fn var_section(&self) -> &'a [u8] {
+        let start = self.header.var_section_offset();
+        self.section(start, self.header.var_size as usize)
+    }
+    
+    /// `len` bytes at `start`, or nothing if a partial view hasn't received them all
+    fn section(&self, start: usize, len: usize) -> &'a [u8] {
+        self.buffer.get(start..start + len).unwrap_or(&[])
+    }
+    
+    /// Number of records: the stored count for `FLAG_BATCH` buffers, otherwise 1
+    pub fn record_count(&self) -> usize {
+        if self.header.has_flag(FLAG_BATCH) {
+            self.header.record_count() as usize
+        } else {
+            1
+        }
+    }
+    
+    /// View record `index` through the shared offset table
+    ///
+    /// Record sections are fixed-stride slices of the data and var sections.
+    /// A non-batch buffer is a single record spanning both sections.
+    pub fn record(&self, index: usize) -> Result<RecordView<'a>> {
+        let count = self.record_count();
+        if index >= count {
+            return Err(SerializationError::RecordOutOfRange { index, count });
+        }
         
-        // For potentially unaligned access, use unsafe with read_unaligned
-        // This is safe because we've validated the bounds
-        unsafe {
-            let ptr = self.buffer.as_ptr().add(field_offset) as *const T;
-            Ok(&*ptr)
+        let data = self.data_section();
+        let var_data = self.var_section();
+        let data_stride = self.header.data_size as usize / count;
+        let var_stride = self.header.var_size as usize / count;
+        if data.len() < self.header.data_size as usize || var_data.len() < self.header.var_size as usize {
+            return Err(SerializationError::BufferTooSmall {
+                needed: self.header.total_size(),
+                have: self.buffer.len(),
+            });
         }
+        Ok(RecordView::new(
+            self.offset_table,
+            self.endianness(),
+            self.element_table(),
+            &data[index * data_stride..(index + 1) * data_stride],
+            &var_data[index * var_stride..(index + 1) * var_stride],
+        ))
+    }
+    
+    /// View the data section as a contiguous array of `T` (zero-copy)
+    ///
+    /// The section must be an exact multiple of `size_of::<T>()`. Fails with
+    /// `MisalignedAccess` if the section isn't `T`-aligned in memory; use
+    /// `as_struct_array_copy` in that case.
+    pub fn as_struct_array<T: Pod>(&self) -> Result<&'a [T]> {
+        let data = self.data_section();
+        check_array_len::<T>(data.len())?;
+        
+        bytemuck::try_cast_slice(data).map_err(|_| SerializationError::MisalignedAccess {
+            offset: self.header.data_section_offset(),
+            align: core::mem::align_of::<T>(),
+        })
+    }
+    
+    /// Copy the data section out as a `Vec<T>`, regardless of alignment
+    pub fn as_struct_array_copy<T: Pod>(&self) -> Result<Vec<T>> {
+        let data = self.data_section();
+        check_array_len::<T>(data.len())?;
+        
+        Ok(data
+            .chunks_exact(core::mem::size_of::<T>())
+            .map(bytemuck::pod_read_unaligned)
+            .collect())
     }
     
     /// Get string field (zero-copy)
+    ///
+    /// Reads from the view's decompressed copy when the var section is compressed.
     pub fn get_string(&self, field_id: u32) -> Result<&str> {
         let entry = self.find_entry(field_id)
-            .ok_or_else(|| SerializationError::FieldNotFound { field_id })?;
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
         
         if entry.field_type != FieldType::String as u16 {
             return Err(SerializationError::FieldSizeMismatch {
@@ -131,16 +1613,7 @@ impl<'a> BinaryView<'a> {
             });
         }
         
-        let var_start = self.header.var_section_offset();
-        let string_offset = var_start + entry.offset as usize;
-        
-        // Find null terminator or use size
-        let mut end = string_offset;
-        while end < self.buffer.len() && self.buffer[end] != 0 {
-            end += 1;
-        }
-        
-        std::str::from_utf8(&self.buffer[string_offset..end])
+        core::str::from_utf8(self.trim_string(entry, self.field_bytes(entry)?))
             .map_err(|_| SerializationError::FieldSizeMismatch {
                 expected: 0,
                 got: 0,
@@ -148,9 +1621,16 @@ impl<'a> BinaryView<'a> {
     }
     
     /// Get blob field (zero-copy)
-    pub fn get_blob(&self, field_id: u32) -> Result<&[u8]> {
+    ///
+    /// Exactly the bytes last written when the buffer records blob lengths
+    /// (`SchemaBuilder::blob_lengths`), so a zero-length blob reads back empty.
+    /// Without the table the written length is unknown and this is the full
+    /// reservation, like `get_blob_raw`. A compressed var section can't be
+    /// borrowed from the buffer and fails with `CompressedVarSection`; use
+    /// `get_blob_cow`.
+    pub fn get_blob(&self, field_id: u32) -> Result<&'a [u8]> {
         let entry = self.find_entry(field_id)
-            .ok_or_else(|| SerializationError::FieldNotFound { field_id })?;
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
         
         if entry.field_type != FieldType::Blob as u16 {
             return Err(SerializationError::FieldSizeMismatch {
@@ -159,18 +1639,286 @@ impl<'a> BinaryView<'a> {
             });
         }
         
-        let var_start = self.header.var_section_offset();
-        let blob_offset = var_start + entry.offset as usize;
-        let blob_end = blob_offset + entry.size as usize;
+        self.blob_content(entry)
+    }
+    
+    /// Get a blob field's full reservation, trailing padding included (zero-copy)
+    pub fn get_blob_raw(&self, field_id: u32) -> Result<&'a [u8]> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
         
-        if blob_end > self.buffer.len() {
+        if entry.field_type != FieldType::Blob as u16 {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: FieldType::Blob as usize,
+                got: entry.field_type as usize,
+            });
+        }
+        
+        self.entry_bytes(entry)
+    }
+    
+    /// Get blob field as a `Cow`
+    ///
+    /// Borrows the buffer like `get_blob`, except in a compressed var section,
+    /// where the blob is copied out of the view's decompressed copy.
+    pub fn get_blob_cow(&self, field_id: u32) -> Result<Cow<'a, [u8]>> {
+        match self.get_blob(field_id) {
+            Err(SerializationError::CompressedVarSection) => {
+                let entry = self.find_entry(field_id)
+                    .ok_or(SerializationError::FieldNotFound { field_id })?;
+                Ok(Cow::Owned(self.trim_blob(entry, self.field_bytes(entry)?).to_vec()))
+            }
+            result => result.map(Cow::Borrowed),
+        }
+    }
+    
+    /// Read a blob as a slice of `T`, borrowing when the blob is `T`-aligned
+    ///
+    /// The blob's bytes, as returned by `get_blob_cow`, must be a whole number of `T`s. When its
+    /// address isn't aligned for `T`, or the var section is compressed, the
+    /// elements are copied out with unaligned reads instead.
+    pub fn get_blob_as<T: Pod>(&self, field_id: u32) -> Result<Cow<'a, [T]>> {
+        let bytes = self.get_blob_cow(field_id)?;
+        check_array_len::<T>(bytes.len())?;
+        
+        if let Cow::Borrowed(bytes) = bytes {
+            if let Ok(values) = bytemuck::try_cast_slice(bytes) {
+                return Ok(Cow::Borrowed(values));
+            }
+        }
+        Ok(Cow::Owned(
+            bytes
+                .chunks_exact(core::mem::size_of::<T>())
+                .map(bytemuck::pod_read_unaligned)
+                .collect(),
+        ))
+    }
+    
+    /// View `count` consecutive records of type `T` as one slice (zero-copy)
+    ///
+    /// For buffers that list every record's fields in the table, as when
+    /// packing many structs into one buffer: the records start at
+    /// `start_field` and must be contiguous in the data section, each
+    /// `size_of::<T>()` bytes with the same field types at the same relative
+    /// offsets, otherwise `NonUniformRecords`. Like `get_field`, fails with
+    /// `MisalignedAccess` when the bytes aren't `T`-aligned and with
+    /// `ForeignByteOrder` for multi-byte fields in the other byte order.
+    pub fn get_record_slice<T: Pod>(&self, start_field: u32, count: usize) -> Result<&'a [T]> {
+        let first = *self.find_entry(start_field)
+            .ok_or(SerializationError::FieldNotFound { field_id: start_field })?;
+        let non_uniform = SerializationError::NonUniformRecords { field_id: start_field };
+        if first.is_variable() {
+            return Err(non_uniform);
+        }
+        
+        let stride = core::mem::size_of::<T>();
+        let len = stride.checked_mul(count).ok_or(SerializationError::InvalidOffset {
+            offset: usize::MAX,
+            size: self.header.data_size as usize,
+        })?;
+        check_array_len::<T>(len)?;
+        let start = first.offset as usize;
+        let end = start + len;
+        let data_start = self.header.data_section_offset();
+        if end > self.header.data_size as usize {
             return Err(SerializationError::InvalidOffset {
-                offset: blob_end,
-                size: self.buffer.len(),
+                offset: data_start + end,
+                size: data_start + self.header.data_size as usize,
+            });
+        }
+        
+        // Every field touching the span, by offset; the first record's fields
+        // are the pattern each later record must repeat one stride further on
+        let mut fields: Vec<&OffsetEntry> = self.offset_table
+            .iter()
+            .filter(|entry| !entry.is_variable())
+            .filter(|entry| (entry.offset as usize) < end && entry.offset as usize + entry.size as usize > start)
+            .collect();
+        fields.sort_by_key(|entry| entry.offset);
+        let per_record = fields.iter().take_while(|entry| (entry.offset as usize) < start + stride).count();
+        let uniform = count == 0
+            || (fields.len() == per_record * count
+                && fields[..per_record].iter().all(|entry| {
+                    entry.offset as usize >= start && entry.offset as usize + entry.size as usize <= start + stride
+                })
+                && fields.iter().enumerate().all(|(index, entry)| {
+                    let pattern = fields[index % per_record];
+                    entry.offset as usize == pattern.offset as usize + (index / per_record) * stride
+                        && entry.field_type == pattern.field_type
+                        && entry.size == pattern.size
+                }));
+        if !uniform {
+            return Err(non_uniform);
+        }
+        
+        if self.endianness() != Endianness::native() && fields.iter().any(|entry| entry.size > 1) {
+            return Err(SerializationError::ForeignByteOrder { field_id: start_field });
+        }
+        let bytes = &self.buffer[data_start + start..data_start + end];
+        bytemuck::try_cast_slice(bytes).map_err(|_| SerializationError::MisalignedAccess {
+            offset: data_start + start,
+            align: core::mem::align_of::<T>(),
+        })
+    }
+    
+    /// Element type of an `Array` field, from the array element table
+    pub fn array_element_type(&self, field_id: u32) -> Option<FieldType> {
+        element_type(self.element_table()?, field_id)
+    }
+    
+    /// The `Array` element type table, if the buffer carries one
+    pub(crate) fn element_table(&self) -> Option<&'a [u8]> {
+        if !self.header.has_flag(FLAG_ARRAY_ELEMENTS) {
+            return None;
+        }
+        extension::find_section(self.extension_area(), extension::TAG_ARRAY_ELEMENTS)
+    }
+    
+    /// Width of each value a fixed field holds, for byte-order conversion
+    pub(crate) fn value_width(&self, entry: &OffsetEntry) -> usize {
+        value_width(entry, self.element_table())
+    }
+    
+    /// Borrow an `Array` field's elements as `&[T]` (zero-copy)
+    ///
+    /// `T` must be the recorded element type, otherwise `FieldTypeMismatch`.
+    /// Fails with `MisalignedAccess` if the field isn't `T`-aligned in memory
+    /// and with `ForeignByteOrder` for multi-byte elements stored in the
+    /// other byte order; `get_array_copy` handles both.
+    pub fn get_array<T: AsFieldType + Pod>(&self, field_id: u32) -> Result<&'a [T]> {
+        let bytes = self.array_bytes::<T>(field_id)?;
+        if self.endianness() != Endianness::native() && core::mem::size_of::<T>() > 1 {
+            return Err(SerializationError::ForeignByteOrder { field_id });
+        }
+        
+        let offset = self.field_offset(field_id)?;
+        bytemuck::try_cast_slice(bytes).map_err(|_| SerializationError::MisalignedAccess {
+            offset,
+            align: core::mem::align_of::<T>(),
+        })
+    }
+    
+    /// Copy an `Array` field's elements out in host byte order, regardless of alignment
+    pub fn get_array_copy<T: AsFieldType + Pod>(&self, field_id: u32) -> Result<Vec<T>> {
+        let bytes = self.array_bytes::<T>(field_id)?;
+        let foreign = self.endianness() != Endianness::native();
+        
+        Ok(bytes
+            .chunks_exact(core::mem::size_of::<T>())
+            .map(|chunk| {
+                let mut value: T = bytemuck::pod_read_unaligned(chunk);
+                if foreign {
+                    bytemuck::bytes_of_mut(&mut value).reverse();
+                }
+                value
+            })
+            .collect())
+    }
+    
+    /// An `Array` field's bytes, checked to hold a whole number of `T` elements
+    fn array_bytes<T: AsFieldType + Pod>(&self, field_id: u32) -> Result<&'a [u8]> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        
+        if entry.field_type != FieldType::Array as u16 {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: FieldType::Array as usize,
+                got: entry.field_type as usize,
+            });
+        }
+        
+        let element_type = self.array_element_type(field_id);
+        if element_type != Some(T::FIELD_TYPE) {
+            return Err(SerializationError::FieldTypeMismatch {
+                field_id,
+                expected: T::FIELD_TYPE as u16,
+                found: element_type.map_or(0, |element_type| element_type as u16),
             });
         }
         
-        Ok(&self.buffer[blob_offset..blob_end])
+        let bytes = self.entry_bytes(entry)?;
+        check_array_len::<T>(bytes.len())?;
+        Ok(bytes)
+    }
+}
+
+/// Compact summary: header sizes and metadata, then one `id: Type[size]` per field
+impl core::fmt::Debug for BinaryView<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Copy packed fields out before formatting them
+        let header = *self.header;
+        let (version, data_size, var_size) = (header.version, header.data_size, header.var_size);
+        
+        f.debug_struct("BinaryView")
+            .field("version", &version)
+            .field("len", &self.buffer.len())
+            .field("data_size", &data_size)
+            .field("var_size", &var_size)
+            .field("flags", &format_args!("{:#x}", header.flags()))
+            .field("schema_epoch", &header.schema_epoch())
+            .field("fields", &FieldSummaries(self.offset_table))
+            .finish()
+    }
+}
+
+struct BufferDump<'v, 'a>(&'v BinaryView<'a>);
+
+impl core::fmt::Display for BufferDump<'_, '_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let view = self.0;
+        // Copy packed fields out before formatting them
+        let header = *view.header;
+        writeln!(f, "magic: {:#x}", { header.magic })?;
+        writeln!(f, "version: {}", { header.version })?;
+        writeln!(f, "header_size: {}", { header.header_size })?;
+        writeln!(f, "offset_table_size: {} ({} entries)", { header.offset_table_size }, view.offset_table.len())?;
+        writeln!(f, "data_size: {}", { header.data_size })?;
+        writeln!(f, "var_size: {}", { header.var_size })?;
+        writeln!(f, "extension_size: {}", header.extension_size())?;
+        writeln!(f, "checksum: {:#x}", { header.checksum })?;
+        writeln!(f, "flags: {:#x}", header.flags())?;
+        
+        for (info, entry) in view.fields().zip(view.offset_table) {
+            match info.field_type {
+                Some(field_type) => write!(f, "field {}: {:?}", info.field_id, field_type)?,
+                None => write!(f, "field {}: type {}", info.field_id, { entry.field_type })?,
+            }
+            write!(f, " at {} size {}:", info.offset, info.size)?;
+            match view.field_bytes(entry) {
+                Ok(bytes) => {
+                    // Enough to recognise a value without flooding the output
+                    for byte in bytes.iter().take(16) {
+                        write!(f, " {:02x}", byte)?;
+                    }
+                    if bytes.len() > 16 {
+                        f.write_str(" ..")?;
+                    }
+                }
+                Err(_) => f.write_str(" <unreadable>")?,
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+struct FieldSummaries<'a>(&'a [OffsetEntry]);
+
+impl core::fmt::Debug for FieldSummaries<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.0.iter().map(|entry| FieldSummary(*entry))).finish()
+    }
+}
+
+struct FieldSummary(OffsetEntry);
+
+impl core::fmt::Debug for FieldSummary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (field_id, raw_type, size) = (self.0.field_id, self.0.field_type, self.0.size);
+        match FieldType::try_from(raw_type) {
+            Ok(field_type) => write!(f, "{}: {:?}[{}]", field_id, field_type, size),
+            Err(_) => write!(f, "{}: type {}[{}]", field_id, raw_type, size),
+        }
     }
 }
 
@@ -189,6 +1937,9 @@ impl<'a> BinaryViewMut<'a> {
         {
             let header_check = bytemuck::from_bytes::<FormatHeader>(&buffer[0..HEADER_SIZE]);
             header_check.validate()?;
+            if header_check.has_flag(FLAG_SCHEMA_EXTERNAL) {
+                return Err(SerializationError::ExternalSchemaRequired);
+            }
             
             let total_size = header_check.total_size();
             if buffer_len < total_size {
@@ -197,6 +1948,17 @@ impl<'a> BinaryViewMut<'a> {
                     have: buffer_len,
                 });
             }
+            
+            let offset_table_start = header_check.header_size as usize;
+            let offset_table_end = offset_table_start + header_check.offset_table_size as usize;
+            let offset_table = bytemuck::cast_slice::<u8, OffsetEntry>(
+                &buffer[offset_table_start..offset_table_end]
+            );
+            check_var_section(header_check, offset_table)?;
+            check_sorted(header_check, offset_table)?;
+            for entry in offset_table {
+                header_check.check_placement(entry)?;
+            }
         }
         
         // Use unsafe to get multiple mutable references to non-overlapping regions
@@ -207,8 +1969,8 @@ impl<'a> BinaryViewMut<'a> {
             
             let offset_table_start = header.header_size as usize;
             let offset_table_ptr = header_ptr.add(offset_table_start);
-            let offset_table_len = header.offset_table_size as usize / std::mem::size_of::<OffsetEntry>();
-            let offset_table = std::slice::from_raw_parts_mut(
+            let offset_table_len = header.offset_table_size as usize / core::mem::size_of::<OffsetEntry>();
+            let offset_table = core::slice::from_raw_parts_mut(
                 offset_table_ptr as *mut OffsetEntry,
                 offset_table_len,
             );
@@ -223,15 +1985,26 @@ impl<'a> BinaryViewMut<'a> {
     
     /// Find offset entry for a field
     pub fn find_entry(&self, field_id: u32) -> Option<&OffsetEntry> {
-        self.offset_table.iter().find(|e| e.field_id == field_id)
+        entry_position(self.offset_table, self.header.has_flag(FLAG_SORTED_TABLE), field_id)
+            .map(|index| &self.offset_table[index])
+    }
+    
+    /// Whether the offset table has an entry for `field_id`
+    pub fn has_field(&self, field_id: u32) -> bool {
+        self.find_entry(field_id).is_some()
+    }
+    
+    /// Number of entries in the offset table
+    pub fn field_count(&self) -> usize {
+        self.offset_table.len()
     }
     
     /// Modify a fixed-size field in place
     pub fn modify_field<T: Pod>(&mut self, field_id: u32, value: &T) -> Result<()> {
         let entry = self.find_entry(field_id)
-            .ok_or_else(|| SerializationError::FieldNotFound { field_id })?;
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
         
-        let value_size = std::mem::size_of::<T>();
+        let value_size = core::mem::size_of::<T>();
         if value_size != entry.size as usize {
             return Err(SerializationError::FieldSizeMismatch {
                 expected: entry.size as usize,
@@ -250,11 +2023,158 @@ impl<'a> BinaryViewMut<'a> {
             });
         }
         
-        // Safe: we've validated the bounds
+        let width = self.value_width(entry);
+        let value = entry.convert_value(*value, width, Endianness::native(), self.header.endianness());
+        self.buffer[field_offset..field_end].copy_from_slice(bytemuck::bytes_of(&value));
+        Ok(())
+    }
+    
+    /// Var fields can't be edited in place once the var section is compressed
+    fn check_var_writable(&self) -> Result<()> {
+        match self.header.uncompressed_var_size() {
+            Some(_) => Err(SerializationError::CompressedVarSection),
+            None => Ok(()),
+        }
+    }
+    
+    /// Change a field's id without touching its data
+    ///
+    /// The field's records in the name, CRC, enum dictionary, string and blob
+    /// length, default and array element tables move to the new id with it.
+    pub fn rename_field(&mut self, old_id: u32, new_id: u32) -> Result<()> {
+        let index = self.offset_table.iter().position(|e| e.field_id == old_id)
+            .ok_or(SerializationError::FieldNotFound { field_id: old_id })?;
+        
+        if old_id != new_id && self.find_entry(new_id).is_some() {
+            return Err(SerializationError::DuplicateFieldId { field_id: new_id });
+        }
+        
+        self.offset_table[index].field_id = new_id;
+        for (flag, tag) in [
+            (FLAG_FIELD_NAMES, extension::TAG_FIELD_NAMES),
+            (FLAG_FIELD_CRCS, extension::TAG_FIELD_CRCS),
+            (FLAG_ENUM_DICTIONARY, extension::TAG_ENUM_DICTIONARY),
+            (FLAG_STRING_LENGTHS, extension::TAG_STRING_LENGTHS),
+            (FLAG_FIELD_DEFAULTS, extension::TAG_FIELD_DEFAULTS),
+            (FLAG_ARRAY_ELEMENTS, extension::TAG_ARRAY_ELEMENTS),
+            (FLAG_BLOB_LENGTHS, extension::TAG_BLOB_LENGTHS),
+        ] {
+            if let Some(start) = self.table_id_position(flag, tag, old_id) {
+                self.buffer[start..start + 4].copy_from_slice(&new_id.to_le_bytes());
+            }
+        }
+        if self.header.has_flag(FLAG_SORTED_TABLE) && !is_sorted(self.offset_table) {
+            self.header.clear_flag(FLAG_SORTED_TABLE);
+        }
+        Ok(())
+    }
+    
+    /// Compute `BinaryView::checksum_fields` and store it in the header
+    pub fn store_fields_checksum(&mut self, field_ids: &[u32]) -> Result<u64> {
+        let checksum = BinaryView::view(self.buffer)?.checksum_fields(field_ids)?;
+        self.header.set_fields_checksum(checksum);
+        Ok(checksum)
+    }
+    
+    /// Recompute a field's CRC in the per-field CRC table after editing it
+    ///
+    /// Does nothing when the buffer has no CRC table or no record for the field.
+    pub fn update_field_crc(&mut self, field_id: u32) -> Result<()> {
+        let view = BinaryView::view(self.buffer)?;
+        let entry = view.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        let crc = checksum::crc32(view.entry_bytes(entry)?);
+        
+        self.patch_table_value(FLAG_FIELD_CRCS, extension::TAG_FIELD_CRCS, field_id, crc);
+        Ok(())
+    }
+    
+    /// Overwrite a field's value in a flagged `(field_id, value)` extension table
+    ///
+    /// Does nothing when the flag is clear or the table has no record for the field.
+    fn patch_table_value(&mut self, flag: u64, tag: u32, field_id: u32, value: u32) {
+        if let Some(start) = self.table_value_position(flag, tag, field_id) {
+            self.buffer[start..start + 4].copy_from_slice(&value.to_le_bytes());
+        }
+    }
+    
+    /// Absolute position of a field's record id in a flagged extension table keyed by field id
+    fn table_id_position(&self, flag: u64, tag: u32, field_id: u32) -> Option<usize> {
+        if !self.header.has_flag(flag) {
+            return None;
+        }
+        let area_start = self.header.extension_offset();
+        let area = self.extension_area();
+        let table = extension::find_section_range(area, tag)?;
+        let position = extension::id_position(tag, &area[table.clone()], field_id)?;
+        Some(area_start + table.start + position)
+    }
+    
+    /// Absolute position of a field's value in a flagged `(field_id, value)` extension table
+    fn table_value_position(&self, flag: u64, tag: u32, field_id: u32) -> Option<usize> {
+        if !self.header.has_flag(flag) {
+            return None;
+        }
+        let area_start = self.header.extension_offset();
+        let area = self.extension_area();
+        let table = extension::find_section_range(area, tag)?;
+        let position = extension::value_position(&area[table.clone()], field_id)?;
+        Some(area_start + table.start + position)
+    }
+    
+    fn extension_area(&self) -> &[u8] {
+        let area_start = self.header.extension_offset();
+        &self.buffer[area_start..area_start + self.header.extension_size() as usize]
+    }
+    
+    /// Width of each value a fixed field holds, as `BinaryView` reads it
+    fn value_width(&self, entry: &OffsetEntry) -> usize {
+        let table = if self.header.has_flag(FLAG_ARRAY_ELEMENTS) {
+            extension::find_section(self.extension_area(), extension::TAG_ARRAY_ELEMENTS)
+        } else {
+            None
+        };
+        value_width(entry, table)
+    }
+    
+    /// Set `FLAG_CHECKSUM` and store `BinaryView::compute_checksum` in the header
+    ///
+    /// Keeps the algorithm already recorded (FNV-1a for a fresh buffer). Call
+    /// this last: any later edit, including header metadata, invalidates it.
+    pub fn store_checksum(&mut self) -> Result<u64> {
+        let algo = self.header.checksum_algo();
+        self.store_checksum_with(algo)
+    }
+    
+    /// Like `store_checksum`, recording and using `algo`
+    pub fn store_checksum_with(&mut self, algo: ChecksumAlgo) -> Result<u64> {
+        self.header.set_checksum_algo(algo);
+        self.header.set_flag(FLAG_CHECKSUM);
+        let checksum = BinaryView::view(self.buffer)?.compute_checksum();
+        self.header.checksum = checksum;
+        Ok(checksum)
+    }
+    
+    /// Overwrite the whole data section with `value` in one copy
+    ///
+    /// All-or-nothing: `size_of::<T>()` must equal the header's `data_size`.
+    pub fn replace_data<T: Pod>(&mut self, value: &T) -> Result<()> {
+        let value_size = core::mem::size_of::<T>();
+        let data_size = self.header.data_size as usize;
+        if value_size != data_size {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: data_size,
+                got: value_size,
+            });
+        }
+        
+        let data_start = self.header.data_section_offset();
+        
+        // Safe: view_mut validated the buffer covers the whole data section
         unsafe {
-            std::ptr::copy_nonoverlapping(
+            core::ptr::copy_nonoverlapping(
                 value as *const T as *const u8,
-                self.buffer.as_mut_ptr().add(field_offset),
+                self.buffer.as_mut_ptr().add(data_start),
                 value_size,
             );
         }
@@ -263,9 +2183,13 @@ impl<'a> BinaryViewMut<'a> {
     }
     
     /// Modify a string field in place (must fit in existing space)
+    ///
+    /// Needs a byte for the NUL terminator unless the buffer records the
+    /// field's length (`SchemaBuilder::string_lengths`), in which case the
+    /// value may fill the reservation exactly and contain NULs.
     pub fn modify_string(&mut self, field_id: u32, value: &str) -> Result<()> {
         let entry = self.find_entry(field_id)
-            .ok_or_else(|| SerializationError::FieldNotFound { field_id })?;
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
         
         if entry.field_type != FieldType::String as u16 {
             return Err(SerializationError::FieldSizeMismatch {
@@ -274,11 +2198,18 @@ impl<'a> BinaryViewMut<'a> {
             });
         }
         
+        self.check_var_writable()?;
+        
+        // A recorded length makes the terminator unnecessary
         let value_bytes = value.as_bytes();
-        if value_bytes.len() + 1 > entry.size as usize {
+        let needed = match self.table_value_position(FLAG_STRING_LENGTHS, extension::TAG_STRING_LENGTHS, field_id) {
+            Some(_) => value_bytes.len(),
+            None => value_bytes.len() + 1,
+        };
+        if needed > entry.size as usize {
             return Err(SerializationError::FieldSizeMismatch {
                 expected: entry.size as usize,
-                got: value_bytes.len() + 1,
+                got: needed,
             });
         }
         
@@ -300,13 +2231,74 @@ impl<'a> BinaryViewMut<'a> {
         self.buffer[string_offset..string_offset + value_bytes.len()]
             .copy_from_slice(value_bytes);
         
+        self.patch_table_value(FLAG_STRING_LENGTHS, extension::TAG_STRING_LENGTHS, field_id, value_bytes.len() as u32);
         Ok(())
     }
     
+    /// Store `value` in an `Enum` field; it must already be in the field's dictionary
+    pub fn modify_enum(&mut self, field_id: u32, value: &str) -> Result<()> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        
+        if entry.field_type != FieldType::Enum as u16 {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: FieldType::Enum as usize,
+                got: entry.field_type as usize,
+            });
+        }
+        
+        let index = BinaryView::view(self.buffer)?
+            .enum_dictionary(field_id)
+            .and_then(|values| values.iter().position(|v| *v == value))
+            .ok_or(SerializationError::UnknownEnumValue { field_id })?;
+        self.modify_field(field_id, &(index as u16))
+    }
+    
+    /// Store a `Bool` field as a single 0 or 1 byte
+    pub fn set_bool(&mut self, field_id: u32, value: bool) -> Result<()> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        
+        if entry.field_type != FieldType::Bool as u16 {
+            return Err(SerializationError::FieldSizeMismatch {
+                expected: FieldType::Bool as usize,
+                got: entry.field_type as usize,
+            });
+        }
+        
+        self.modify_field(field_id, &(value as u8))
+    }
+    
+    /// Like `modify_string`, but truncates to fit instead of failing
+    ///
+    /// Keeps as many whole UTF-8 characters as fit before the terminator, or
+    /// in the whole field when its length is recorded, so a multi-byte
+    /// character is never split. Returns the bytes written (excluding the
+    /// terminator).
+    pub fn modify_string_truncating(&mut self, field_id: u32, value: &str) -> Result<usize> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        
+        // A zero-size field can't hold the terminator; `modify_string` reports it
+        let capacity = match self.table_value_position(FLAG_STRING_LENGTHS, extension::TAG_STRING_LENGTHS, field_id) {
+            Some(_) => entry.size as usize,
+            None => (entry.size as usize).saturating_sub(1),
+        };
+        let mut cut = value.len().min(capacity);
+        while !value.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        
+        self.modify_string(field_id, &value[..cut])?;
+        Ok(cut)
+    }
+    
     /// Modify a blob field in place
+    ///
+    /// Updates the recorded length when the buffer carries a blob length table.
     pub fn modify_blob(&mut self, field_id: u32, value: &[u8]) -> Result<()> {
         let entry = self.find_entry(field_id)
-            .ok_or_else(|| SerializationError::FieldNotFound { field_id })?;
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
         
         if entry.field_type != FieldType::Blob as u16 {
             return Err(SerializationError::FieldSizeMismatch {
@@ -315,6 +2307,7 @@ impl<'a> BinaryViewMut<'a> {
             });
         }
         
+        self.check_var_writable()?;
         if value.len() > entry.size as usize {
             return Err(SerializationError::FieldSizeMismatch {
                 expected: entry.size as usize,
@@ -340,10 +2333,224 @@ impl<'a> BinaryViewMut<'a> {
         self.buffer[blob_offset..blob_offset + value.len()]
             .copy_from_slice(value);
         
+        self.patch_table_value(FLAG_BLOB_LENGTHS, extension::TAG_BLOB_LENGTHS, field_id, value.len() as u32);
+        Ok(())
+    }
+    
+    /// Zero a field's full stored bytes, leaving its entry in place
+    ///
+    /// Fixed fields read back as 0, strings as empty, and blobs as all-zero
+    /// bytes (empty with a blob length table); nothing of the old value
+    /// remains in the reservation.
+    pub fn zero_field(&mut self, field_id: u32) -> Result<()> {
+        let entry = self.find_entry(field_id)
+            .ok_or(SerializationError::FieldNotFound { field_id })?;
+        
+        let is_string = entry.field_type == FieldType::String as u16;
+        let is_blob = entry.field_type == FieldType::Blob as u16;
+        if entry.is_variable() {
+            self.check_var_writable()?;
+        }
+        let section_start = if entry.is_variable() {
+            self.header.var_section_offset()
+        } else {
+            self.header.data_section_offset()
+        };
+        let start = section_start + entry.offset as usize;
+        let end = start + entry.size as usize;
+        
+        if end > self.buffer.len() {
+            return Err(SerializationError::InvalidOffset {
+                offset: end,
+                size: self.buffer.len(),
+            });
+        }
+        
+        self.buffer[start..end].fill(0);
+        if is_string {
+            self.patch_table_value(FLAG_STRING_LENGTHS, extension::TAG_STRING_LENGTHS, field_id, 0);
+        } else if is_blob {
+            self.patch_table_value(FLAG_BLOB_LENGTHS, extension::TAG_BLOB_LENGTHS, field_id, 0);
+        }
         Ok(())
     }
 }
 
+/// Reject a missing var section when the table declares var fields that need bytes
+///
+/// `needed` is the logical size the buffer would have with the var section
+/// the entries require; `have` is the size the header declares.
+fn check_var_section(header: &FormatHeader, entries: &[OffsetEntry]) -> Result<()> {
+    let var_needed = entries
+        .iter()
+        .filter(|entry| entry.is_variable())
+        .map(|entry| entry.offset as usize + entry.size as usize)
+        .max()
+        .unwrap_or(0);
+    
+    if header.var_size == 0 && var_needed > 0 {
+        return Err(SerializationError::BufferTooSmall {
+            needed: header.total_size() + var_needed,
+            have: header.total_size(),
+        });
+    }
+    Ok(())
+}
+
+/// Reject a table flagged `FLAG_SORTED_TABLE` whose ids aren't strictly ascending
+fn check_sorted(header: &FormatHeader, entries: &[OffsetEntry]) -> Result<()> {
+    if !header.has_flag(FLAG_SORTED_TABLE) {
+        return Ok(());
+    }
+    match entries.windows(2).find(|pair| pair[0].field_id >= pair[1].field_id) {
+        Some(pair) => Err(SerializationError::UnsortedTable { field_id: pair[1].field_id }),
+        None => Ok(()),
+    }
+}
+
+/// Reject entries ending past their section, or fixed fields sharing bytes
+///
+/// Errors carry absolute buffer positions: `offset` is where the offending
+/// span ends and `size` is the limit it had to stay within, the section end or
+/// the start of the next fixed field.
+fn check_spans(header: &FormatHeader, entries: &[OffsetEntry]) -> Result<()> {
+    let mut fixed = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let (section_start, section_size) = if entry.is_variable() {
+            (header.var_section_offset(), header.var_extent() as usize)
+        } else {
+            (header.data_section_offset(), header.data_size as usize)
+        };
+        let start = entry.offset as usize;
+        let end = start + entry.size as usize;
+        if end > section_size {
+            return Err(SerializationError::InvalidOffset {
+                offset: section_start + end,
+                size: section_start + section_size,
+            });
+        }
+        if !entry.is_variable() && entry.size > 0 {
+            fixed.push((start, end));
+        }
+    }
+    
+    fixed.sort_unstable();
+    match fixed.windows(2).find(|pair| pair[0].1 > pair[1].0) {
+        Some(pair) => Err(SerializationError::InvalidOffset {
+            offset: header.data_section_offset() + pair[0].1,
+            size: header.data_section_offset() + pair[1].0,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Reject a table listing any field id twice
+///
+/// Small and already sorted tables are checked in place; only a large
+/// unsorted table pays for a sorted copy of its ids.
+pub(crate) fn check_unique(entries: &[OffsetEntry]) -> Result<()> {
+    const PAIRWISE_LIMIT: usize = 16;
+    
+    let duplicate = if entries.len() <= PAIRWISE_LIMIT {
+        entries
+            .iter()
+            .enumerate()
+            .find(|(index, entry)| entries[..*index].iter().any(|earlier| earlier.field_id == entry.field_id))
+            .map(|(_, entry)| entry.field_id)
+    } else if is_sorted(entries) {
+        None
+    } else {
+        let mut ids: Vec<u32> = entries.iter().map(|entry| entry.field_id).collect();
+        ids.sort_unstable();
+        ids.windows(2).find(|pair| pair[0] == pair[1]).map(|pair| pair[0])
+    };
+    
+    match duplicate {
+        Some(field_id) => Err(SerializationError::DuplicateFieldId { field_id }),
+        None => Ok(()),
+    }
+}
+
+fn is_sorted(entries: &[OffsetEntry]) -> bool {
+    entries.windows(2).all(|pair| pair[0].field_id < pair[1].field_id)
+}
+
+/// Index of `field_id` in the table: binary search when `sorted`, otherwise a linear scan
+fn entry_position(entries: &[OffsetEntry], sorted: bool, field_id: u32) -> Option<usize> {
+    if sorted {
+        entries.binary_search_by_key(&field_id, |entry| entry.field_id).ok()
+    } else {
+        entries.iter().position(|entry| entry.field_id == field_id)
+    }
+}
+
+/// Ensure `len` bytes divide evenly into elements of `T`
+fn check_array_len<T: Pod>(len: usize) -> Result<()> {
+    let element_size = core::mem::size_of::<T>();
+    if element_size == 0 || !len.is_multiple_of(element_size) {
+        return Err(SerializationError::FieldSizeMismatch {
+            expected: element_size,
+            got: len,
+        });
+    }
+    Ok(())
+}
+
+/// Whole-buffer checksum over everything but the header's `checksum` field
+fn buffer_checksum(header: &FormatHeader, buffer: &[u8]) -> u64 {
+    let checksum_start = core::mem::offset_of!(FormatHeader, checksum);
+    let checksum_end = checksum_start + core::mem::size_of::<u64>();
+    header.checksum_algo().checksum(&[
+        &buffer[..checksum_start],
+        &buffer[checksum_end..header.total_size().min(buffer.len())],
+    ])
+}
+
+/// Whether `BinaryView::view` would accept `buffer`
+///
+/// Runs the same header, size, offset table and placement checks without
+/// building a view, for cheaply filtering candidate buffers before parsing.
+/// Use `view` itself to learn why a buffer is rejected.
+pub fn is_valid(buffer: &[u8]) -> bool {
+    BinaryView::checked_sections(buffer).is_ok()
+}
+
+/// Replace the var section with its `codec`-compressed bytes and record the uncompressed size
+///
+/// Empty var sections and batch buffers, whose record count occupies the
+/// same header slot, are left as they are.
+#[cfg(feature = "zstd")]
+fn compress_var_section(header: &mut FormatHeader, bytes: &mut Vec<u8>, codec: Codec) -> Result<()> {
+    if header.var_size == 0 || header.has_flag(FLAG_BATCH) {
+        return Ok(());
+    }
+    let range = header.var_section_offset()..header.extension_offset();
+    let compressed = codec.compress(&bytes[range.clone()])?;
+    header.set_var_compressed(header.var_size);
+    header.var_size = compressed.len() as u32;
+    bytes.splice(range, compressed);
+    header.write_to(bytes);
+    Ok(())
+}
+
+/// An `Array` field's element type as recorded in an element type table
+fn element_type(table: &[u8], field_id: u32) -> Option<FieldType> {
+    let code = extension::lookup_value(table, field_id)?;
+    FieldType::try_from(u16::try_from(code).ok()?).ok()
+}
+
+/// Width of each value a fixed field holds: the whole field for a scalar, or
+/// an `Array`'s element width from `element_table` (1, never swapped, when unrecorded)
+pub(crate) fn value_width(entry: &OffsetEntry, element_table: Option<&[u8]>) -> usize {
+    if entry.field_type != FieldType::Array as u16 {
+        return entry.size as usize;
+    }
+    element_table
+        .and_then(|table| element_type(table, entry.field_id))
+        .and_then(FieldType::fixed_size)
+        .unwrap_or(1)
+}
+
 impl Default for BinarySerializer {
     fn default() -> Self {
         Self::new()
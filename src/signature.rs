@@ -0,0 +1,88 @@
+//! HMAC-SHA256 signatures for buffers crossing untrusted channels
+//!
+//! The signature is stored as the last extension section. The MAC covers
+//! every byte of the logical buffer before the 32-byte MAC itself: header
+//! (including section sizes and flags), offset table, both data sections and
+//! the rest of the extension area, plus the signature section's own tag and
+//! length.
+
+use crate::error::{Result, SerializationError};
+use crate::extension;
+use crate::format::{FormatHeader, FLAG_CHECKSUM, FLAG_SIGNED};
+use crate::serializer::{BinarySerializer, BinaryView};
+use alloc::vec::Vec;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Extension tag of the signature section, always the last section
+const TAG_SIGNATURE: u32 = 5;
+/// Length of an HMAC-SHA256 tag
+const SIGNATURE_LEN: usize = 32;
+
+fn mac(key: &[u8], signed_bytes: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC takes any key length");
+    mac.update(signed_bytes);
+    mac
+}
+
+impl BinarySerializer {
+    /// Append an HMAC-SHA256 of the finished buffer under `key` and return it
+    ///
+    /// The buffer goes through `finish` first, so it is checked the same way
+    /// and fixed values are converted to the `with_endianness` byte order
+    /// before they are signed. `finalize_signed` then sets `FLAG_SIGNED` and
+    /// grows the extension area by the signature section. Sign last: a later
+    /// edit, including `store_checksum`, breaks the signature.
+    ///
+    /// A checksum would cover the MAC and the MAC the checksum, so a header
+    /// with `FLAG_CHECKSUM` (as `with_checksum` writes) fails with
+    /// `ChecksumWithSignature`; the signature already detects corruption.
+    pub fn finalize_signed(self, key: &[u8]) -> Result<Vec<u8>> {
+        if FormatHeader::read_from(self.buffer())?.has_flag(FLAG_CHECKSUM) {
+            return Err(SerializationError::ChecksumWithSignature);
+        }
+        let mut buffer = self.finish()?.bytes;
+        let mut header = FormatHeader::read_from(&buffer)?;
+        
+        let mut section = Vec::with_capacity(8 + SIGNATURE_LEN);
+        extension::write_section(&mut section, TAG_SIGNATURE, &[0; SIGNATURE_LEN]);
+        header.set_flag(FLAG_SIGNED);
+        header.set_extension_size(header.extension_size() + section.len() as u32);
+        header.write_to(&mut buffer);
+        buffer.extend_from_slice(&section[..8]);
+        
+        let tag = mac(key, &buffer).finalize().into_bytes();
+        buffer.extend_from_slice(&tag);
+        Ok(buffer)
+    }
+}
+
+impl BinaryView<'_> {
+    /// Check the buffer's HMAC-SHA256 signature under `key`
+    ///
+    /// Fails with `SignatureMissing` when the buffer isn't signed and
+    /// `SignatureMismatch` when any covered byte differs or the signature
+    /// section isn't where `finalize_signed` put it. The comparison is
+    /// constant-time.
+    pub fn verify_signature(&self, key: &[u8]) -> Result<()> {
+        let header = self.header();
+        if !header.has_flag(FLAG_SIGNED) {
+            return Err(SerializationError::SignatureMissing);
+        }
+        
+        let area = self.extension_area();
+        let section = extension::find_section_range(area, TAG_SIGNATURE)
+            .ok_or(SerializationError::SignatureMissing)?;
+        if section.len() != SIGNATURE_LEN || section.end != area.len() {
+            return Err(SerializationError::SignatureMismatch);
+        }
+        
+        let tag_start = header.extension_offset() + section.start;
+        let buffer = self.bytes();
+        mac(key, &buffer[..tag_start])
+            .verify_slice(&buffer[tag_start..tag_start + SIGNATURE_LEN])
+            .map_err(|_| SerializationError::SignatureMismatch)
+    }
+}
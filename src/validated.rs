@@ -0,0 +1,274 @@
+//! Parse-once reader that resolves and checks every field up front
+
+use crate::error::{Result, SerializationError};
+use crate::format::{AsFieldType, Endianness, FieldType, OffsetEntry};
+use crate::serializer::BinaryView;
+use crate::value::FieldValue;
+use bytemuck::Pod;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// A field resolved to its slice of the buffer
+#[derive(Debug, Clone, Copy)]
+enum Resolved<'a> {
+    Fixed(FieldType, &'a [u8]),
+    String(&'a str, &'a [u8]),
+    Blob(&'a [u8], &'a [u8]),
+}
+
+/// Read-only view whose fields were bounds-, type- and overlap-checked at construction
+///
+/// Every field is resolved once into a slice of the buffer (strings also to
+/// their UTF-8 text) and cached by id, so accessors are a map lookup plus a
+/// type check, with no bounds arithmetic per call.
+pub struct ValidatedView<'a> {
+    view: BinaryView<'a>,
+    fields: BTreeMap<u32, Resolved<'a>>,
+}
+
+impl<'a> ValidatedView<'a> {
+    /// Validate `buffer` fully and cache each field's location
+    ///
+    /// Fails on unknown field types, fixed sizes that disagree with their
+    /// type, fields past the end of the buffer, duplicate ids, fields that
+    /// overlap within a section, and strings that aren't valid UTF-8. Fields
+    /// are borrowed from `buffer`, so var fields of a compressed var section
+    /// fail with `CompressedVarSection`.
+    pub fn new(buffer: &'a [u8]) -> Result<Self> {
+        let view = BinaryView::view(buffer)?;
+        let mut fields = BTreeMap::new();
+        let mut spans = Vec::with_capacity(view.entries().len());
+        
+        for entry in view.entries() {
+            let field_id = entry.field_id;
+            let field_type = FieldType::try_from(entry.field_type)?;
+            if let Some(width) = field_type.fixed_size() {
+                if width != entry.size as usize {
+                    return Err(SerializationError::FieldSizeMismatch {
+                        expected: width,
+                        got: entry.size as usize,
+                    });
+                }
+            }
+            
+            let bytes = view.entry_bytes(entry)?;
+            let resolved = match field_type {
+                FieldType::String => {
+                    let text = core::str::from_utf8(view.string_content(entry)?).map_err(|_| SerializationError::FieldSizeMismatch {
+                        expected: 0,
+                        got: 0,
+                    })?;
+                    Resolved::String(text, bytes)
+                }
+                FieldType::Blob => Resolved::Blob(view.blob_content(entry)?, bytes),
+                _ => Resolved::Fixed(field_type, bytes),
+            };
+            
+            if fields.insert(field_id, resolved).is_some() {
+                return Err(SerializationError::DuplicateFieldId { field_id });
+            }
+            spans.push((entry.is_variable(), entry.offset, entry.offset + entry.size as u32, field_id));
+        }
+        
+        spans.sort_unstable();
+        for pair in spans.windows(2) {
+            let (section, _, end, first) = pair[0];
+            let (next_section, next_start, _, second) = pair[1];
+            if section == next_section && next_start < end {
+                return Err(SerializationError::OverlappingFields { first, second });
+            }
+        }
+        
+        Ok(Self { view, fields })
+    }
+    
+    /// The underlying unchecked view
+    pub fn view(&self) -> &BinaryView<'a> {
+        &self.view
+    }
+    
+    /// Number of fields
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+    
+    fn resolve(&self, field_id: u32) -> Result<Resolved<'a>> {
+        self.fields.get(&field_id).copied().ok_or(SerializationError::FieldNotFound { field_id })
+    }
+    
+    /// Read a fixed field as `T`, which must match the stored type exactly
+    ///
+    /// The value is returned in host byte order, as from `get_field_copy`.
+    pub fn get<T: AsFieldType + Pod>(&self, field_id: u32) -> Result<T> {
+        match self.resolve(field_id)? {
+            Resolved::Fixed(field_type, bytes) if field_type == T::FIELD_TYPE => {
+                let entry = self.view.find_entry(field_id)
+                    .ok_or(SerializationError::FieldNotFound { field_id })?;
+                let value = bytemuck::pod_read_unaligned(bytes);
+                Ok(entry.convert_value(value, bytes.len(), self.view.endianness(), Endianness::native()))
+            }
+            other => Err(SerializationError::FieldTypeMismatch {
+                field_id,
+                expected: T::FIELD_TYPE as u16,
+                found: other.field_type() as u16,
+            }),
+        }
+    }
+    
+    /// Get a string field (zero-copy, validated at construction)
+    pub fn get_string(&self, field_id: u32) -> Result<&'a str> {
+        match self.resolve(field_id)? {
+            Resolved::String(text, _) => Ok(text),
+            other => Err(SerializationError::FieldTypeMismatch {
+                field_id,
+                expected: FieldType::String as u16,
+                found: other.field_type() as u16,
+            }),
+        }
+    }
+    
+    /// Get a blob field's written bytes (zero-copy), as `BinaryView::get_blob`
+    pub fn get_blob(&self, field_id: u32) -> Result<&'a [u8]> {
+        match self.resolve(field_id)? {
+            Resolved::Blob(content, _) => Ok(content),
+            other => Err(SerializationError::FieldTypeMismatch {
+                field_id,
+                expected: FieldType::Blob as u16,
+                found: other.field_type() as u16,
+            }),
+        }
+    }
+    
+    /// Literal stored bytes of any field
+    pub fn field_raw_bytes(&self, field_id: u32) -> Result<&'a [u8]> {
+        Ok(match self.resolve(field_id)? {
+            Resolved::Fixed(_, bytes) | Resolved::String(_, bytes) | Resolved::Blob(_, bytes) => bytes,
+        })
+    }
+}
+
+/// Single-pass iterator that checks and decodes each field in table order
+///
+/// Created by `BinaryView::into_validated_iter`. Each entry gets the
+/// per-field checks of `ValidatedView::new` (known type, fixed width, bounds,
+/// UTF-8) just before it is decoded; duplicate ids and overlaps, which need
+/// the whole table, are not checked. A bad entry yields an `Err` and the
+/// walk continues unless `stop_on_error` is set.
+pub struct ValidatedIter<'a> {
+    view: BinaryView<'a>,
+    next: usize,
+    stop_on_error: bool,
+    stopped: bool,
+}
+
+impl<'a> BinaryView<'a> {
+    /// Validate and decode every field in one walk of the offset table
+    ///
+    /// For bulk scans: each field is checked once as it is read, instead of
+    /// validating up front and resolving ids again per accessor call. As with
+    /// `ValidatedView`, var fields of a compressed var section yield
+    /// `CompressedVarSection`.
+    pub fn into_validated_iter(self) -> ValidatedIter<'a> {
+        ValidatedIter {
+            view: self,
+            next: 0,
+            stop_on_error: false,
+            stopped: false,
+        }
+    }
+}
+
+impl<'a> ValidatedIter<'a> {
+    /// End the iteration after the first `Err` instead of moving on to the next field
+    pub fn stop_on_error(mut self, enabled: bool) -> Self {
+        self.stop_on_error = enabled;
+        self
+    }
+    
+    fn decode(&self, entry: &OffsetEntry) -> Result<FieldValue<'a>> {
+        let field_type = FieldType::try_from(entry.field_type)?;
+        if let Some(width) = field_type.fixed_size() {
+            if width != entry.size as usize {
+                return Err(SerializationError::FieldSizeMismatch {
+                    expected: width,
+                    got: entry.size as usize,
+                });
+            }
+        }
+        
+        let bytes = self.view.entry_bytes(entry)?;
+        let value = match field_type {
+            FieldType::String => {
+                let text = core::str::from_utf8(self.view.string_content(entry)?).map_err(|_| SerializationError::FieldSizeMismatch {
+                    expected: 0,
+                    got: 0,
+                })?;
+                FieldValue::String(text)
+            }
+            FieldType::Blob => FieldValue::Blob(self.view.blob_content(entry)?),
+            FieldType::Array => FieldValue::Array(bytes),
+            _ => {
+                let native = entry.convert_bytes(bytes, self.view.value_width(entry), self.view.endianness(), Endianness::native());
+                fixed_value(field_type, &native)
+            }
+        };
+        Ok(value)
+    }
+}
+
+impl<'a> Iterator for ValidatedIter<'a> {
+    type Item = Result<(u32, FieldValue<'a>)>;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stopped {
+            return None;
+        }
+        let entry = self.view.entries().get(self.next)?;
+        self.next += 1;
+        
+        let result = self.decode(entry).map(|value| (entry.field_id, value));
+        if result.is_err() && self.stop_on_error {
+            self.stopped = true;
+        }
+        Some(result)
+    }
+    
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.stopped { 0 } else { self.view.entries().len() - self.next };
+        (0, Some(remaining))
+    }
+}
+
+/// Decode a fixed field from native-order bytes already checked against its width
+fn fixed_value(field_type: FieldType, bytes: &[u8]) -> FieldValue<'static> {
+    use bytemuck::pod_read_unaligned as read;
+    match field_type {
+        FieldType::Int8 => FieldValue::Int8(read(bytes)),
+        FieldType::Int16 => FieldValue::Int16(read(bytes)),
+        FieldType::Int32 => FieldValue::Int32(read(bytes)),
+        FieldType::Int64 => FieldValue::Int64(read(bytes)),
+        FieldType::Uint8 => FieldValue::Uint8(bytes[0]),
+        FieldType::Uint16 => FieldValue::Uint16(read(bytes)),
+        FieldType::Uint32 => FieldValue::Uint32(read(bytes)),
+        FieldType::Uint64 => FieldValue::Uint64(read(bytes)),
+        FieldType::Float32 => FieldValue::Float32(read(bytes)),
+        FieldType::Float64 => FieldValue::Float64(read(bytes)),
+        FieldType::Bool => FieldValue::Bool(bytes[0] != 0),
+        FieldType::Enum => FieldValue::Enum(read(bytes)),
+        FieldType::String | FieldType::Blob | FieldType::Array => unreachable!("decoded by the caller"),
+    }
+}
+
+impl Resolved<'_> {
+    fn field_type(&self) -> FieldType {
+        match self {
+            Resolved::Fixed(field_type, _) => *field_type,
+            Resolved::String(..) => FieldType::String,
+            Resolved::Blob(..) => FieldType::Blob,
+        }
+    }
+}
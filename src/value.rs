@@ -0,0 +1,72 @@
+//! Typed field values for APIs that take or yield whole records
+
+use crate::format::FieldType;
+use alloc::borrow::Cow;
+use alloc::vec;
+
+/// A single field's value, borrowing string and blob contents
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldValue<'a> {
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Uint8(u8),
+    Uint16(u16),
+    Uint32(u32),
+    Uint64(u64),
+    Float32(f32),
+    Float64(f64),
+    Bool(bool),
+    String(&'a str),
+    Blob(&'a [u8]),
+    /// Index into the field's enum dictionary
+    Enum(u16),
+    /// An `Array` field's element bytes as stored; its element type is
+    /// recorded in the buffer (see `BinaryView::array_element_type`)
+    Array(&'a [u8]),
+}
+
+impl<'a> FieldValue<'a> {
+    pub fn field_type(&self) -> FieldType {
+        match self {
+            FieldValue::Int8(_) => FieldType::Int8,
+            FieldValue::Int16(_) => FieldType::Int16,
+            FieldValue::Int32(_) => FieldType::Int32,
+            FieldValue::Int64(_) => FieldType::Int64,
+            FieldValue::Uint8(_) => FieldType::Uint8,
+            FieldValue::Uint16(_) => FieldType::Uint16,
+            FieldValue::Uint32(_) => FieldType::Uint32,
+            FieldValue::Uint64(_) => FieldType::Uint64,
+            FieldValue::Float32(_) => FieldType::Float32,
+            FieldValue::Float64(_) => FieldType::Float64,
+            FieldValue::Bool(_) => FieldType::Bool,
+            FieldValue::String(_) => FieldType::String,
+            FieldValue::Blob(_) => FieldType::Blob,
+            FieldValue::Enum(_) => FieldType::Enum,
+            FieldValue::Array(_) => FieldType::Array,
+        }
+    }
+    
+    /// Stored bytes: little-endian for fixed types, contents (no terminator) for var types,
+    /// element bytes for arrays
+    pub fn as_bytes(&self) -> Cow<'a, [u8]> {
+        match *self {
+            FieldValue::Int8(v) => Cow::Owned(v.to_le_bytes().to_vec()),
+            FieldValue::Int16(v) => Cow::Owned(v.to_le_bytes().to_vec()),
+            FieldValue::Int32(v) => Cow::Owned(v.to_le_bytes().to_vec()),
+            FieldValue::Int64(v) => Cow::Owned(v.to_le_bytes().to_vec()),
+            FieldValue::Uint8(v) => Cow::Owned(v.to_le_bytes().to_vec()),
+            FieldValue::Uint16(v) => Cow::Owned(v.to_le_bytes().to_vec()),
+            FieldValue::Uint32(v) => Cow::Owned(v.to_le_bytes().to_vec()),
+            FieldValue::Uint64(v) => Cow::Owned(v.to_le_bytes().to_vec()),
+            FieldValue::Float32(v) => Cow::Owned(v.to_le_bytes().to_vec()),
+            FieldValue::Float64(v) => Cow::Owned(v.to_le_bytes().to_vec()),
+            FieldValue::Bool(v) => Cow::Owned(vec![v as u8]),
+            FieldValue::String(v) => Cow::Borrowed(v.as_bytes()),
+            FieldValue::Blob(v) => Cow::Borrowed(v),
+            FieldValue::Enum(v) => Cow::Owned(v.to_le_bytes().to_vec()),
+            FieldValue::Array(v) => Cow::Borrowed(v),
+        }
+    }
+}
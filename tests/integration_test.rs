@@ -1,6 +1,7 @@
 use bisere::*;
 use bisere::format::MAGIC;
 use bytemuck::{Pod, Zeroable};
+use std::borrow::Cow;
 
 #[repr(C, packed)]
 #[derive(Debug, Clone, Copy, Pod, Zeroable, PartialEq)]
@@ -63,27 +64,582 @@ fn create_test_buffer() -> Vec<u8> {
             size: 1,
         },
     ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
     serializer.write_data(bytemuck::bytes_of(&data));
     serializer.write_var_data(&vec![0u8; var_size as usize]);
     
     serializer.into_buffer()
 }
 
+#[test]
+fn test_stack_serializer_matches_vec_output() {
+    let expected = create_test_buffer();
+    let view = BinaryView::view(&expected).unwrap();
+    let entries: Vec<OffsetEntry> = (1..=4).map(|id| *view.find_entry(id).unwrap()).collect();
+    let header = FormatHeader::new(4 * std::mem::size_of::<OffsetEntry>() as u32, 21, 256);
+    let data = &expected[80 + 48..80 + 48 + 21];
+    
+    let mut out = [0u8; 512];
+    let mut serializer = StackSerializer::new(&mut out);
+    serializer.write_header(header).unwrap();
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(data).unwrap();
+    serializer.write_var_data(&[0u8; 256]).unwrap();
+    let written = serializer.finish();
+    assert_eq!(written, expected.len());
+    assert_eq!(&out[..written], expected.as_slice());
+    
+    let mut small = [0u8; 100];
+    let mut serializer = StackSerializer::new(&mut small);
+    serializer.write_header(header).unwrap();
+    assert!(matches!(
+        serializer.write_offset_table(&entries),
+        Err(SerializationError::BufferTooSmall { needed: 128, have: 100 })
+    ));
+    assert_eq!(serializer.len(), 80);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_write_serializer_streams_sections() {
+    let expected = create_test_buffer();
+    let view = BinaryView::view(&expected).unwrap();
+    let entries: Vec<OffsetEntry> = (1..=4).map(|id| *view.find_entry(id).unwrap()).collect();
+    let header = FormatHeader::new(4 * std::mem::size_of::<OffsetEntry>() as u32, 21, 256);
+    let data = &expected[80 + 48..80 + 48 + 21];
+    
+    let mut serializer = WriteSerializer::new(Vec::new(), header, &entries).unwrap();
+    serializer.write_data(data).unwrap();
+    serializer.write_var_data(&[0u8; 128]).unwrap();
+    serializer.write_var_data(&[0u8; 128]).unwrap();
+    assert_eq!(serializer.finish().unwrap(), expected);
+    
+    // Sections past `total_size()`, or stopping short of it, are rejected
+    let mut serializer = WriteSerializer::new(Vec::new(), header, &entries).unwrap();
+    serializer.write_data(data).unwrap();
+    assert!(matches!(
+        serializer.write_var_data(&[0u8; 257]),
+        Err(SerializationError::LengthMismatch { expected: 405, got: 406 })
+    ));
+    assert!(matches!(
+        serializer.finish(),
+        Err(SerializationError::LengthMismatch { expected: 405, got: 149 })
+    ));
+    
+    // The table must match the header before anything is written
+    assert!(matches!(
+        WriteSerializer::new(Vec::new(), header, &entries[..3]),
+        Err(SerializationError::InconsistentHeader { .. })
+    ));
+    
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(header);
+    serializer.write_offset_table(&entries).unwrap();
+    let mut sink = Vec::new();
+    assert_eq!(serializer.write_to(&mut sink).unwrap(), 128);
+    assert_eq!(sink, &expected[..128]);
+}
+
+#[test]
+fn test_view_rejects_missing_var_section() {
+    let string_entry = OffsetEntry { field_id: 2, offset: 0, field_type: FieldType::String as u16, size: 16 };
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(FormatHeader::new(24, 8, 0));
+    serializer.write_offset_table(&[
+        OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Uint64 as u16, size: 8 },
+        string_entry,
+    ]).unwrap();
+    serializer.write_data(&7u64.to_le_bytes());
+    let buffer = serializer.into_buffer();
+    
+    assert!(matches!(
+        BinaryView::view(&buffer),
+        Err(SerializationError::BufferTooSmall { needed: 128, have: 112 })
+    ));
+    let entries = [string_entry];
+    assert!(matches!(
+        BinaryView::view_with_schema(&buffer, &entries),
+        Err(SerializationError::BufferTooSmall { .. })
+    ));
+    assert!(matches!(
+        BinaryViewMut::view_mut(&mut buffer.clone()),
+        Err(SerializationError::BufferTooSmall { needed: 128, have: 112 })
+    ));
+    
+    // A zero-capacity var field needs no var section
+    let mut builder = SchemaBuilder::new();
+    builder.add_var_with_capacity(1, FieldType::Blob, b"", 0).unwrap();
+    let buffer = builder.build().unwrap();
+    assert_eq!(BinaryView::view(&buffer).unwrap().get_blob(1).unwrap(), b"");
+}
+
+#[cfg(feature = "profiling")]
+#[test]
+fn test_access_stats() {
+    let buffer = create_test_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+    for _ in 0..5 {
+        view.get_field_copy::<f64>(3).unwrap();
+    }
+    view.get_field_copy::<u64>(1).unwrap();
+    assert!(view.get_field_copy::<u64>(99).is_err());
+
+    let stats = view.access_stats();
+    assert_eq!(stats.accesses[&3], 5);
+    assert_eq!(stats.accesses[&1], 1);
+    assert_eq!(stats.accesses[&99], 1);
+    assert!(!stats.accesses.contains_key(&2));
+    // Field 3 is third in the table, field 1 first, and a miss scans all four
+    assert_eq!(stats.scan_distance, 5 * 3 + 1 + 4);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_creation_time() {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let now = || SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() as i64;
+    let before = now();
+    let mut builder = user_schema_builder();
+    builder.with_creation_time().add_metadata("producer", "ingest-7").unwrap();
+    let buffer = builder.build().unwrap();
+    let after = now();
+
+    let view = BinaryView::view(&buffer).unwrap();
+    let created = view.created_at().unwrap();
+    assert!(before <= created && created <= after, "{} not in {}..={}", created, before, after);
+    // The halves sharing slots with the extension size and entry count don't disturb them
+    assert_eq!(view.metadata("producer"), Some("ingest-7"));
+    let mut header = FormatHeader::new(12, 0, 0);
+    header.set_creation_time(-1);
+    header.set_entry_count(1);
+    header.set_extension_size(7);
+    assert_eq!(header.creation_time(), Some(-1));
+    assert_eq!((header.entry_count(), header.extension_size()), (Some(1), 7));
+
+    let plain = user_schema_builder().build().unwrap();
+    assert_eq!(BinaryView::view(&plain).unwrap().created_at(), None);
+}
+
+#[test]
+fn test_is_compatible() {
+    let base = user_schema_builder().build().unwrap();
+
+    let mut reordered = SchemaBuilder::new();
+    reordered
+        .add_fixed(4, FieldType::Uint8, &[0]).unwrap()
+        .add_fixed(3, FieldType::Float64, &0f64.to_le_bytes()).unwrap()
+        .add_fixed(2, FieldType::Uint32, &0u32.to_le_bytes()).unwrap()
+        .add_fixed(1, FieldType::Uint64, &0u64.to_le_bytes()).unwrap();
+    let reordered = reordered.build().unwrap();
+    assert_eq!(is_compatible(&base, &reordered).unwrap(), Compatibility::Identical);
+
+    let mut extended = user_schema_builder();
+    extended.add_var(5, FieldType::String, b"Alice").unwrap();
+    let extended = extended.build().unwrap();
+    assert_eq!(is_compatible(&base, &extended).unwrap(), Compatibility::Superset);
+    assert_eq!(is_compatible(&extended, &base).unwrap(), Compatibility::Incompatible);
+
+    let mut retyped = SchemaBuilder::new();
+    retyped
+        .add_fixed(1, FieldType::Int64, &0i64.to_le_bytes()).unwrap()
+        .add_fixed(2, FieldType::Uint32, &0u32.to_le_bytes()).unwrap()
+        .add_fixed(3, FieldType::Float64, &0f64.to_le_bytes()).unwrap()
+        .add_fixed(4, FieldType::Uint8, &[0]).unwrap();
+    let retyped = retyped.build().unwrap();
+    assert_eq!(is_compatible(&base, &retyped).unwrap(), Compatibility::Incompatible);
+    assert!(is_compatible(&base, &[0u8; 4]).is_err());
+}
+
+#[test]
+fn test_raw_fields() {
+    let mut builder = user_schema_builder();
+    builder
+        .add_var_with_capacity(5, FieldType::String, b"Alice", 16).unwrap()
+        .add_var(6, FieldType::Blob, &[1, 2, 3]).unwrap();
+    let buffer = builder.build().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+
+    let fields: Vec<(u32, &[u8])> = view.raw_fields().collect::<Result<_>>().unwrap();
+    let lengths: Vec<(u32, usize)> = fields.iter().map(|(id, bytes)| (*id, bytes.len())).collect();
+    assert_eq!(lengths, [(1, 8), (2, 4), (3, 8), (4, 1), (5, 16), (6, 3)]);
+    for (field_id, bytes) in &fields {
+        assert_eq!(*bytes, view.field_raw_bytes(*field_id).unwrap());
+    }
+
+    let mut by_offset = fields.clone();
+    by_offset.sort_by_key(|(field_id, _)| view.field_offset(*field_id).unwrap());
+    let sections: Vec<u8> = by_offset.iter().flat_map(|(_, bytes)| bytes.iter().copied()).collect();
+    let data_start = view.field_offset(1).unwrap();
+    assert_eq!(sections, &buffer[data_start..data_start + 21 + 19]);
+}
+
+#[test]
+fn test_serializer_finish() {
+    let entries = [OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Uint64 as u16, size: 8 }];
+    let serialize = |header: FormatHeader, data: &[u8]| {
+        let mut serializer = BinarySerializer::new();
+        serializer.write_header(header);
+        serializer.write_offset_table(&entries).unwrap();
+        serializer.write_data(data);
+        serializer
+    };
+
+    let finished = serialize(FormatHeader::new(12, 8, 0), &7u64.to_le_bytes()).finish().unwrap();
+    assert_eq!(finished.checksum, None);
+    assert_eq!(finished.bytes, serialize(FormatHeader::new(12, 8, 0), &7u64.to_le_bytes()).into_buffer());
+
+    let mut header = FormatHeader::new(12, 8, 0);
+    header.set_flag(format::FLAG_CHECKSUM);
+    let finished = serialize(header, &7u64.to_le_bytes()).finish().unwrap();
+    let view = BinaryView::view(&finished.bytes).unwrap();
+    view.verify().unwrap();
+    assert_eq!(finished.checksum, Some(view.compute_checksum()));
+
+    // write_data disagreeing with data_size
+    assert!(matches!(
+        serialize(FormatHeader::new(12, 8, 0), &7u32.to_le_bytes()).finish(),
+        Err(SerializationError::LengthMismatch { expected: 100, got: 96 })
+    ));
+    assert!(matches!(
+        serialize(FormatHeader::new(12, 8, 0), &[0; 12]).finish(),
+        Err(SerializationError::LengthMismatch { expected: 100, got: 104 })
+    ));
+    assert!(matches!(
+        serialize(FormatHeader::new(12, 4, 4), &[0; 8]).finish(),
+        Err(SerializationError::MisplacedField { field_id: 1 })
+    ));
+}
+
+#[test]
+fn test_header_parse() {
+    let buffer = create_test_buffer();
+    let header_bytes: &[u8; format::HEADER_SIZE] = buffer[..format::HEADER_SIZE].try_into().unwrap();
+
+    let header = FormatHeader::parse(header_bytes).unwrap();
+    assert_eq!(header.total_size(), buffer.len());
+    let data_size = header.data_size;
+    assert_eq!(data_size, 21);
+    // Header fields are stored little-endian whatever the host
+    assert_eq!(header_bytes[16..20], 21u32.to_le_bytes());
+
+    let mut bad_magic = *header_bytes;
+    bad_magic[0] ^= 0xff;
+    assert!(matches!(
+        FormatHeader::parse(&bad_magic),
+        Err(SerializationError::InvalidMagic { expected: MAGIC, .. })
+    ));
+    let mut bad_version = *header_bytes;
+    bad_version[4] = 9;
+    assert!(matches!(FormatHeader::parse(&bad_version), Err(SerializationError::UnsupportedVersion { version: 9 })));
+}
+
+#[test]
+fn test_is_valid() {
+    let valid = create_test_buffer();
+    let mut candidates = vec![valid.clone(), Vec::new(), valid[..40].to_vec(), valid[..200].to_vec()];
+
+    let mut bad_magic = valid.clone();
+    bad_magic[0] ^= 0xff;
+    let mut bad_version = valid.clone();
+    bad_version[4] = 9;
+    let mut partial_table = valid.clone();
+    partial_table[12] += 1;
+    let mut misplaced = valid.clone();
+    misplaced[80 + 4] = 200;
+    candidates.extend([bad_magic, bad_version, partial_table, misplaced]);
+
+    let mut external = BinarySerializer::new();
+    external.write_header_external(FormatHeader::new(0, 8, 0));
+    external.write_data(&[0; 8]);
+    candidates.push(external.into_buffer());
+
+    let mut no_var = BinarySerializer::new();
+    no_var.write_header(FormatHeader::new(12, 0, 0));
+    no_var.write_offset_table(&[OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Blob as u16, size: 4 }]).unwrap();
+    candidates.push(no_var.into_buffer());
+
+    for (i, buffer) in candidates.iter().enumerate() {
+        assert_eq!(is_valid(buffer), BinaryView::view(buffer).is_ok(), "candidate {}", i);
+    }
+    assert!(is_valid(&valid));
+    assert_eq!(candidates.iter().filter(|buffer| is_valid(buffer)).count(), 1);
+}
+
+#[test]
+fn test_view_partial() {
+    let buffer = create_test_buffer();
+    let data_start = 80 + 4 * 12;
+
+    // Cut partway through the data section: fields 1 and 2 have arrived, 3 hasn't
+    let partial = &buffer[..data_start + 12];
+    assert!(BinaryView::view(partial).is_err());
+    let view = BinaryView::view_partial(partial).unwrap();
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 12345);
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 30);
+    assert!(matches!(view.get_field_copy::<f64>(3), Err(SerializationError::FieldNotFound { field_id: 3 })));
+    assert!(matches!(view.get_field_copy::<u8>(4), Err(SerializationError::FieldNotFound { field_id: 4 })));
+    assert!(view.record(0).is_err());
+
+    // Everything arrived: same answers as a full view
+    let view = BinaryView::view_partial(&buffer).unwrap();
+    assert_eq!(view.get_field_copy::<f64>(3).unwrap(), 95.5);
+    assert_eq!(view.get_field_copy::<u8>(4).unwrap(), 1);
+
+    // The offset table itself must be complete
+    assert!(matches!(
+        BinaryView::view_partial(&buffer[..data_start - 1]),
+        Err(SerializationError::BufferTooSmall { needed, .. }) if needed == data_start
+    ));
+}
+
+#[test]
+fn test_sorted_table() {
+    // Written out of id order; the serializer sorts and flags the table
+    let entries: Vec<OffsetEntry> = (0..200u32)
+        .map(|i| OffsetEntry { field_id: (i * 7919) % 1000, offset: i * 4, field_type: FieldType::Uint32 as u16, size: 4 })
+        .collect();
+    let data: Vec<u8> = (0..200u32).flat_map(|i| ((i * 7919) % 1000).to_le_bytes()).collect();
+    let mut serializer = BinarySerializer::new().with_sorted_table();
+    serializer.write_header(FormatHeader::new(200 * 12, 800, 0));
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&data);
+    let mut buffer = serializer.finish().unwrap().bytes;
+
+    let header = bytemuck::pod_read_unaligned::<FormatHeader>(&buffer[..80]);
+    assert!(header.has_flag(format::FLAG_SORTED_TABLE));
+    let view = BinaryView::view(&buffer).unwrap();
+    let ids: Vec<u32> = view.fields().map(|field| field.field_id).collect();
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    for entry in &entries {
+        let field_id = entry.field_id;
+        assert_eq!(view.get_field_copy::<u32>(field_id).unwrap(), field_id);
+    }
+    assert!(view.find_entry(1001).is_none());
+
+    // A rename that breaks the order drops the flag instead of hiding fields
+    let mut view = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    view.rename_field(0, 5000).unwrap();
+    assert!(view.find_entry(5000).is_some());
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(!bytemuck::pod_read_unaligned::<FormatHeader>(&buffer[..80]).has_flag(format::FLAG_SORTED_TABLE));
+    assert_eq!(view.get_field_copy::<u32>(5000).unwrap(), 0);
+
+    // A table falsely flagged sorted is rejected
+    let mut lying = create_test_buffer();
+    let mut header = bytemuck::pod_read_unaligned::<FormatHeader>(&lying[..80]);
+    header.set_flag(format::FLAG_SORTED_TABLE);
+    lying[..80].copy_from_slice(bytemuck::bytes_of(&header));
+    lying[80..84].copy_from_slice(&9u32.to_le_bytes());
+    assert!(matches!(BinaryView::view(&lying), Err(SerializationError::UnsortedTable { field_id: 2 })));
+    assert!(matches!(BinaryViewMut::view_mut(&mut lying), Err(SerializationError::UnsortedTable { field_id: 2 })));
+}
+
+#[test]
+fn test_fields_iterator() {
+    let mut builder = user_schema_builder();
+    builder.add_var_with_capacity(10, FieldType::String, b"Alice", 16).unwrap();
+    let buffer = builder.build().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+
+    let fields: Vec<FieldInfo> = view.fields().collect();
+    let ids: Vec<u32> = fields.iter().map(|field| field.field_id).collect();
+    assert_eq!(ids, [1, 2, 3, 4, 10]);
+    assert_eq!(fields[0].field_type, Some(FieldType::Uint64));
+    assert_eq!(fields[4].field_type, Some(FieldType::String));
+    assert_eq!(fields[4].size, 16);
+    for field in &fields {
+        assert_eq!(field.offset, view.field_offset(field.field_id).unwrap());
+    }
+
+    // Unknown type codes are still listed
+    let mut buffer = create_test_buffer();
+    buffer[80 + 8] = 0xee;
+    let view = BinaryView::view(&buffer).unwrap();
+    let first = view.fields().next().unwrap();
+    assert_eq!((first.field_id, first.field_type, first.size), (1, None, 8));
+}
+
+#[test]
+fn test_field_type() {
+    let mut builder = user_schema_builder();
+    builder.add_var_with_capacity(10, FieldType::String, b"Alice", 16).unwrap();
+    let buffer = builder.build().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.field_type(1).unwrap(), FieldType::Uint64);
+    assert_eq!(view.field_type(4).unwrap(), FieldType::Uint8);
+    assert_eq!(view.field_type(10).unwrap(), FieldType::String);
+    assert!(matches!(view.field_type(99), Err(SerializationError::FieldNotFound { field_id: 99 })));
+
+    let mut buffer = create_test_buffer();
+    buffer[80 + 8] = 0xee;
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(matches!(view.field_type(1), Err(SerializationError::UnknownFieldType { value: 0xee })));
+}
+
+#[test]
+fn test_view_debug() {
+    let buffer = create_test_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+    let debug = format!("{:?}", view);
+    assert!(debug.starts_with("BinaryView { version: 1, len: 405,"), "{}", debug);
+    assert!(debug.contains("fields: [1: Uint64[8], 2: Uint32[4], 3: Float64[8], 4: Uint8[1]]"), "{}", debug);
+}
+
 #[test]
 fn test_roundtrip() {
     let buffer = create_test_buffer();
     let view = BinaryView::view(&buffer).unwrap();
     
-    let id: &u64 = view.get_field(1).unwrap();
-    let age: &u32 = view.get_field(2).unwrap();
-    let score: &f64 = view.get_field(3).unwrap();
-    let active: &u8 = view.get_field(4).unwrap();
+    let id: u64 = view.get_field_copy(1).unwrap();
+    let age: u32 = view.get_field_copy(2).unwrap();
+    let score: f64 = view.get_field_copy(3).unwrap();
+    let active: u8 = view.get_field_copy(4).unwrap();
     
-    assert_eq!(*id, 12345);
-    assert_eq!(*age, 30);
-    assert_eq!(*score, 95.5);
-    assert_eq!(*active, 1);
+    assert_eq!(id, 12345);
+    assert_eq!(age, 30);
+    assert_eq!(score, 95.5);
+    assert_eq!(active, 1);
+}
+
+#[cfg(feature = "derive")]
+schema! {
+    struct User {
+        id: u64 = 1,
+        age: u32 = 2,
+        score: f64 = 3,
+        active: u8 = 4,
+    }
+}
+
+#[cfg(feature = "derive")]
+schema! {
+    struct Profile {
+        verified: bool = 1,
+        name: String[64] = 2,
+        avatar: Vec<u8>[4] = 3,
+    }
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_schema_macro_roundtrip() {
+    // Same assertions as `test_roundtrip`, against the hand-built buffer
+    let buffer = create_test_buffer();
+    let view = User::view(&buffer).unwrap();
+    assert_eq!(view.id().unwrap(), 12345);
+    assert_eq!(view.age().unwrap(), 30);
+    assert_eq!(view.score().unwrap(), 95.5);
+    assert_eq!(view.active().unwrap(), 1);
+
+    let user = User {
+        id: 12345,
+        age: 30,
+        score: 95.5,
+        active: 1,
+    };
+    let mut buffer = user.to_bytes().unwrap();
+    User::view_mut(&mut buffer).unwrap().set_age(31).unwrap();
+    assert_eq!(User::view(&buffer).unwrap().age().unwrap(), 31);
+    assert_eq!(User::schema().data_size(), 21);
+}
+
+#[cfg(feature = "derive")]
+#[repr(C, packed)]
+#[derive(Debug, Clone, Copy, PartialEq, BiSere)]
+struct DerivedUser {
+    #[bisere(id = 1)]
+    id: u64,
+    #[bisere(id = 2)]
+    age: u32,
+    #[bisere(id = 3)]
+    score: f64,
+    #[bisere(id = 4)]
+    active: bool,
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_derive_roundtrip() {
+    // Same layout as `create_test_buffer`, so it reads the hand-built buffer too
+    let user = DerivedUser::from_bisere(&create_test_buffer()).unwrap();
+    assert_eq!(
+        user,
+        DerivedUser {
+            id: 12345,
+            age: 30,
+            score: 95.5,
+            active: true,
+        }
+    );
+
+    let buffer = user.to_bisere();
+    assert_eq!(DerivedUser::from_bisere(&buffer).unwrap(), user);
+
+    let view = BinaryView::view(&buffer).unwrap();
+    let entry = view.find_entry(3).unwrap();
+    assert_eq!({ entry.field_type }, FieldType::Float64 as u16);
+    assert_eq!({ entry.offset }, 12);
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 30);
+
+    assert!(matches!(
+        DerivedUser::from_bisere(&build(&[(1, FieldType::Uint64, 7u64.to_le_bytes().to_vec())]).unwrap()),
+        Err(SerializationError::FieldNotFound { field_id: 2 })
+    ));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_schema_macro_var_fields() {
+    let profile = Profile {
+        verified: true,
+        name: "Alice".to_string(),
+        avatar: vec![1, 2, 3, 4],
+    };
+    let mut buffer = profile.to_bytes().unwrap();
+
+    let view = Profile::view(&buffer).unwrap();
+    assert!(view.verified().unwrap());
+    assert_eq!(view.name().unwrap(), "Alice");
+    assert_eq!(view.avatar().unwrap(), &[1, 2, 3, 4]);
+
+    let mut view = Profile::view_mut(&mut buffer).unwrap();
+    view.set_verified(false).unwrap();
+    view.set_name("Bob").unwrap();
+    view.set_avatar(&[9]).unwrap();
+
+    let view = Profile::view(&buffer).unwrap();
+    assert!(!view.verified().unwrap());
+    assert_eq!(view.name().unwrap(), "Bob");
+    assert_eq!(view.avatar().unwrap()[0], 9);
+
+    assert!(matches!(
+        Profile::view(&create_test_buffer()),
+        Err(SerializationError::FieldTypeMismatch { field_id: 1, .. })
+    ));
+}
+
+#[test]
+fn test_get_field_alignment() {
+    // Field 3 (f64) follows a u64 and a u32, so it sits 4 bytes off 8-alignment
+    let buffer = create_test_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+    let offset = view.field_offset(3).unwrap();
+    let misaligned = !(buffer.as_ptr() as usize + offset).is_multiple_of(8);
+
+    assert_eq!(view.get_field_copy::<f64>(3).unwrap(), 95.5);
+    match view.get_field::<f64>(3) {
+        Err(SerializationError::MisalignedAccess { offset: at, align: 8 }) => {
+            assert!(misaligned);
+            assert_eq!(at, offset);
+        }
+        Ok(value) => {
+            assert!(!misaligned);
+            assert_eq!(*value, 95.5);
+        }
+        Err(err) => panic!("unexpected error: {:?}", err),
+    }
+    assert_eq!(*view.get_field::<u8>(4).unwrap(), 1);
 }
 
 #[test]
@@ -104,6 +660,59 @@ fn test_zero_copy() {
     assert_eq!(*id_ptr, 12345);
 }
 
+#[test]
+fn test_cow_accessors() {
+    let buffer = create_test_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+    let data_start = buffer.as_ptr() as usize + 80 + 4 * std::mem::size_of::<OffsetEntry>();
+
+    // id sits at data offset 0, score at data offset 12
+    let id = view.get_field_cow::<u64>(1).unwrap();
+    assert_eq!(matches!(id, Cow::Borrowed(_)), data_start.is_multiple_of(8));
+    assert_eq!(*id, 12345);
+
+    let score = view.get_field_cow::<f64>(3).unwrap();
+    assert_eq!(matches!(score, Cow::Borrowed(_)), (data_start + 12).is_multiple_of(8));
+    assert_eq!(*score, 95.5);
+
+    let mut builder = SchemaBuilder::new();
+    builder.add_var(1, FieldType::Blob, b"bytes").unwrap();
+    let buffer = builder.build().unwrap();
+    let blob = BinaryView::view(&buffer).unwrap().get_blob_cow(1).unwrap();
+    assert!(matches!(blob, Cow::Borrowed(b"bytes")));
+}
+
+#[test]
+fn test_get_blob_as() {
+    let samples = [1u32, 2, 3, 0xdead_beef];
+    let mut builder = SchemaBuilder::new();
+    builder.add_var(1, FieldType::Blob, bytemuck::cast_slice(&samples)).unwrap();
+    let built = builder.build().unwrap();
+    // The blob starts at byte 92: u32-aligned when the buffer is
+    assert_eq!(BinaryView::view(&built).unwrap().field_offset(1).unwrap(), 92);
+
+    // Copy into u32-aligned storage, at the start and then one byte in
+    let mut storage = vec![0u32; built.len() / 4 + 2];
+    let backing: &mut [u8] = bytemuck::cast_slice_mut(&mut storage);
+    backing[..built.len()].copy_from_slice(&built);
+    let view = BinaryView::view(&backing[..built.len()]).unwrap();
+    let values = view.get_blob_as::<u32>(1).unwrap();
+    assert!(matches!(values, Cow::Borrowed(_)));
+    assert_eq!(&*values, &samples);
+
+    let backing: &mut [u8] = bytemuck::cast_slice_mut(&mut storage);
+    backing[1..built.len() + 1].copy_from_slice(&built);
+    let view = BinaryView::view(&backing[1..built.len() + 1]).unwrap();
+    let values = view.get_blob_as::<u32>(1).unwrap();
+    assert!(matches!(values, Cow::Owned(_)));
+    assert_eq!(&*values, &samples);
+
+    assert!(matches!(
+        view.get_blob_as::<[u8; 3]>(1),
+        Err(SerializationError::FieldSizeMismatch { expected: 3, got: 16 })
+    ));
+}
+
 #[test]
 fn test_modify_fixed() {
     let mut buffer = create_test_buffer();
@@ -127,17 +736,113 @@ fn test_modify_fixed() {
     
     // Verify modifications
     let view = BinaryView::view(&buffer).unwrap();
-    assert_eq!(*view.get_field::<u64>(1).unwrap(), 99999);
-    assert_eq!(*view.get_field::<u32>(2).unwrap(), 35);
-    assert_eq!(*view.get_field::<f64>(3).unwrap(), 88.8);
-    assert_eq!(*view.get_field::<u8>(4).unwrap(), 0);
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 99999);
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 35);
+    assert_eq!(view.get_field_copy::<f64>(3).unwrap(), 88.8);
+    assert_eq!(view.get_field_copy::<u8>(4).unwrap(), 0);
+}
+
+#[test]
+fn test_replace_data() {
+    let mut buffer = create_test_buffer();
+    let mut view_mut = BinaryViewMut::view_mut(&mut buffer).unwrap();
+
+    let replacement = TestData {
+        id: 777,
+        age: 41,
+        score: 12.25,
+        active: 0,
+    };
+    view_mut.replace_data(&replacement).unwrap();
+
+    // A mismatched size leaves the section untouched
+    match view_mut.replace_data(&0u64) {
+        Err(SerializationError::FieldSizeMismatch { expected, got }) => {
+            assert_eq!(expected, std::mem::size_of::<TestData>());
+            assert_eq!(got, 8);
+        }
+        _ => panic!("Expected FieldSizeMismatch error"),
+    }
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 777);
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 41);
+    assert_eq!(view.get_field_copy::<f64>(3).unwrap(), 12.25);
+    assert_eq!(view.get_field_copy::<u8>(4).unwrap(), 0);
+}
+
+#[test]
+fn test_rename_field() {
+    let mut buffer = create_test_buffer();
+    let mut view_mut = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    view_mut.rename_field(2, 20).unwrap();
+
+    match view_mut.rename_field(20, 1) {
+        Err(SerializationError::DuplicateFieldId { field_id }) => assert_eq!(field_id, 1),
+        _ => panic!("Expected DuplicateFieldId error"),
+    }
+    match view_mut.rename_field(2, 30) {
+        Err(SerializationError::FieldNotFound { field_id }) => assert_eq!(field_id, 2),
+        _ => panic!("Expected FieldNotFound error"),
+    }
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_field_copy::<u32>(20).unwrap(), 30);
+    match view.get_field_copy::<u32>(2) {
+        Err(SerializationError::FieldNotFound { field_id }) => assert_eq!(field_id, 2),
+        _ => panic!("Expected FieldNotFound error"),
+    }
+}
+
+#[test]
+fn test_rename_field_moves_table_records() {
+    let mut builder = SchemaBuilder::new();
+    builder
+        .field_crcs(true)
+        .string_lengths(true)
+        .blob_lengths(true)
+        .add_fixed(1, FieldType::Uint32, &7u32.to_le_bytes()).unwrap()
+        .add_named(1, "count").unwrap()
+        .add_fixed_with_default(2, FieldType::Uint16, &9u16.to_le_bytes()).unwrap()
+        .add_enum(3, &["red", "green"], "green").unwrap()
+        .add_var_with_capacity(4, FieldType::String, b"a\0b", 8).unwrap()
+        .add_var_with_capacity(5, FieldType::Blob, &[1, 2, 0], 8).unwrap()
+        .add_array(6, &[1.5f32, 2.5]).unwrap();
+    let mut buffer = builder.build().unwrap();
+
+    let mut view_mut = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    for id in 1..=6 {
+        view_mut.rename_field(id, id + 10).unwrap();
+    }
+
+    let view = BinaryView::view(&buffer).unwrap();
+    // Names
+    assert_eq!(view.field_name(11), Some("count"));
+    assert_eq!(view.field_name(1), None);
+    // CRCs
+    for id in 11..=16 {
+        view.verify_field(id).unwrap();
+    }
+    // Defaults
+    assert_eq!(view.field_default(12), Some(&9u16.to_le_bytes()[..]));
+    assert_eq!(view.field_default(2), None);
+    // Enum dictionaries
+    assert_eq!(view.get_enum(13).unwrap(), "green");
+    assert_eq!(view.enum_dictionary(13), Some(vec!["red", "green"]));
+    // String lengths
+    assert_eq!(view.get_string(14).unwrap(), "a\0b");
+    // Blob lengths
+    assert_eq!(view.get_blob(15).unwrap(), [1, 2, 0]);
+    // Array element types
+    assert_eq!(view.array_element_type(16), Some(FieldType::Float32));
+    assert_eq!(view.get_array_copy::<f32>(16).unwrap(), [1.5, 2.5]);
 }
 
 #[test]
 fn test_modify_string() {
     let mut serializer = BinarySerializer::new();
     let header = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
+        std::mem::size_of::<OffsetEntry>() as u32,
         0,
         256,
     );
@@ -149,7 +854,7 @@ fn test_modify_string() {
         field_type: FieldType::String as u16,
         size: 256,
     }];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
     serializer.write_data(&[]);
     
     let mut var_data = vec![0u8; 256];
@@ -171,7 +876,7 @@ fn test_modify_string() {
 fn test_modify_blob() {
     let mut serializer = BinarySerializer::new();
     let header = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
+        std::mem::size_of::<OffsetEntry>() as u32,
         0,
         256,
     );
@@ -183,7 +888,7 @@ fn test_modify_blob() {
         field_type: FieldType::Blob as u16,
         size: 256,
     }];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
     serializer.write_data(&[]);
     serializer.write_var_data(&vec![0u8; 256]);
     
@@ -221,7 +926,7 @@ fn test_error_field_not_found() {
     let buffer = create_test_buffer();
     let view = BinaryView::view(&buffer).unwrap();
     
-    match view.get_field::<u32>(999) {
+    match view.get_field_copy::<u32>(999) {
         Err(SerializationError::FieldNotFound { field_id }) => {
             assert_eq!(field_id, 999);
         }
@@ -246,7 +951,7 @@ fn test_error_bounds_checking() {
     // Test InvalidOffset - create buffer with invalid offset entry
     let mut serializer = BinarySerializer::new();
     let header = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
+        std::mem::size_of::<OffsetEntry>() as u32,
         0,
         10, // Small var section
     );
@@ -258,15 +963,15 @@ fn test_error_bounds_checking() {
         field_type: FieldType::Uint32 as u16,
         size: 4,
     }];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
     serializer.write_data(&[]);
-    serializer.write_var_data(&vec![0u8; 10]);
+    serializer.write_var_data(&[0u8; 10]);
     
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer).unwrap();
     
     // This should fail with InvalidOffset
-    match view.get_field::<u32>(1) {
+    match view.get_field_copy::<u32>(1) {
         Err(SerializationError::InvalidOffset { .. }) => {}
         _ => panic!("Expected InvalidOffset error"),
     }
@@ -288,7 +993,7 @@ fn test_error_bounds_checking() {
     // Test string size mismatch
     let mut serializer3 = BinarySerializer::new();
     let header3 = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
+        std::mem::size_of::<OffsetEntry>() as u32,
         0,
         10, // Small var section
     );
@@ -300,9 +1005,9 @@ fn test_error_bounds_checking() {
         field_type: FieldType::String as u16,
         size: 10, // Only 10 bytes
     }];
-    serializer3.write_offset_table(&entries3);
+    serializer3.write_offset_table(&entries3).unwrap();
     serializer3.write_data(&[]);
-    serializer3.write_var_data(&vec![0u8; 10]);
+    serializer3.write_var_data(&[0u8; 10]);
     
     let mut buffer3 = serializer3.into_buffer();
     let mut view_mut3 = BinaryViewMut::view_mut(&mut buffer3).unwrap();
@@ -316,6 +1021,37 @@ fn test_error_bounds_checking() {
     }
 }
 
+#[test]
+fn test_error_misplaced_field() {
+    let mut serializer = BinarySerializer::new();
+    let header = FormatHeader::new(2 * std::mem::size_of::<OffsetEntry>() as u32, 8, 16);
+    serializer.write_header(header);
+
+    // The Uint64 at offset 8 sits where the var section begins
+    let entries = vec![
+        OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Uint64 as u16, size: 8 },
+        OffsetEntry { field_id: 2, offset: 8, field_type: FieldType::Uint64 as u16, size: 8 },
+    ];
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&[0u8; 8]);
+    serializer.write_var_data(&[0u8; 16]);
+
+    let mut buffer = serializer.into_buffer();
+    match BinaryView::view(&buffer) {
+        Err(SerializationError::MisplacedField { field_id }) => assert_eq!(field_id, 2),
+        _ => panic!("Expected MisplacedField error"),
+    }
+    assert!(matches!(
+        BinaryViewMut::view_mut(&mut buffer),
+        Err(SerializationError::MisplacedField { field_id: 2 })
+    ));
+
+    // Schemas built through SchemaBuilder are placed correctly by construction
+    let mut builder = user_schema_builder();
+    builder.add_var(5, FieldType::Blob, &[1, 2, 3]).unwrap();
+    BinaryView::view(&builder.build().unwrap()).unwrap();
+}
+
 // ========== Comprehensive Additional Tests ==========
 
 #[test]
@@ -361,23 +1097,25 @@ fn test_all_integer_types() {
         OffsetEntry { field_id: 7, offset: { offset += 2; offset }, field_type: FieldType::Uint32 as u16, size: 4 },
         OffsetEntry { field_id: 8, offset: { offset += 4; offset }, field_type: FieldType::Uint64 as u16, size: 8 },
     ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
     serializer.write_data(bytemuck::bytes_of(&data));
     serializer.write_var_data(&[]);
 
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer).unwrap();
 
-    assert_eq!(*view.get_field::<i8>(1).unwrap(), -128);
-    assert_eq!(*view.get_field::<i16>(2).unwrap(), -32768);
-    assert_eq!(*view.get_field::<i32>(3).unwrap(), -2147483648);
-    assert_eq!(*view.get_field::<i64>(4).unwrap(), -9223372036854775808);
-    assert_eq!(*view.get_field::<u8>(5).unwrap(), 255);
-    assert_eq!(*view.get_field::<u16>(6).unwrap(), 65535);
-    assert_eq!(*view.get_field::<u32>(7).unwrap(), 4294967295);
-    assert_eq!(*view.get_field::<u64>(8).unwrap(), 18446744073709551615);
+    assert_eq!(view.get_field_copy::<i8>(1).unwrap(), -128);
+    assert_eq!(view.get_field_copy::<i16>(2).unwrap(), -32768);
+    assert_eq!(view.get_field_copy::<i32>(3).unwrap(), -2147483648);
+    assert_eq!(view.get_field_copy::<i64>(4).unwrap(), -9223372036854775808);
+    assert_eq!(view.get_field_copy::<u8>(5).unwrap(), 255);
+    assert_eq!(view.get_field_copy::<u16>(6).unwrap(), 65535);
+    assert_eq!(view.get_field_copy::<u32>(7).unwrap(), 4294967295);
+    assert_eq!(view.get_field_copy::<u64>(8).unwrap(), 18446744073709551615);
 }
 
+// The literals are sample data, not stand-ins for PI and E
+#[allow(clippy::approx_constant)]
 #[test]
 fn test_all_float_types() {
     #[repr(C, packed)]
@@ -403,15 +1141,15 @@ fn test_all_float_types() {
         OffsetEntry { field_id: 1, offset, field_type: FieldType::Float32 as u16, size: 4 },
         OffsetEntry { field_id: 2, offset: { offset += 4; offset }, field_type: FieldType::Float64 as u16, size: 8 },
     ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
     serializer.write_data(bytemuck::bytes_of(&data));
     serializer.write_var_data(&[]);
 
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer).unwrap();
 
-    let f32_val = *view.get_field::<f32>(1).unwrap();
-    let f64_val = *view.get_field::<f64>(2).unwrap();
+    let f32_val = view.get_field_copy::<f32>(1).unwrap();
+    let f64_val = view.get_field_copy::<f64>(2).unwrap();
     assert!((f32_val - 3.14159).abs() < 0.0001);
     assert!((f64_val - 2.718281828459045).abs() < 0.0000001);
 }
@@ -450,18 +1188,18 @@ fn test_edge_case_values() {
         OffsetEntry { field_id: 4, offset: { offset += 8; offset }, field_type: FieldType::Float64 as u16, size: 8 },
         OffsetEntry { field_id: 5, offset: { offset += 8; offset }, field_type: FieldType::Float64 as u16, size: 8 },
     ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
     serializer.write_data(bytemuck::bytes_of(&data));
     serializer.write_var_data(&[]);
 
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer).unwrap();
 
-    assert_eq!(*view.get_field::<u64>(1).unwrap(), 0);
-    assert_eq!(*view.get_field::<u64>(2).unwrap(), u64::MAX);
-    assert_eq!(*view.get_field::<i64>(3).unwrap(), i64::MIN);
-    assert_eq!(*view.get_field::<f64>(4).unwrap(), 0.0);
-    assert!((*view.get_field::<f64>(5).unwrap() - (-123.456)).abs() < 0.0001);
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 0);
+    assert_eq!(view.get_field_copy::<u64>(2).unwrap(), u64::MAX);
+    assert_eq!(view.get_field_copy::<i64>(3).unwrap(), i64::MIN);
+    assert_eq!(view.get_field_copy::<f64>(4).unwrap(), 0.0);
+    assert!((view.get_field_copy::<f64>(5).unwrap() - (-123.456)).abs() < 0.0001);
 }
 
 #[test]
@@ -479,7 +1217,7 @@ fn test_multiple_strings() {
         OffsetEntry { field_id: 20, offset: 100, field_type: FieldType::String as u16, size: 200 },
         OffsetEntry { field_id: 30, offset: 300, field_type: FieldType::String as u16, size: 212 },
     ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
     serializer.write_data(&[]);
 
     let mut var_data = vec![0u8; 512];
@@ -511,7 +1249,7 @@ fn test_multiple_blobs() {
         OffsetEntry { field_id: 22, offset: 50, field_type: FieldType::Blob as u16, size: 100 },
         OffsetEntry { field_id: 33, offset: 150, field_type: FieldType::Blob as u16, size: 362 },
     ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
     serializer.write_data(&[]);
     serializer.write_var_data(&vec![0u8; 512]);
 
@@ -536,7 +1274,7 @@ fn test_multiple_blobs() {
 fn test_empty_string() {
     let mut serializer = BinarySerializer::new();
     let header = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
+        std::mem::size_of::<OffsetEntry>() as u32,
         0,
         100,
     );
@@ -548,9 +1286,9 @@ fn test_empty_string() {
         field_type: FieldType::String as u16,
         size: 100,
     }];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
     serializer.write_data(&[]);
-    serializer.write_var_data(&vec![0u8; 100]);
+    serializer.write_var_data(&[0u8; 100]);
 
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer).unwrap();
@@ -561,7 +1299,7 @@ fn test_empty_string() {
 fn test_empty_blob() {
     let mut serializer = BinarySerializer::new();
     let header = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
+        std::mem::size_of::<OffsetEntry>() as u32,
         0,
         100,
     );
@@ -573,9 +1311,9 @@ fn test_empty_blob() {
         field_type: FieldType::Blob as u16,
         size: 100,
     }];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
     serializer.write_data(&[]);
-    serializer.write_var_data(&vec![0u8; 100]);
+    serializer.write_var_data(&[0u8; 100]);
 
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer).unwrap();
@@ -588,7 +1326,7 @@ fn test_empty_blob() {
 fn test_unicode_string() {
     let mut serializer = BinarySerializer::new();
     let header = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
+        std::mem::size_of::<OffsetEntry>() as u32,
         0,
         256,
     );
@@ -600,7 +1338,7 @@ fn test_unicode_string() {
         field_type: FieldType::String as u16,
         size: 256,
     }];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
     serializer.write_data(&[]);
 
     let unicode_str = "Hello 世界 🌍";
@@ -628,7 +1366,7 @@ fn test_non_sequential_field_ids() {
         OffsetEntry { field_id: 200, offset: { offset += 8; offset }, field_type: FieldType::Uint32 as u16, size: 4 },
         OffsetEntry { field_id: 1, offset: { offset += 4; offset }, field_type: FieldType::Uint64 as u16, size: 8 },
     ];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
 
     let mut data = vec![0u8; data_size as usize];
     data[0..4].copy_from_slice(&100u32.to_le_bytes());
@@ -641,216 +1379,2949 @@ fn test_non_sequential_field_ids() {
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer).unwrap();
 
-    assert_eq!(*view.get_field::<u32>(100).unwrap(), 100);
-    assert_eq!(*view.get_field::<u64>(50).unwrap(), 200);
-    assert_eq!(*view.get_field::<u32>(200).unwrap(), 300);
-    assert_eq!(*view.get_field::<u64>(1).unwrap(), 400);
-}
-
-#[test]
-fn test_multiple_modifications() {
-    let mut buffer = create_test_buffer();
-    let mut view_mut = BinaryViewMut::view_mut(&mut buffer).unwrap();
-
-    // Modify all fields multiple times
-    for i in 0..10 {
-        let new_age = 20 + i as u32;
-        view_mut.modify_field(2, &new_age).unwrap();
-    }
-
-    let view = BinaryView::view(&buffer).unwrap();
-    assert_eq!(*view.get_field::<u32>(2).unwrap(), 29);
+    assert_eq!(view.get_field_copy::<u32>(100).unwrap(), 100);
+    assert_eq!(view.get_field_copy::<u64>(50).unwrap(), 200);
+    assert_eq!(view.get_field_copy::<u32>(200).unwrap(), 300);
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 400);
 }
 
 #[test]
-fn test_large_buffer() {
+fn test_fields_by_offset() {
     let mut serializer = BinarySerializer::new();
-    let offset_table_size = 1 * std::mem::size_of::<OffsetEntry>() as u32;
-    let data_size = 0;
-    let var_size = 65535; // Max u16 value
-    let header = FormatHeader::new(offset_table_size, data_size, var_size);
+    let header = FormatHeader::new(3 * std::mem::size_of::<OffsetEntry>() as u32, 12, 16);
     serializer.write_header(header);
 
-    let entries = vec![OffsetEntry {
-        field_id: 1,
-        offset: 0,
-        field_type: FieldType::Blob as u16,
-        size: var_size as u16,
-    }];
-    serializer.write_offset_table(&entries);
-    serializer.write_data(&[]);
-    serializer.write_var_data(&vec![0u8; var_size as usize]);
+    // Table order deliberately differs from physical layout
+    let entries = vec![
+        OffsetEntry { field_id: 1, offset: 8, field_type: FieldType::Uint32 as u16, size: 4 },
+        OffsetEntry { field_id: 2, offset: 0, field_type: FieldType::String as u16, size: 16 },
+        OffsetEntry { field_id: 3, offset: 0, field_type: FieldType::Uint64 as u16, size: 8 },
+    ];
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&[0u8; 12]);
+    serializer.write_var_data(&[0u8; 16]);
 
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer).unwrap();
-    let blob = view.get_blob(1).unwrap();
-    assert_eq!(blob.len(), var_size as usize);
+
+    let ids: Vec<u32> = view.fields_by_offset().iter().map(|e| e.field_id).collect();
+    assert_eq!(ids, vec![3, 1]);
 }
 
 #[test]
-fn test_many_fields() {
-    const NUM_FIELDS: usize = 50;
-    let mut serializer = BinarySerializer::new();
-    let offset_table_size = (NUM_FIELDS * std::mem::size_of::<OffsetEntry>()) as u32;
-    let data_size = (NUM_FIELDS * 4) as u32; // Each field is u32
-    let header = FormatHeader::new(offset_table_size, data_size, 0);
-    serializer.write_header(header);
+fn test_extract_field() {
+    let buffer = create_test_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
 
-    let mut offset = 0u32;
-    let mut entries = Vec::new();
-    for i in 0..NUM_FIELDS {
-        entries.push(OffsetEntry {
-            field_id: i as u32,
-            offset,
-            field_type: FieldType::Uint32 as u16,
-            size: 4,
-        });
-        offset += 4;
-    }
-    serializer.write_offset_table(&entries);
+    let extracted = view.extract_field(3).unwrap();
+    let single = BinaryView::view(&extracted).unwrap();
+    assert_eq!(single.get_field_copy::<f64>(3).unwrap(), 95.5);
+    assert!(single.find_entry(1).is_none());
+    assert_eq!(extracted.len(), 80 + std::mem::size_of::<OffsetEntry>() + 8);
 
-    let mut data = vec![0u8; data_size as usize];
-    for i in 0..NUM_FIELDS {
-        let value = (i * 100) as u32;
-        data[i * 4..(i + 1) * 4].copy_from_slice(&value.to_le_bytes());
-    }
-    serializer.write_data(&data);
-    serializer.write_var_data(&[]);
+    // The extracted buffer keeps the source's byte order
+    let mut swapped = buffer.clone();
+    swap_endianness(&mut swapped).unwrap();
+    let extracted = BinaryView::view(&swapped).unwrap().extract_field(3).unwrap();
+    let single = BinaryView::view(&extracted).unwrap();
+    assert_eq!(single.endianness(), BinaryView::view(&swapped).unwrap().endianness());
+    assert_eq!(single.get_field_copy::<f64>(3).unwrap(), 95.5);
 
-    let buffer = serializer.into_buffer();
-    let view = BinaryView::view(&buffer).unwrap();
+    // Var fields carry their whole reservation
+    let mut builder = SchemaBuilder::new();
+    builder.add_fixed(1, FieldType::Uint32, &1u32.to_le_bytes()).unwrap();
+    builder.add_var(2, FieldType::String, b"Hello").unwrap();
+    let buffer = builder.build().unwrap();
+    let extracted = BinaryView::view(&buffer).unwrap().extract_field(2).unwrap();
+    let single = BinaryView::view(&extracted).unwrap();
+    assert_eq!(single.get_string(2).unwrap(), "Hello");
+    let size = single.find_entry(2).unwrap().size;
+    assert_eq!(size, 6);
 
-    for i in 0..NUM_FIELDS {
-        let value = *view.get_field::<u32>(i as u32).unwrap();
-        assert_eq!(value, (i * 100) as u32);
+    // Enum fields carry their dictionary
+    let mut builder = SchemaBuilder::new();
+    builder.add_enum(1, &["red", "green"], "green").unwrap();
+    let buffer = builder.build().unwrap();
+    let extracted = BinaryView::view(&buffer).unwrap().extract_field(1).unwrap();
+    assert_eq!(BinaryView::view(&extracted).unwrap().get_enum(1).unwrap(), "green");
+
+    // Blob fields carry their written length
+    let mut builder = SchemaBuilder::new();
+    builder.blob_lengths(true).add_var_with_capacity(1, FieldType::Blob, &[1, 2, 0], 8).unwrap();
+    let buffer = builder.build().unwrap();
+    let extracted = BinaryView::view(&buffer).unwrap().extract_field(1).unwrap();
+    assert_eq!(BinaryView::view(&extracted).unwrap().get_blob(1).unwrap(), [1, 2, 0]);
+
+    match view.extract_field(999) {
+        Err(SerializationError::FieldNotFound { field_id }) => assert_eq!(field_id, 999),
+        _ => panic!("Expected FieldNotFound error"),
     }
 }
 
 #[test]
-fn test_string_boundary_conditions() {
+fn test_as_struct_array() {
+    #[repr(C, packed)]
+    #[derive(Debug, Clone, Copy, Pod, Zeroable, PartialEq)]
+    struct Sample {
+        id: u32,
+        value: f64,
+    }
+
+    let samples = [
+        Sample { id: 1, value: 1.5 },
+        Sample { id: 2, value: 2.5 },
+        Sample { id: 3, value: 3.5 },
+    ];
+
     let mut serializer = BinarySerializer::new();
-    let header = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
-        0,
-        10,
-    );
+    let header = FormatHeader::new(0, std::mem::size_of_val(&samples) as u32, 0);
     serializer.write_header(header);
+    serializer.write_data(bytemuck::cast_slice(&samples));
+    let buffer = serializer.into_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
 
-    let entries = vec![OffsetEntry {
-        field_id: 10,
-        offset: 0,
-        field_type: FieldType::String as u16,
-        size: 10, // Exactly 10 bytes (9 chars + null)
-    }];
-    serializer.write_offset_table(&entries);
-    serializer.write_data(&[]);
+    // Packed structs have alignment 1, so the zero-copy path always applies
+    assert_eq!(view.as_struct_array::<Sample>().unwrap(), &samples);
+    assert_eq!(view.as_struct_array_copy::<Sample>().unwrap(), samples.to_vec());
 
-    let mut var_data = vec![0u8; 10];
-    var_data[0..9].copy_from_slice(b"123456789");
-    serializer.write_var_data(&var_data);
+    // Section length must be an exact multiple of the element size
+    match view.as_struct_array_copy::<[u8; 5]>() {
+        Err(SerializationError::FieldSizeMismatch { expected, got }) => {
+            assert_eq!(expected, 5);
+            assert_eq!(got, 36);
+        }
+        _ => panic!("Expected FieldSizeMismatch error"),
+    }
 
+    // A data section after a 12-byte offset table is not u64-aligned
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(FormatHeader::new(std::mem::size_of::<OffsetEntry>() as u32, 16, 0));
+    serializer.write_offset_table(&[OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Uint64 as u16, size: 8 }]).unwrap();
+    serializer.write_data(bytemuck::cast_slice(&[7u64, 9u64]));
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer).unwrap();
-    assert_eq!(view.get_string(10).unwrap(), "123456789");
+
+    assert_eq!(view.as_struct_array_copy::<u64>().unwrap(), vec![7, 9]);
+    if !(buffer.as_ptr() as usize + 92).is_multiple_of(std::mem::align_of::<u64>()) {
+        assert!(matches!(
+            view.as_struct_array::<u64>(),
+            Err(SerializationError::MisalignedAccess { offset: 92, align: 8 })
+        ));
+    }
 }
 
 #[test]
-fn test_error_unsupported_version() {
-    let mut buffer = vec![0u8; 100];
-    // Set correct magic but wrong version
-    buffer[0..4].copy_from_slice(&MAGIC.to_le_bytes());
-    buffer[4..8].copy_from_slice(&999u32.to_le_bytes()); // Invalid version
+fn test_get_many() {
+    let mut builder = SchemaBuilder::new();
+    for id in 1..=5u32 {
+        builder.add_fixed(id, FieldType::Uint32, &(id * 10).to_le_bytes()).unwrap();
+    }
+    builder.add_var(6, FieldType::Blob, b"payload").unwrap();
+    let buffer = builder.build().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
 
-    match BinaryView::view(&buffer) {
-        Err(SerializationError::UnsupportedVersion { version }) => {
-            assert_eq!(version, 999);
+    assert_eq!(view.get_many::<u32>(&[4, 1, 3]).unwrap(), vec![40, 10, 30]);
+
+    let bytes = view.get_many_bytes(&[6, 2]).unwrap();
+    assert_eq!(bytes[0], b"payload");
+    assert_eq!(bytes[1], &20u32.to_le_bytes());
+
+    match view.get_many::<u32>(&[1, 99, 98]) {
+        Err(SerializationError::FieldNotFound { field_id }) => assert_eq!(field_id, 99),
+        _ => panic!("Expected FieldNotFound error"),
+    }
+}
+
+#[test]
+fn test_checksum_fields() {
+    let mut buffer = create_test_buffer();
+    let mut view_mut = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    let stored = view_mut.store_fields_checksum(&[1, 3]).unwrap();
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.checksum_fields(&[3, 1]).unwrap(), stored);
+    view.verify_fields_checksum(&[1, 3]).unwrap();
+
+    // Fields outside the protected set may change freely
+    let mut view_mut = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    view_mut.modify_field(2, &99u32).unwrap();
+    BinaryView::view(&buffer).unwrap().verify_fields_checksum(&[1, 3]).unwrap();
+
+    // Touching a protected field is detected
+    let mut view_mut = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    view_mut.modify_field(3, &1.0f64).unwrap();
+    match BinaryView::view(&buffer).unwrap().verify_fields_checksum(&[1, 3]) {
+        Err(SerializationError::ChecksumMismatch { expected, found }) => {
+            assert_eq!(expected, stored);
+            assert_ne!(found, stored);
         }
-        _ => panic!("Expected UnsupportedVersion error"),
+        _ => panic!("Expected ChecksumMismatch error"),
     }
 }
 
 #[test]
-fn test_error_wrong_field_type() {
+fn test_multiple_modifications() {
+    let mut buffer = create_test_buffer();
+    let mut view_mut = BinaryViewMut::view_mut(&mut buffer).unwrap();
+
+    // Modify all fields multiple times
+    for i in 0..10 {
+        let new_age = 20 + i as u32;
+        view_mut.modify_field(2, &new_age).unwrap();
+    }
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 29);
+}
+
+#[test]
+fn test_large_buffer() {
     let mut serializer = BinarySerializer::new();
-    let header = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
-        0,
-        256,
-    );
+    let offset_table_size = std::mem::size_of::<OffsetEntry>() as u32;
+    let data_size = 0;
+    let var_size = 65535; // Max u16 value
+    let header = FormatHeader::new(offset_table_size, data_size, var_size);
     serializer.write_header(header);
 
     let entries = vec![OffsetEntry {
-        field_id: 10,
+        field_id: 1,
         offset: 0,
-        field_type: FieldType::Blob as u16, // Wrong type - should be String
-        size: 256,
+        field_type: FieldType::Blob as u16,
+        size: var_size as u16,
     }];
-    serializer.write_offset_table(&entries);
+    serializer.write_offset_table(&entries).unwrap();
     serializer.write_data(&[]);
-    serializer.write_var_data(&vec![0u8; 256]);
+    serializer.write_var_data(&vec![0u8; var_size as usize]);
 
     let buffer = serializer.into_buffer();
     let view = BinaryView::view(&buffer).unwrap();
+    let blob = view.get_blob(1).unwrap();
+    assert_eq!(blob.len(), var_size as usize);
+}
 
-    // Try to get as string when it's a blob
-    match view.get_string(10) {
-        Err(SerializationError::FieldSizeMismatch { .. }) => {}
-        _ => panic!("Expected FieldSizeMismatch error for wrong type"),
+#[test]
+fn test_schema_builder_max_var_size() {
+    let mut builder = SchemaBuilder::new();
+    builder.add_var(1, FieldType::Blob, &vec![7u8; u16::MAX as usize]).unwrap();
+    let buffer = builder.build().unwrap();
+
+    let view = BinaryView::view(&buffer).unwrap();
+    let blob = view.get_blob(1).unwrap();
+    assert_eq!(blob.len(), u16::MAX as usize);
+    assert!(blob.iter().all(|&b| b == 7));
+}
+
+#[test]
+fn test_error_field_too_large() {
+    let mut builder = SchemaBuilder::new();
+    match builder.add_var(1, FieldType::Blob, &vec![0u8; u16::MAX as usize + 1]) {
+        Err(SerializationError::FieldTooLarge { field_id, requested }) => {
+            assert_eq!(field_id, 1);
+            assert_eq!(requested, u16::MAX as usize + 1);
+        }
+        _ => panic!("Expected FieldTooLarge error"),
+    }
+
+    // The NUL terminator counts against a string's reservation
+    let name = "x".repeat(u16::MAX as usize);
+    match builder.add_var(2, FieldType::String, name.as_bytes()) {
+        Err(SerializationError::FieldTooLarge { field_id, requested }) => {
+            assert_eq!(field_id, 2);
+            assert_eq!(requested, u16::MAX as usize + 1);
+        }
+        _ => panic!("Expected FieldTooLarge error"),
     }
 }
 
 #[test]
-fn test_modify_string_to_empty() {
-    let mut serializer = BinarySerializer::new();
-    let header = FormatHeader::new(
-        1 * std::mem::size_of::<OffsetEntry>() as u32,
-        0,
-        256,
-    );
-    serializer.write_header(header);
+fn test_schema_epoch() {
+    let mut builder = SchemaBuilder::new();
+    builder.add_fixed(1, FieldType::Uint32, &7u32.to_le_bytes()).unwrap();
+    builder.with_epoch(3);
+    let buffer = builder.build().unwrap();
 
-    let entries = vec![OffsetEntry {
-        field_id: 10,
-        offset: 0,
-        field_type: FieldType::String as u16,
-        size: 256,
-    }];
-    serializer.write_offset_table(&entries);
-    serializer.write_data(&[]);
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.schema_epoch(), 3);
+    assert_eq!(view.get_field_copy::<u32>(1).unwrap(), 7);
 
-    let mut var_data = vec![0u8; 256];
-    var_data[0..5].copy_from_slice(b"Hello");
-    serializer.write_var_data(&var_data);
+    // Buffers built by hand default to epoch 0
+    let buffer = create_test_buffer();
+    assert_eq!(BinaryView::view(&buffer).unwrap().schema_epoch(), 0);
+}
 
-    let mut buffer = serializer.into_buffer();
-    let mut view_mut = BinaryViewMut::view_mut(&mut buffer).unwrap();
-    view_mut.modify_string(10, "").unwrap();
+fn user_schema_builder() -> SchemaBuilder {
+    let mut builder = SchemaBuilder::new();
+    builder
+        .add_fixed(1, FieldType::Uint64, &12345u64.to_le_bytes()).unwrap()
+        .add_fixed(2, FieldType::Uint32, &30u32.to_le_bytes()).unwrap()
+        .add_fixed(3, FieldType::Float64, &95.5f64.to_le_bytes()).unwrap()
+        .add_fixed(4, FieldType::Uint8, &[1]).unwrap();
+    builder
+}
+
+#[test]
+fn test_schema_conforms() {
+    let schema = user_schema_builder().schema();
+    let buffer = create_test_buffer();
+    schema.conforms(&buffer).unwrap();
+    schema.conforms_exact(&buffer).unwrap();
+
+    // Extra fields are tolerated unless checking exactly
+    let mut extended = user_schema_builder();
+    extended.add_var(5, FieldType::String, b"extra").unwrap();
+    let buffer = extended.build().unwrap();
+    schema.conforms(&buffer).unwrap();
+    match schema.conforms_exact(&buffer) {
+        Err(SerializationError::UnexpectedField { field_id }) => assert_eq!(field_id, 5),
+        _ => panic!("Expected UnexpectedField error"),
+    }
+
+    // Missing field
+    let mut partial = SchemaBuilder::new();
+    partial.add_fixed(1, FieldType::Uint64, &0u64.to_le_bytes()).unwrap();
+    match schema.conforms(&partial.build().unwrap()) {
+        Err(SerializationError::MissingField { field_id }) => assert_eq!(field_id, 2),
+        _ => panic!("Expected MissingField error"),
+    }
+
+    // Mistyped field
+    let mut mistyped = SchemaBuilder::new();
+    mistyped
+        .add_fixed(1, FieldType::Int64, &0i64.to_le_bytes()).unwrap()
+        .add_fixed(2, FieldType::Uint32, &0u32.to_le_bytes()).unwrap()
+        .add_fixed(3, FieldType::Float64, &0f64.to_le_bytes()).unwrap()
+        .add_fixed(4, FieldType::Uint8, &[0]).unwrap();
+    match schema.conforms(&mistyped.build().unwrap()) {
+        Err(SerializationError::FieldTypeMismatch { field_id, expected, found }) => {
+            assert_eq!(field_id, 1);
+            assert_eq!(expected, FieldType::Uint64 as u16);
+            assert_eq!(found, FieldType::Int64 as u16);
+        }
+        _ => panic!("Expected FieldTypeMismatch error"),
+    }
+
+    // A field ending past u32::MAX can't be laid out
+    let overflowing = OffsetEntry { field_id: 1, offset: u32::MAX - 4, field_type: FieldType::Uint64 as u16, size: 8 };
+    assert!(matches!(
+        Schema::new(vec![overflowing]),
+        Err(SerializationError::InvalidOffset { offset: 4_294_967_299, .. })
+    ));
+}
+
+#[test]
+fn test_mut_editor() {
+    let builder = user_schema_builder();
+    let editor = MutEditor::new(builder.schema());
+    let mut buffers = [builder.build().unwrap(), builder.build().unwrap()];
+
+    for (age, buffer) in buffers.iter_mut().enumerate() {
+        let mut edit = editor.bind(buffer).unwrap();
+        edit.set(2, &(age as u32 + 40)).unwrap();
+        edit.set(3, &1.25f64).unwrap();
+        assert_eq!(edit.get::<u32>(2).unwrap(), age as u32 + 40);
+        assert!(matches!(edit.set(2, &1u64), Err(SerializationError::FieldSizeMismatch { expected: 4, got: 8 })));
+        assert!(matches!(edit.set(9, &1u8), Err(SerializationError::FieldNotFound { field_id: 9 })));
+    }
+    for (age, buffer) in buffers.iter().enumerate() {
+        let view = BinaryView::view(buffer).unwrap();
+        assert_eq!(view.get_field_copy::<u32>(2).unwrap(), age as u32 + 40);
+        assert_eq!(view.get_field_copy::<f64>(3).unwrap(), 1.25);
+        assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 12345);
+    }
 
+    // Same sizes but a different field order is refused
+    let mut reordered = SchemaBuilder::new();
+    reordered
+        .add_fixed(2, FieldType::Uint32, &30u32.to_le_bytes()).unwrap()
+        .add_fixed(1, FieldType::Uint64, &12345u64.to_le_bytes()).unwrap()
+        .add_fixed(3, FieldType::Float64, &95.5f64.to_le_bytes()).unwrap()
+        .add_fixed(4, FieldType::Uint8, &[1]).unwrap();
+    let mut reordered = reordered.build().unwrap();
+    assert!(matches!(editor.bind(&mut reordered), Err(SerializationError::SchemaMismatch)));
+
+    let mut other = create_test_buffer();
+    assert!(matches!(editor.bind(&mut other), Err(SerializationError::SchemaMismatch)));
+    assert!(matches!(editor.bind(&mut [0u8; 8]), Err(SerializationError::BufferTooSmall { .. })));
+}
+
+#[test]
+fn test_remap() {
+    let mut source = SchemaBuilder::new();
+    source
+        .add_fixed(1, FieldType::Uint64, &42u64.to_le_bytes()).unwrap()
+        .add_var(2, FieldType::String, b"Alice").unwrap();
+    let source = source.build().unwrap();
+
+    let mut target = SchemaBuilder::new();
+    target
+        .add_var_with_capacity(10, FieldType::String, b"", 16).unwrap()
+        .add_fixed_with_default(11, FieldType::Uint32, &7u32.to_le_bytes()).unwrap()
+        .add::<u64>(12);
+    let target_schema = target.schema();
+
+    let remapped = remap(&source, &[(1, 12), (2, 10)], &target_schema).unwrap();
+    target_schema.conforms_exact(&remapped).unwrap();
+    let view = BinaryView::view(&remapped).unwrap();
+    assert_eq!(view.get_field_copy::<u64>(12).unwrap(), 42);
+    assert_eq!(view.get_string(10).unwrap(), "Alice");
+    assert_eq!(view.get_field_copy::<u32>(11).unwrap(), 7);
+
+    assert!(matches!(
+        remap(&source, &[(1, 11)], &target_schema),
+        Err(SerializationError::FieldTypeMismatch { field_id: 11, .. })
+    ));
+    assert!(matches!(
+        remap(&source, &[(3, 12)], &target_schema),
+        Err(SerializationError::FieldNotFound { field_id: 3 })
+    ));
+    let mut narrow = SchemaBuilder::new();
+    narrow.add_var_with_capacity(10, FieldType::String, b"", 4).unwrap();
+    assert!(matches!(
+        remap(&source, &[(2, 10)], &narrow.schema()),
+        Err(SerializationError::FieldSizeMismatch { expected: 4, got: 6 })
+    ));
+}
+
+#[test]
+fn test_finite_floats() {
+    let mut builder = user_schema_builder();
+    builder
+        .add_fixed(5, FieldType::Float64, &f64::NAN.to_le_bytes()).unwrap()
+        .add_fixed(6, FieldType::Float64, &f64::NEG_INFINITY.to_le_bytes()).unwrap()
+        .add_fixed(7, FieldType::Float32, &(-0.0f32).to_le_bytes()).unwrap();
+    let buffer = builder.build().unwrap();
     let view = BinaryView::view(&buffer).unwrap();
-    assert_eq!(view.get_string(10).unwrap(), "");
+
+    assert!(view.get_field_copy::<f64>(5).unwrap().is_nan());
+    assert!(matches!(view.get_f64_finite(5), Err(SerializationError::NonFiniteFloat { field_id: 5 })));
+    assert!(matches!(view.get_f64_finite(6), Err(SerializationError::NonFiniteFloat { field_id: 6 })));
+    assert_eq!(view.get_f64_finite(3).unwrap(), 95.5);
+    assert!(matches!(
+        view.get_f64_finite(1),
+        Err(SerializationError::FieldTypeMismatch { field_id: 1, .. })
+    ));
+
+    use std::num::FpCategory;
+    assert_eq!(view.float_class(5).unwrap(), FpCategory::Nan);
+    assert_eq!(view.float_class(6).unwrap(), FpCategory::Infinite);
+    assert_eq!(view.float_class(7).unwrap(), FpCategory::Zero);
+    assert_eq!(view.float_class(3).unwrap(), FpCategory::Normal);
+    assert!(view.float_class(2).is_err());
 }
 
 #[test]
-fn test_find_entry() {
-    let buffer = create_test_buffer();
+fn test_sort_fields() {
+    let mut in_order = user_schema_builder();
+    in_order.add_var(5, FieldType::String, b"Alice").unwrap();
+    let in_order = in_order.build().unwrap();
+
+    let mut shuffled = SchemaBuilder::new();
+    shuffled
+        .add_var(5, FieldType::String, b"Alice").unwrap()
+        .add_fixed(4, FieldType::Uint8, &[1]).unwrap()
+        .add_fixed(1, FieldType::Uint64, &12345u64.to_le_bytes()).unwrap()
+        .add_fixed(3, FieldType::Float64, &95.5f64.to_le_bytes()).unwrap()
+        .add_fixed(2, FieldType::Uint32, &30u32.to_le_bytes()).unwrap();
+    let shuffled = shuffled.build().unwrap();
+    assert_ne!(shuffled, in_order);
+
+    assert_eq!(sort_fields(&shuffled).unwrap(), in_order);
+    assert_eq!(sort_fields(&in_order).unwrap(), in_order);
+
+    // Aligned layouts stay aligned, and a stored checksum is refreshed
+    let mut aligned = SchemaBuilder::new();
+    aligned
+        .align_fields(true)
+        .add_fixed(4, FieldType::Uint8, &[1]).unwrap()
+        .add_fixed(2, FieldType::Uint32, &30u32.to_le_bytes()).unwrap()
+        .add_fixed(1, FieldType::Uint64, &12345u64.to_le_bytes()).unwrap();
+    let mut aligned = aligned.build().unwrap();
+    BinaryViewMut::view_mut(&mut aligned).unwrap().store_checksum().unwrap();
+
+    let sorted = sort_fields(&aligned).unwrap();
+    let view = BinaryView::view(&sorted).unwrap();
+    view.verify().unwrap();
+    assert_eq!(view.fields_by_offset().iter().map(|e| e.field_id).collect::<Vec<_>>(), [1, 2, 4]);
+    assert_eq!(view.field_offset(1).unwrap() % 8, 0);
+    assert_eq!(view.field_offset(2).unwrap() % 4, 0);
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 12345);
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 30);
+    assert_eq!(view.get_field_copy::<u8>(4).unwrap(), 1);
+
+    // Each record of a batch is reordered
+    let mut builder = SchemaBuilder::new();
+    builder
+        .add_var_with_capacity(3, FieldType::String, b"", 8).unwrap()
+        .add_fixed(2, FieldType::Bool, &[0]).unwrap()
+        .add_fixed(1, FieldType::Uint64, &0u64.to_le_bytes()).unwrap();
+    let mut batch = BatchSerializer::new(builder.schema());
+    for (id, message) in [(1u64, "start"), (2, "stop")] {
+        batch.push(&[FieldValue::String(message), FieldValue::Bool(true), FieldValue::Uint64(id)]).unwrap();
+    }
+    let sorted = sort_fields(&batch.finish()).unwrap();
+    let view = BinaryView::view(&sorted).unwrap();
+    assert_eq!(view.field_offset(1).unwrap(), view.field_offset(2).unwrap() - 8);
+    let record = view.record(1).unwrap();
+    assert_eq!(record.get_field_copy::<u64>(1).unwrap(), 2);
+    assert_eq!(record.get_string(3).unwrap(), "stop");
+}
+
+#[test]
+fn test_metadata() {
+    let plain = user_schema_builder().build().unwrap();
+    let mut builder = user_schema_builder();
+    builder
+        .add_metadata("content-type", "application/x-user").unwrap()
+        .add_metadata("producer", "ingest-7").unwrap();
+    let buffer = builder.build().unwrap();
+    assert_eq!(builder.total_size(), buffer.len());
+
     let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.metadata("content-type"), Some("application/x-user"));
+    assert_eq!(view.metadata("producer"), Some("ingest-7"));
+    assert_eq!(view.metadata("timestamp"), None);
+    assert_eq!(
+        view.metadata_iter().collect::<Vec<_>>(),
+        [("content-type", "application/x-user"), ("producer", "ingest-7")]
+    );
 
-    assert!(view.find_entry(1).is_some());
-    assert!(view.find_entry(2).is_some());
-    assert!(view.find_entry(3).is_some());
-    assert!(view.find_entry(4).is_some());
-    assert!(view.find_entry(999).is_none());
+    // Field data and layout are unaffected
+    let plain_view = BinaryView::view(&plain).unwrap();
+    assert_eq!(&buffer[format::HEADER_SIZE..plain.len()], &plain[format::HEADER_SIZE..]);
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 12345);
+    assert_eq!(plain_view.metadata_iter().count(), 0);
+
+    let rebuilt = SchemaBuilder::from_view(&view).unwrap().build().unwrap();
+    assert_eq!(BinaryView::view(&rebuilt).unwrap().metadata("producer"), Some("ingest-7"));
+
+    builder.add_metadata("producer", "ingest-8").unwrap();
+    let buffer = builder.build().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.metadata("producer"), Some("ingest-8"));
+    assert_eq!(view.metadata_iter().count(), 2);
 }
 
 #[test]
-fn test_buffer_methods() {
+fn test_from_packed_struct() {
+    let data = TestData { id: 12345, age: 30, score: 95.5, active: 1 };
+    let descriptors = [
+        (1, FieldType::Uint64),
+        (2, FieldType::Uint32),
+        (3, FieldType::Float64),
+        (4, FieldType::Uint8),
+    ];
+    let built = SchemaBuilder::from_packed_struct(&descriptors, bytemuck::bytes_of(&data))
+        .unwrap()
+        .build()
+        .unwrap();
+
+    // The hand-written equivalent, offsets tracking the struct layout
+    let entries = [
+        OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Uint64 as u16, size: 8 },
+        OffsetEntry { field_id: 2, offset: 8, field_type: FieldType::Uint32 as u16, size: 4 },
+        OffsetEntry { field_id: 3, offset: 12, field_type: FieldType::Float64 as u16, size: 8 },
+        OffsetEntry { field_id: 4, offset: 20, field_type: FieldType::Uint8 as u16, size: 1 },
+    ];
     let mut serializer = BinarySerializer::new();
-    let header = FormatHeader::new(0, 0, 0);
-    serializer.write_header(header);
+    serializer.write_header(FormatHeader::new(48, std::mem::size_of::<TestData>() as u32, 0));
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(bytemuck::bytes_of(&data));
+    assert_eq!(built, serializer.into_buffer());
+
+    assert!(matches!(
+        SchemaBuilder::from_packed_struct(&descriptors[..3], bytemuck::bytes_of(&data)),
+        Err(SerializationError::FieldSizeMismatch { expected: 20, got: 21 })
+    ));
+    assert!(SchemaBuilder::from_packed_struct(&[(1, FieldType::Blob)], &[]).is_err());
+}
+
+#[test]
+fn test_field_ptr_len() {
+    let mut builder = user_schema_builder();
+    builder.add_var_with_capacity(5, FieldType::String, b"Hi", 16).unwrap();
+    let buffer = builder.build().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+
+    for field_id in [1, 3, 5] {
+        let (ptr, len) = view.field_ptr_len(field_id).unwrap();
+        let bytes = unsafe { std::slice::from_raw_parts(ptr, len) };
+        assert_eq!(bytes, view.field_raw_bytes(field_id).unwrap());
+        assert_eq!(ptr as usize - buffer.as_ptr() as usize, view.field_offset(field_id).unwrap());
+    }
+    let (_, len) = view.field_ptr_len(1).unwrap();
+    assert_eq!(len, 8);
+    assert!(matches!(
+        view.field_ptr_len(99),
+        Err(SerializationError::FieldNotFound { field_id: 99 })
+    ));
+}
+
+#[test]
+fn test_utilization() {
+    let mut builder = user_schema_builder();
+    builder.add_var_with_capacity(5, FieldType::String, b"Hi", 256).unwrap();
+    let buffer = builder.build().unwrap();
+    let utilization = BinaryView::view(&buffer).unwrap().utilization();
     
-    let buffer_ref = serializer.buffer();
-    assert!(buffer_ref.len() >= 80); // At least header size
+    assert_eq!(utilization.total_size, buffer.len());
+    // 21 fixed bytes plus "Hi" and its terminator
+    assert_eq!(utilization.used_bytes, 21 + 3);
+    assert_eq!(utilization.reserved_var_bytes, 256);
+    assert_eq!(utilization.unused_var_bytes, 253);
     
-    let buffer = serializer.into_buffer();
-    assert!(buffer.len() >= 80);
+    let mut builder = user_schema_builder();
+    builder.add_var(5, FieldType::Blob, &[1, 0, 2]).unwrap();
+    let buffer = builder.build().unwrap();
+    let utilization = BinaryView::view(&buffer).unwrap().utilization();
+    assert_eq!(utilization.unused_var_bytes, 0);
+    assert_eq!(utilization.used_bytes, 24);
+}
+
+#[test]
+fn test_schema_fingerprint() {
+    let mut builder = SchemaBuilder::new();
+    builder
+        .add_fixed(1, FieldType::Uint8, &[1]).unwrap()
+        .add_var(2, FieldType::String, b"name").unwrap()
+        .add_fixed(3, FieldType::Uint64, &7u64.to_le_bytes()).unwrap();
+    let packed = builder.build().unwrap();
+    let fingerprint = BinaryView::view(&packed).unwrap().schema_fingerprint();
+
+    // Same fields, different physical layout and values
+    builder.optimize_layout().align_fields(true);
+    let mut relaid = builder.build().unwrap();
+    BinaryViewMut::view_mut(&mut relaid).unwrap().modify_field(3, &9u64).unwrap();
+    let view = BinaryView::view(&relaid).unwrap();
+    assert_ne!(view.field_offset(3).unwrap(), BinaryView::view(&packed).unwrap().field_offset(3).unwrap());
+    assert_eq!(view.schema_fingerprint(), fingerprint);
+
+    // Same fields declared in another order
+    let mut reordered = SchemaBuilder::new();
+    reordered
+        .add_fixed(3, FieldType::Uint64, &0u64.to_le_bytes()).unwrap()
+        .add_fixed(1, FieldType::Uint8, &[0]).unwrap()
+        .add_var(2, FieldType::String, b"rest").unwrap();
+    let buffer = reordered.build().unwrap();
+    assert_eq!(BinaryView::view(&buffer).unwrap().schema_fingerprint(), fingerprint);
+
+    // A different type or reservation changes it
+    let mut widened = SchemaBuilder::new();
+    widened
+        .add_fixed(1, FieldType::Uint8, &[1]).unwrap()
+        .add_var(2, FieldType::String, b"longer").unwrap()
+        .add_fixed(3, FieldType::Uint64, &7u64.to_le_bytes()).unwrap();
+    let buffer = widened.build().unwrap();
+    assert_ne!(BinaryView::view(&buffer).unwrap().schema_fingerprint(), fingerprint);
+}
+
+#[test]
+fn test_field_defaults() {
+    // v1 buffers predate field 5
+    let old = user_schema_builder().build().unwrap();
+
+    let mut v2 = user_schema_builder();
+    v2.add_fixed_with_default(5, FieldType::Int32, &(-1i32).to_le_bytes()).unwrap();
+    let schema = v2.schema();
+    assert_eq!(schema.field_default(5), Some(&(-1i32).to_le_bytes()[..]));
+    assert_eq!(schema.field_default(1), None);
+    schema.conforms(&old).unwrap();
+
+    let view = BinaryView::view(&old).unwrap();
+    assert_eq!(view.get_field_copy_or_default::<i32>(&schema, 5).unwrap(), -1);
+    assert_eq!(view.get_field_copy_or_default::<u32>(&schema, 2).unwrap(), 30);
+    assert!(matches!(
+        view.get_field_copy_or_default::<u64>(&schema, 5),
+        Err(SerializationError::FieldSizeMismatch { expected: 4, got: 8 })
+    ));
+    assert!(matches!(
+        view.get_field_copy_or_default::<u32>(&schema, 9),
+        Err(SerializationError::FieldNotFound { field_id: 9 })
+    ));
+
+    // New buffers start at the default, record it, and keep it through from_view
+    let new = v2.build().unwrap();
+    assert_eq!(v2.total_size(), new.len());
+    let view = BinaryView::view(&new).unwrap();
+    assert_eq!(view.get_field_copy::<i32>(5).unwrap(), -1);
+    assert_eq!(view.field_default(5), Some(&(-1i32).to_le_bytes()[..]));
+    assert_eq!(view.field_default(1), None);
+    let rebuilt = SchemaBuilder::from_view(&view).unwrap().schema();
+    assert_eq!(rebuilt.field_default(5), Some(&(-1i32).to_le_bytes()[..]));
+}
+
+#[test]
+fn test_add_var_with_capacity() {
+    let mut builder = SchemaBuilder::new();
+    builder.add_var_with_capacity(10, FieldType::String, b"Hi", 64).unwrap();
+    builder.add_var_with_capacity(20, FieldType::Blob, &[1, 2, 3], 16).unwrap();
+    let mut buffer = builder.build().unwrap();
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_string(10).unwrap(), "Hi");
+    assert_eq!(view.get_blob(20).unwrap(), &[1, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    // The reserved headroom is usable for in-place growth
+    let mut view_mut = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    view_mut.modify_string(10, "A much longer greeting than before").unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_string(10).unwrap(), "A much longer greeting than before");
+
+    match SchemaBuilder::new().add_var_with_capacity(1, FieldType::Blob, &[0u8; 8], 4) {
+        Err(SerializationError::FieldSizeMismatch { expected, got }) => {
+            assert_eq!(expected, 4);
+            assert_eq!(got, 8);
+        }
+        _ => panic!("Expected FieldSizeMismatch error"),
+    }
+}
+
+#[test]
+fn test_field_names() {
+    let mut builder = user_schema_builder();
+    builder.add_var(5, FieldType::String, b"Alice").unwrap();
+    builder.add_named(1, "id").unwrap().add_named(5, "name").unwrap();
+    let buffer = builder.build().unwrap();
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.field_name(1), Some("id"));
+    assert_eq!(view.field_name(5), Some("name"));
+    assert_eq!(view.field_name(2), None);
+
+    // Data fields are unaffected by the trailing name table
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 12345);
+    assert_eq!(view.get_string(5).unwrap(), "Alice");
+
+    // Unnamed buffers pay nothing and report no names
+    let plain = user_schema_builder().build().unwrap();
+    assert_eq!(plain.len(), 80 + 4 * std::mem::size_of::<OffsetEntry>() + 21);
+    assert_eq!(BinaryView::view(&plain).unwrap().field_name(1), None);
+}
+
+#[test]
+fn test_optimize_layout() {
+    let mut builder = SchemaBuilder::new();
+    builder
+        .add_fixed(1, FieldType::Uint8, &[7]).unwrap()
+        .add_fixed(2, FieldType::Uint64, &11u64.to_le_bytes()).unwrap()
+        .add_var(3, FieldType::String, b"name").unwrap()
+        .add_fixed(4, FieldType::Uint16, &13u16.to_le_bytes()).unwrap()
+        .add_fixed(5, FieldType::Uint32, &17u32.to_le_bytes()).unwrap()
+        .add_fixed(6, FieldType::Float64, &1.5f64.to_le_bytes()).unwrap();
+    builder.optimize_layout();
+    let buffer = builder.build().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+
+    let placed: Vec<(u32, u16)> = view.fields_by_offset().iter().map(|e| (e.field_id, e.size)).collect();
+    assert_eq!(placed, vec![(2, 8), (6, 8), (5, 4), (4, 2), (1, 1)]);
+
+    assert_eq!(view.get_field_copy::<u8>(1).unwrap(), 7);
+    assert_eq!(view.get_field_copy::<u64>(2).unwrap(), 11);
+    assert_eq!(view.get_string(3).unwrap(), "name");
+    assert_eq!(view.get_field_copy::<u16>(4).unwrap(), 13);
+    assert_eq!(view.get_field_copy::<u32>(5).unwrap(), 17);
+    assert_eq!(view.get_field_copy::<f64>(6).unwrap(), 1.5);
+}
+
+#[test]
+fn test_incremental_serializer() {
+    let mut serializer = IncrementalSerializer::new();
+    serializer.push_var(10, FieldType::String, b"first", 32).unwrap();
+    serializer.push_fixed(1, FieldType::Uint64, &42u64.to_le_bytes()).unwrap();
+    serializer.push_var(20, FieldType::Blob, &[1, 2, 3], 8).unwrap();
+    serializer.push_fixed(2, FieldType::Float32, &2.5f32.to_le_bytes()).unwrap();
+    assert_eq!(serializer.len(), 4);
+
+    assert!(matches!(
+        serializer.push_fixed(3, FieldType::Uint16, &[0u8; 4]),
+        Err(SerializationError::FieldSizeMismatch { expected: 2, got: 4 })
+    ));
+    assert!(matches!(
+        serializer.push_var(30, FieldType::Blob, &[0u8; 16], 8),
+        Err(SerializationError::FieldSizeMismatch { expected: 8, got: 16 })
+    ));
+
+    let buffer = serializer.finish();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_string(10).unwrap(), "first");
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 42);
+    assert_eq!(&view.get_blob(20).unwrap()[..3], &[1, 2, 3]);
+    assert_eq!(view.get_field_copy::<f32>(2).unwrap(), 2.5);
+
+    let offset = view.find_entry(20).unwrap().offset;
+    assert_eq!(offset, 32);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_push_var_from_reader() {
+    use std::io::Cursor;
+
+    let mut serializer = IncrementalSerializer::new();
+    let short = serializer.push_var_from_reader(1, Cursor::new(vec![7u8; 5]), 16, false).unwrap();
+    assert_eq!(short, 5);
+
+    let cut = serializer.push_var_from_reader(2, Cursor::new(vec![9u8; 40]), 16, true).unwrap();
+    assert_eq!(cut, 16);
+
+    let exact = serializer.push_var_from_reader(3, Cursor::new(vec![3u8; 8]), 8, false).unwrap();
+    assert_eq!(exact, 8);
+
+    assert!(matches!(
+        serializer.push_var_from_reader(4, Cursor::new(vec![1u8; 17]), 16, false),
+        Err(SerializationError::SourceTooLarge { field_id: 4, reserve: 16 })
+    ));
+    assert_eq!(serializer.len(), 3);
+
+    let buffer = serializer.finish();
+    let view = BinaryView::view(&buffer).unwrap();
+    let blob = view.get_blob(1).unwrap();
+    assert_eq!(blob.len(), 16);
+    assert_eq!(&blob[..5], &[7; 5]);
+    assert!(blob[5..].iter().all(|&b| b == 0));
+    assert_eq!(view.get_blob(2).unwrap(), &[9; 16]);
+    assert_eq!(view.get_blob(3).unwrap(), &[3; 8]);
+    let offset = view.find_entry(3).unwrap().offset;
+    assert_eq!(offset, 32);
+    assert!(view.find_entry(4).is_none());
+}
+
+#[test]
+fn test_push_field() {
+    let fields: [(u32, FieldType, &[u8], Option<u16>); 4] = [
+        (1, FieldType::Uint32, &7u32.to_le_bytes(), None),
+        (2, FieldType::String, b"dynamic", None),
+        (3, FieldType::Float64, &1.25f64.to_le_bytes(), None),
+        (4, FieldType::Blob, &[1, 2, 3], Some(16)),
+    ];
+
+    let mut serializer = IncrementalSerializer::new();
+    for (field_id, field_type, value, reserve) in fields {
+        serializer.push_field(field_id, field_type, value, reserve).unwrap();
+    }
+    assert!(matches!(
+        serializer.push_field(5, FieldType::Int16, &[0u8; 3], None),
+        Err(SerializationError::FieldSizeMismatch { expected: 2, got: 3 })
+    ));
+    assert!(matches!(
+        serializer.push_field(6, FieldType::Blob, &[0u8; 70_000], None),
+        Err(SerializationError::FieldSizeMismatch { expected: 65535, got: 70_000 })
+    ));
+    assert_eq!(serializer.len(), 4);
+
+    let buffer = serializer.finish();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_field_copy::<u32>(1).unwrap(), 7);
+    assert_eq!(view.get_string(2).unwrap(), "dynamic");
+    assert_eq!(view.get_field_copy::<f64>(3).unwrap(), 1.25);
+    assert_eq!(&view.get_blob(4).unwrap()[..3], &[1, 2, 3]);
+
+    // Fixed fields land in the data section, var fields in the var section
+    assert_eq!(view.field_offset(3).unwrap() - view.field_offset(1).unwrap(), 4);
+    let string_size = view.find_entry(2).unwrap().size;
+    let blob_size = view.find_entry(4).unwrap().size;
+    assert_eq!((string_size, blob_size), (8, 16));
+}
+
+#[test]
+fn test_typed_field_builder() {
+    let mut builder = SchemaBuilder::new();
+    builder
+        .add_fixed_field(1, FieldType::Uint64, &12345u64).unwrap()
+        .add_string_field(10, 32, "Alice").unwrap()
+        .add_fixed_field(2, FieldType::Float32, &2.5f32).unwrap();
+
+    assert!(matches!(
+        builder.add_fixed_field(3, FieldType::Uint16, &7u32),
+        Err(SerializationError::FieldSizeMismatch { expected: 2, got: 4 })
+    ));
+    assert!(matches!(
+        builder.add_string_field(11, 4, "four"),
+        Err(SerializationError::FieldSizeMismatch { expected: 4, got: 5 })
+    ));
+
+    let buffer = builder.build().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 12345);
+    assert_eq!(view.get_field_copy::<f32>(2).unwrap(), 2.5);
+    assert_eq!(view.get_string(10).unwrap(), "Alice");
+    assert_eq!(view.field_offset(2).unwrap() - view.field_offset(1).unwrap(), 8);
+    let size = view.find_entry(10).unwrap().size;
+    assert_eq!(size, 32);
+}
+
+#[test]
+fn test_align_fields() {
+    let mut builder = SchemaBuilder::new();
+    builder
+        .add_fixed(1, FieldType::Uint8, &[1]).unwrap()
+        .add_fixed(2, FieldType::Uint64, &2u64.to_le_bytes()).unwrap()
+        .add_fixed(3, FieldType::Uint16, &3u16.to_le_bytes()).unwrap()
+        .add_fixed(4, FieldType::Float64, &4.5f64.to_le_bytes()).unwrap()
+        .add_var(5, FieldType::String, b"five").unwrap();
+    builder.align_fields(true);
+    let buffer = builder.build().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(view.is_aligned_layout());
+
+    for (field_id, width) in [(1u32, 1usize), (2, 8), (3, 2), (4, 8)] {
+        assert!(view.field_offset(field_id).unwrap().is_multiple_of(width));
+    }
+
+    assert_eq!(view.get_field_copy::<u8>(1).unwrap(), 1);
+    assert_eq!(view.get_field_copy::<u64>(2).unwrap(), 2);
+    assert_eq!(view.get_field_copy::<u16>(3).unwrap(), 3);
+    assert_eq!(view.get_string(5).unwrap(), "five");
+    let score = view.get_field_cow::<f64>(4).unwrap();
+    assert_eq!(*score, 4.5);
+    if (buffer.as_ptr() as usize).is_multiple_of(8) {
+        assert!(matches!(score, Cow::Borrowed(_)));
+    }
+
+    assert!(!BinaryView::view(&create_test_buffer()).unwrap().is_aligned_layout());
+}
+
+#[cfg(feature = "memmap")]
+#[test]
+fn test_view_from_mmap() {
+    use bisere::memmap2::Mmap;
+    
+    let path = std::env::temp_dir().join(format!("bisere-mmap-{}.bin", std::process::id()));
+    let buffer = create_test_buffer();
+    let map = |bytes: &[u8]| {
+        std::fs::write(&path, bytes).unwrap();
+        let file = std::fs::File::open(&path).unwrap();
+        unsafe { Mmap::map(&file) }.unwrap()
+    };
+    
+    // Trailing padding past `total_size()` is ignored
+    let mut padded = buffer.clone();
+    padded.resize(4096, 0xAA);
+    let mmap = map(&padded);
+    let view = BinaryView::from_mmap(&mmap).unwrap();
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 12345);
+    assert_eq!(view.get_field_copy::<f64>(3).unwrap(), 95.5);
+    
+    let mmap = map(&buffer[..300]);
+    assert!(matches!(
+        BinaryView::from_mmap(&mmap),
+        Err(SerializationError::BufferTooSmall { needed: 405, have: 300 })
+    ));
+    
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(feature = "hmac")]
+fn signed_buffer(key: &[u8]) -> Vec<u8> {
+    let entries = [
+        OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Uint64 as u16, size: 8 },
+        OffsetEntry { field_id: 2, offset: 0, field_type: FieldType::String as u16, size: 8 },
+    ];
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(FormatHeader::new(24, 8, 8));
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&42u64.to_le_bytes());
+    serializer.write_var_data(b"signed\0\0");
+    serializer.finalize_signed(key).unwrap()
+}
+
+#[cfg(feature = "hmac")]
+#[test]
+fn test_signed_buffer() {
+    let key = b"shared secret";
+    let buffer = signed_buffer(key);
+    let view = BinaryView::view(&buffer).unwrap();
+    view.verify_signature(key).unwrap();
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 42);
+    assert_eq!(view.get_string(2).unwrap(), "signed");
+    assert!(matches!(view.verify_signature(b"other key"), Err(SerializationError::SignatureMismatch)));
+
+    // Every byte is covered, from the header sizes to the tag itself
+    for index in 0..buffer.len() {
+        let mut tampered = buffer.clone();
+        tampered[index] ^= 0x01;
+        let result = BinaryView::view(&tampered).and_then(|view| view.verify_signature(key));
+        assert!(result.is_err(), "flipping byte {} went unnoticed", index);
+    }
+
+    let unsigned = create_test_buffer();
+    assert!(matches!(
+        BinaryView::view(&unsigned).unwrap().verify_signature(key),
+        Err(SerializationError::SignatureMissing)
+    ));
+
+    // Signing goes through `finish`, so the byte order is applied first
+    let foreign = match Endianness::native() {
+        Endianness::Little => Endianness::Big,
+        Endianness::Big => Endianness::Little,
+    };
+    let entries = [OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Uint64 as u16, size: 8 }];
+    let mut serializer = BinarySerializer::new().with_endianness(foreign);
+    serializer.write_header(FormatHeader::new(12, 8, 0));
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&7u64.to_ne_bytes());
+    let buffer = serializer.finalize_signed(key).unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    view.verify_signature(key).unwrap();
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 7);
+
+    let mut serializer = BinarySerializer::new().with_checksum(ChecksumAlgo::Crc32c);
+    serializer.write_header(FormatHeader::new(12, 8, 0));
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&7u64.to_ne_bytes());
+    assert!(matches!(serializer.finalize_signed(key), Err(SerializationError::ChecksumWithSignature)));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_to_value() {
+    let mut builder = SchemaBuilder::new();
+    builder
+        .add_fixed(1, FieldType::Uint64, &u64::MAX.to_le_bytes()).unwrap()
+        .add_fixed(2, FieldType::Int32, &(-7i32).to_le_bytes()).unwrap()
+        .add_fixed(3, FieldType::Float64, &95.5f64.to_le_bytes()).unwrap()
+        .add_fixed(4, FieldType::Bool, &[1]).unwrap()
+        .add_fixed(5, FieldType::Uint64, &42u64.to_le_bytes()).unwrap()
+        .add_var(6, FieldType::String, b"Alice").unwrap()
+        .add_var(7, FieldType::Blob, &[0xde, 0xad]).unwrap()
+        .add_array(8, &[1i16, -2]).unwrap();
+    let buffer = builder.build().unwrap();
+
+    let value = BinaryView::view(&buffer).unwrap().to_value().unwrap();
+    assert_eq!(value["1"], serde_json::json!("18446744073709551615"));
+    assert_eq!(value["2"], serde_json::json!(-7));
+    assert_eq!(value["3"], serde_json::json!(95.5));
+    assert_eq!(value["4"], serde_json::json!(true));
+    assert_eq!(value["5"], serde_json::json!(42));
+    assert_eq!(value["6"], serde_json::json!("Alice"));
+    assert_eq!(value["7"], serde_json::json!("dead"));
+    assert_eq!(value["8"], serde_json::json!([1, -2]));
+}
+
+#[test]
+fn test_many_fields() {
+    const NUM_FIELDS: usize = 50;
+    let mut serializer = BinarySerializer::new();
+    let offset_table_size = (NUM_FIELDS * std::mem::size_of::<OffsetEntry>()) as u32;
+    let data_size = (NUM_FIELDS * 4) as u32; // Each field is u32
+    let header = FormatHeader::new(offset_table_size, data_size, 0);
+    serializer.write_header(header);
+
+    let mut offset = 0u32;
+    let mut entries = Vec::new();
+    for i in 0..NUM_FIELDS {
+        entries.push(OffsetEntry {
+            field_id: i as u32,
+            offset,
+            field_type: FieldType::Uint32 as u16,
+            size: 4,
+        });
+        offset += 4;
+    }
+    serializer.write_offset_table(&entries).unwrap();
+
+    let mut data = vec![0u8; data_size as usize];
+    for i in 0..NUM_FIELDS {
+        let value = (i * 100) as u32;
+        data[i * 4..(i + 1) * 4].copy_from_slice(&value.to_le_bytes());
+    }
+    serializer.write_data(&data);
+    serializer.write_var_data(&[]);
+
+    let buffer = serializer.into_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+
+    for i in 0..NUM_FIELDS {
+        let value = view.get_field_copy::<u32>(i as u32).unwrap();
+        assert_eq!(value, (i * 100) as u32);
+    }
+}
+
+#[test]
+fn test_string_boundary_conditions() {
+    let mut serializer = BinarySerializer::new();
+    let header = FormatHeader::new(
+        std::mem::size_of::<OffsetEntry>() as u32,
+        0,
+        10,
+    );
+    serializer.write_header(header);
+
+    let entries = vec![OffsetEntry {
+        field_id: 10,
+        offset: 0,
+        field_type: FieldType::String as u16,
+        size: 10, // Exactly 10 bytes (9 chars + null)
+    }];
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&[]);
+
+    let mut var_data = vec![0u8; 10];
+    var_data[0..9].copy_from_slice(b"123456789");
+    serializer.write_var_data(&var_data);
+
+    let buffer = serializer.into_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_string(10).unwrap(), "123456789");
+}
+
+#[test]
+fn test_error_unsupported_version() {
+    let mut buffer = vec![0u8; 100];
+    // Set correct magic but wrong version
+    buffer[0..4].copy_from_slice(&MAGIC.to_le_bytes());
+    buffer[4..8].copy_from_slice(&999u32.to_le_bytes()); // Invalid version
+
+    match BinaryView::view(&buffer) {
+        Err(SerializationError::UnsupportedVersion { version }) => {
+            assert_eq!(version, 999);
+        }
+        _ => panic!("Expected UnsupportedVersion error"),
+    }
+}
+
+#[test]
+fn test_error_wrong_field_type() {
+    let mut serializer = BinarySerializer::new();
+    let header = FormatHeader::new(
+        std::mem::size_of::<OffsetEntry>() as u32,
+        0,
+        256,
+    );
+    serializer.write_header(header);
+
+    let entries = vec![OffsetEntry {
+        field_id: 10,
+        offset: 0,
+        field_type: FieldType::Blob as u16, // Wrong type - should be String
+        size: 256,
+    }];
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&[]);
+    serializer.write_var_data(&vec![0u8; 256]);
+
+    let buffer = serializer.into_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+
+    // Try to get as string when it's a blob
+    match view.get_string(10) {
+        Err(SerializationError::FieldSizeMismatch { .. }) => {}
+        _ => panic!("Expected FieldSizeMismatch error for wrong type"),
+    }
+}
+
+#[test]
+fn test_modify_string_to_empty() {
+    let mut serializer = BinarySerializer::new();
+    let header = FormatHeader::new(
+        std::mem::size_of::<OffsetEntry>() as u32,
+        0,
+        256,
+    );
+    serializer.write_header(header);
+
+    let entries = vec![OffsetEntry {
+        field_id: 10,
+        offset: 0,
+        field_type: FieldType::String as u16,
+        size: 256,
+    }];
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&[]);
+
+    let mut var_data = vec![0u8; 256];
+    var_data[0..5].copy_from_slice(b"Hello");
+    serializer.write_var_data(&var_data);
+
+    let mut buffer = serializer.into_buffer();
+    let mut view_mut = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    view_mut.modify_string(10, "").unwrap();
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_string(10).unwrap(), "");
+}
+
+#[test]
+fn test_truncate_to_logical() {
+    let original = create_test_buffer();
+    let mut buffer = original.clone();
+    buffer.extend_from_slice(&[0xAB; 100]);
+
+    truncate_to_logical(&mut buffer).unwrap();
+    assert_eq!(buffer, original);
+
+    // Already exact buffers are left alone
+    truncate_to_logical(&mut buffer).unwrap();
+    assert_eq!(buffer, original);
+
+    let mut short = original[..original.len() - 1].to_vec();
+    match truncate_to_logical(&mut short) {
+        Err(SerializationError::BufferTooSmall { needed, have }) => {
+            assert_eq!(needed, original.len());
+            assert_eq!(have, original.len() - 1);
+        }
+        _ => panic!("Expected BufferTooSmall error"),
+    }
+}
+
+#[test]
+fn test_field_offset() {
+    let buffer = create_test_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+    let data_start = 80 + 4 * std::mem::size_of::<OffsetEntry>();
+
+    assert_eq!(view.field_offset(1).unwrap(), data_start);
+    assert_eq!(view.field_offset(2).unwrap(), data_start + 8);
+    let age = view.field_offset(2).unwrap();
+    assert_eq!(&buffer[age..age + 4], &30u32.to_le_bytes());
+
+    let mut builder = user_schema_builder();
+    builder.add_var(5, FieldType::String, b"Alice").unwrap();
+    let buffer = builder.build().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    let name = view.field_offset(5).unwrap();
+    assert_eq!(name, 80 + 5 * std::mem::size_of::<OffsetEntry>() + 21);
+    assert_eq!(&buffer[name..name + 5], b"Alice");
+
+    assert!(matches!(view.field_offset(999), Err(SerializationError::FieldNotFound { field_id: 999 })));
+}
+
+#[test]
+fn test_find_entry() {
+    let buffer = create_test_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+
+    assert!(view.find_entry(1).is_some());
+    assert!(view.find_entry(2).is_some());
+    assert!(view.find_entry(3).is_some());
+    assert!(view.find_entry(4).is_some());
+    assert!(view.find_entry(999).is_none());
+}
+
+#[test]
+fn test_has_field_and_field_count() {
+    let mut buffer = create_test_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.field_count(), 4);
+    assert!((1..=4).all(|id| view.has_field(id)));
+    assert!(!view.has_field(999));
+
+    let view_mut = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    assert_eq!(view_mut.field_count(), 4);
+    assert!(view_mut.has_field(3));
+    assert!(!view_mut.has_field(0));
+
+    let empty = SchemaBuilder::new().build().unwrap();
+    assert_eq!(BinaryView::view(&empty).unwrap().field_count(), 0);
+}
+
+#[test]
+fn test_buffer_methods() {
+    let mut serializer = BinarySerializer::new();
+    let header = FormatHeader::new(0, 0, 0);
+    serializer.write_header(header);
+    
+    let buffer_ref = serializer.buffer();
+    assert!(buffer_ref.len() >= 80); // At least header size
+    
+    let buffer = serializer.into_buffer();
+    assert!(buffer.len() >= 80);
+}
+
+#[test]
+fn test_delta_roundtrip() {
+    let base = user_schema_builder().build().unwrap();
+    
+    let mut builder = user_schema_builder();
+    builder.add_var(5, FieldType::String, b"extra").unwrap();
+    let mut new = builder.build().unwrap();
+    BinaryViewMut::view_mut(&mut new).unwrap().modify_field(2, &31u32).unwrap();
+    
+    let delta = make_delta(&base, &new).unwrap();
+    let delta_view = BinaryView::view(&delta).unwrap();
+    let present: Vec<u32> = (1..=5).filter(|&id| delta_view.find_entry(id).is_some()).collect();
+    assert_eq!(present, vec![2, 5]);
+    
+    let rebuilt = apply_delta(&base, &delta).unwrap();
+    let view = BinaryView::view(&rebuilt).unwrap();
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 31);
+    assert_eq!(view.get_string(5).unwrap(), "extra");
+    let expected = BinaryView::view(&new).unwrap();
+    for field_id in [1, 2, 3, 4, 5] {
+        assert_eq!(
+            view.get_many_bytes(&[field_id]).unwrap(),
+            expected.get_many_bytes(&[field_id]).unwrap()
+        );
+    }
+    
+    assert!(matches!(apply_delta(&base, &new), Err(SerializationError::NotADelta)));
+    
+    // Enum dictionaries travel with the delta and into the rebuilt buffer
+    let mut builder = SchemaBuilder::new();
+    builder.add_enum(1, &["red", "green"], "red").unwrap();
+    let base = builder.build().unwrap();
+    let mut new = base.clone();
+    BinaryViewMut::view_mut(&mut new).unwrap().modify_enum(1, "green").unwrap();
+    let delta = make_delta(&base, &new).unwrap();
+    assert_eq!(BinaryView::view(&delta).unwrap().get_enum(1).unwrap(), "green");
+    let rebuilt = apply_delta(&base, &delta).unwrap();
+    assert_eq!(BinaryView::view(&rebuilt).unwrap().get_enum(1).unwrap(), "green");
+}
+
+fn with_endianness_flag(mut buffer: Vec<u8>, endianness: Endianness) -> Vec<u8> {
+    let mut header = bytemuck::pod_read_unaligned::<FormatHeader>(&buffer[..80]);
+    header.set_endianness(endianness);
+    buffer[..80].copy_from_slice(bytemuck::bytes_of(&header));
+    buffer
+}
+
+#[test]
+fn test_delta_across_byte_orders() {
+    // Base and update produced on a big-endian machine
+    let be_base = with_endianness_flag(
+        build(&[
+            (1, FieldType::Uint64, 7u64.to_be_bytes().to_vec()),
+            (2, FieldType::Uint32, 30u32.to_be_bytes().to_vec()),
+            (3, FieldType::String, b"ann\0".to_vec()),
+        ])
+        .unwrap(),
+        Endianness::Big,
+    );
+    let be_new = with_endianness_flag(
+        build(&[
+            (1, FieldType::Uint64, 7u64.to_be_bytes().to_vec()),
+            (2, FieldType::Uint32, 31u32.to_be_bytes().to_vec()),
+            (3, FieldType::String, b"ann\0".to_vec()),
+        ])
+        .unwrap(),
+        Endianness::Big,
+    );
+    assert_eq!(BinaryView::view(&be_base).unwrap().endianness(), Endianness::Big);
+    
+    let delta = make_delta(&be_base, &be_new).unwrap();
+    let delta_view = BinaryView::view(&delta).unwrap();
+    assert_eq!(delta_view.endianness(), Endianness::Big);
+    let present: Vec<u32> = (1..=3).filter(|&id| delta_view.find_entry(id).is_some()).collect();
+    assert_eq!(present, vec![2]);
+    
+    // The little-endian copy of the same base accepts the big-endian delta
+    let le_base = build(&[
+        (1, FieldType::Uint64, 7u64.to_le_bytes().to_vec()),
+        (2, FieldType::Uint32, 30u32.to_le_bytes().to_vec()),
+        (3, FieldType::String, b"ann\0".to_vec()),
+    ])
+    .unwrap();
+    let rebuilt = apply_delta(&le_base, &delta).unwrap();
+    let view = BinaryView::view(&rebuilt).unwrap();
+    assert_eq!(view.endianness(), Endianness::Little);
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 7);
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 31);
+    assert_eq!(view.get_string(3).unwrap(), "ann");
+    
+    // Comparing across byte orders ignores the encoding difference
+    let delta = make_delta(&le_base, &be_new).unwrap();
+    let delta_view = BinaryView::view(&delta).unwrap();
+    let present: Vec<u32> = (1..=3).filter(|&id| delta_view.find_entry(id).is_some()).collect();
+    assert_eq!(present, vec![2]);
+}
+
+#[test]
+fn test_swap_endianness() {
+    let mut builder = user_schema_builder();
+    builder
+        .add_enum(5, &["red", "green", "blue"], "blue").unwrap()
+        .add_var(6, FieldType::String, b"Alice").unwrap()
+        .field_crcs(true);
+    let original = builder.build().unwrap();
+    let mut buffer = original.clone();
+    BinaryViewMut::view_mut(&mut buffer).unwrap().store_checksum().unwrap();
+    let checksummed = buffer.clone();
+
+    swap_endianness(&mut buffer).unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.endianness(), Endianness::Big);
+    assert_eq!(view.field_raw_bytes(1).unwrap(), 12345u64.to_be_bytes());
+    assert_eq!(view.field_raw_bytes(3).unwrap(), 95.5f64.to_be_bytes());
+    assert_eq!(view.field_raw_bytes(4).unwrap(), [1]);
+    assert_eq!(view.field_raw_bytes(5).unwrap(), 2u16.to_be_bytes());
+    assert_eq!(view.get_string(6).unwrap(), "Alice");
+    view.verify().unwrap();
+    for field_id in 1..=6 {
+        view.verify_field(field_id).unwrap();
+    }
+    // Logically the same record
+    let delta = make_delta(&original, &buffer).unwrap();
+    let delta_view = BinaryView::view(&delta).unwrap();
+    assert!((1..=6).all(|id| delta_view.find_entry(id).is_none()));
+
+    swap_endianness(&mut buffer).unwrap();
+    assert_eq!(buffer, checksummed);
+
+    // Each record of a batch is converted
+    let mut batch = BatchSerializer::new(user_schema_builder().schema());
+    for id in [1u64, 2] {
+        batch
+            .push(&[FieldValue::Uint64(id), FieldValue::Uint32(30), FieldValue::Float64(1.5), FieldValue::Uint8(0)])
+            .unwrap();
+    }
+    let batch = batch.finish();
+    let mut swapped = batch.clone();
+    swap_endianness(&mut swapped).unwrap();
+    let view = BinaryView::view(&swapped).unwrap();
+    assert_eq!(view.record(1).unwrap().field_bytes(1).unwrap(), 2u64.to_be_bytes());
+    swap_endianness(&mut swapped).unwrap();
+    assert_eq!(swapped, batch);
+}
+
+#[test]
+fn test_serializer_with_endianness() {
+    let foreign = match Endianness::native() {
+        Endianness::Little => Endianness::Big,
+        Endianness::Big => Endianness::Little,
+    };
+    let entries = [
+        OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Uint64 as u16, size: 8 },
+        OffsetEntry { field_id: 2, offset: 8, field_type: FieldType::Float32 as u16, size: 4 },
+        OffsetEntry { field_id: 3, offset: 12, field_type: FieldType::Uint8 as u16, size: 1 },
+        OffsetEntry { field_id: 10, offset: 0, field_type: FieldType::Blob as u16, size: 4 },
+    ];
+    let mut data = Vec::new();
+    data.extend_from_slice(bytemuck::bytes_of(&0x0102_0304_0506_0708u64));
+    data.extend_from_slice(bytemuck::bytes_of(&2.5f32));
+    data.push(7);
+
+    let mut serializer = BinarySerializer::new().with_endianness(foreign);
+    serializer.write_header(FormatHeader::new(48, 13, 4));
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&data);
+    serializer.write_var_data(&[1, 2, 3, 4]);
+    let mut buffer = serializer.finalize().unwrap();
+
+    // Stored in the foreign order, read back in host order; blobs untouched
+    let view = BinaryView::view_verified(&buffer).unwrap();
+    assert_eq!(view.endianness(), foreign);
+    let mut stored = 0x0102_0304_0506_0708u64.to_ne_bytes();
+    stored.reverse();
+    assert_eq!(view.field_raw_bytes(1).unwrap(), stored);
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 0x0102_0304_0506_0708);
+    assert_eq!(view.get_field_copy::<f32>(2).unwrap(), 2.5);
+    assert_eq!(*view.get_field_cow::<f32>(2).unwrap(), 2.5);
+    assert_eq!(*view.get_field::<u8>(3).unwrap(), 7);
+    assert!(matches!(view.get_field::<u64>(1), Err(SerializationError::ForeignByteOrder { field_id: 1 })));
+    assert_eq!(view.get_blob(10).unwrap(), &[1, 2, 3, 4]);
+    assert_eq!(view.record(0).unwrap().get_field_copy::<u64>(1).unwrap(), 0x0102_0304_0506_0708);
+
+    // Writes convert from host order too
+    BinaryViewMut::view_mut(&mut buffer).unwrap().modify_field(1, &42u64).unwrap();
+    let schema = Schema::new(entries.to_vec()).unwrap();
+    let editor = MutEditor::new(schema);
+    let mut bound = editor.bind(&mut buffer).unwrap();
+    assert_eq!(bound.get::<u64>(1).unwrap(), 42);
+    bound.set(2, &-1.0f32).unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 42);
+    assert_eq!(view.get_field_copy::<f32>(2).unwrap(), -1.0);
+    let mut stored = 42u64.to_ne_bytes();
+    stored.reverse();
+    assert_eq!(view.field_raw_bytes(1).unwrap(), stored);
+
+    // Array fields convert element by element
+    let mut builder = SchemaBuilder::new();
+    builder.add_array(1, &[1u32, 2]).unwrap();
+    let mut buffer = builder.build().unwrap();
+    swap_endianness(&mut buffer).unwrap();
+    let editor = MutEditor::new(builder.schema());
+    let mut bound = editor.bind(&mut buffer).unwrap();
+    assert_eq!(bound.get::<[u32; 2]>(1).unwrap(), [1, 2]);
+    bound.set(1, &[3u32, 4]).unwrap();
+    assert_eq!(BinaryView::view(&buffer).unwrap().get_array_copy::<u32>(1).unwrap(), [3, 4]);
+
+    // Without the option, bytes and header are written as given
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(FormatHeader::new(48, 13, 4));
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&data);
+    serializer.write_var_data(&[1, 2, 3, 4]);
+    let buffer = serializer.finish().unwrap().bytes;
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.endianness(), Endianness::Little);
+    assert_eq!(view.field_raw_bytes(2).unwrap(), bytemuck::bytes_of(&2.5f32));
+}
+
+#[test]
+fn test_whole_buffer_checksum() {
+    // Absent: nothing to verify
+    let mut buffer = create_test_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(!view.has_checksum());
+    view.verify().unwrap();
+
+    // Valid
+    let stored = BinaryViewMut::view_mut(&mut buffer).unwrap().store_checksum().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(view.has_checksum());
+    assert_eq!(view.compute_checksum(), stored);
+    view.verify().unwrap();
+
+    // Corrupted
+    BinaryViewMut::view_mut(&mut buffer).unwrap().modify_field(2, &31u32).unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(view.has_checksum());
+    assert!(matches!(
+        view.verify(),
+        Err(SerializationError::ChecksumMismatch { expected, .. }) if expected == stored
+    ));
+}
+
+#[test]
+fn test_finalize_and_view_verified() {
+    let entries = [OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Uint64 as u16, size: 8 }];
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(FormatHeader::new(12, 8, 0));
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&7u64.to_le_bytes());
+    let mut buffer = serializer.finalize().unwrap();
+
+    let view = BinaryView::view_verified(&buffer).unwrap();
+    assert!(view.has_checksum());
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 7);
+
+    // Unchecked buffers still pass, as with verify()
+    BinaryView::view_verified(&create_test_buffer()).unwrap();
+
+    let last = buffer.len() - 1;
+    buffer[last] ^= 1;
+    assert!(BinaryView::view(&buffer).is_ok());
+    assert!(BinaryView::view_unchecked(&buffer).is_ok());
+    assert!(matches!(
+        BinaryView::view_verified(&buffer),
+        Err(SerializationError::ChecksumMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_view_unchecked() {
+    let buffer = create_test_buffer();
+    let view = BinaryView::view_unchecked(&buffer).unwrap();
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 30);
+    assert_eq!(view.get_field_copy::<f64>(3).unwrap(), 95.5);
+
+    // Table placement isn't checked, but sizes still are
+    let mut misplaced = buffer.clone();
+    misplaced[80 + 4] = 200;
+    assert!(BinaryView::view(&misplaced).is_err());
+    assert!(BinaryView::view_unchecked(&misplaced).is_ok());
+    assert!(matches!(
+        BinaryView::view_unchecked(&buffer[..200]),
+        Err(SerializationError::BufferTooSmall { .. })
+    ));
+}
+
+#[test]
+fn test_batch_serializer() {
+    let mut builder = SchemaBuilder::new();
+    builder
+        .add_fixed(1, FieldType::Uint64, &0u64.to_le_bytes()).unwrap()
+        .add_fixed(2, FieldType::Bool, &[0]).unwrap()
+        .add_var_with_capacity(3, FieldType::String, b"", 16).unwrap();
+    let schema = builder.schema();
+    let single_size = builder.build().unwrap().len();
+
+    let mut batch = BatchSerializer::new(schema);
+    for (id, message) in [(1u64, "start"), (2, "tick"), (3, "stop")] {
+        batch
+            .push(&[FieldValue::Uint64(id), FieldValue::Bool(id % 2 == 1), FieldValue::String(message)])
+            .unwrap();
+    }
+    assert!(matches!(
+        batch.push(&[FieldValue::Uint32(4), FieldValue::Bool(true), FieldValue::String("")]),
+        Err(SerializationError::FieldTypeMismatch { field_id: 1, .. })
+    ));
+    assert_eq!(batch.len(), 3);
+
+    // Fixed values must match the entry's width, whatever the schema claims
+    let narrow = OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Uint64 as u16, size: 4 };
+    let mut mismatched = BatchSerializer::new(Schema::new(vec![narrow]).unwrap());
+    assert!(matches!(
+        mismatched.push(&[FieldValue::Uint64(1)]),
+        Err(SerializationError::FieldSizeMismatch { expected: 4, got: 8 })
+    ));
+    assert!(mismatched.is_empty());
+    let buffer = batch.finish();
+    assert!(buffer.len() < 3 * single_size);
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.record_count(), 3);
+    let record = view.record(1).unwrap();
+    assert_eq!(record.get_field_copy::<u64>(1).unwrap(), 2);
+    assert_eq!(record.get_field_copy::<u8>(2).unwrap(), 0);
+    assert_eq!(record.get_string(3).unwrap(), "tick");
+    assert_eq!(view.record(2).unwrap().get_string(3).unwrap(), "stop");
+    assert!(matches!(
+        view.record(3),
+        Err(SerializationError::RecordOutOfRange { index: 3, count: 3 })
+    ));
+
+    // A plain buffer is a single record
+    let buffer = create_test_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.record_count(), 1);
+    assert_eq!(view.record(0).unwrap().get_field_copy::<u32>(2).unwrap(), 30);
+}
+
+#[test]
+fn test_offset_table_undercount_rejected() {
+    let entries: Vec<OffsetEntry> = (0..5u32)
+        .map(|i| OffsetEntry {
+            field_id: i + 1,
+            offset: i * 4,
+            field_type: FieldType::Uint32 as u16,
+            size: 4,
+        })
+        .collect();
+
+    // Producer bug: header sized for four entries, five written
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(FormatHeader::new(4 * 12, 20, 0));
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&[0u8; 20]);
+    let buffer = serializer.into_buffer();
+    assert!(matches!(
+        BinaryView::view(&buffer),
+        Err(SerializationError::InconsistentHeader { offset_table_size: 48, entry_count: 5 })
+    ));
+
+    // A table size that isn't a whole number of entries
+    let mut buffer = create_test_buffer();
+    let mut header: FormatHeader = bytemuck::pod_read_unaligned(&buffer[..80]);
+    header.offset_table_size = 13;
+    buffer[..80].copy_from_slice(bytemuck::bytes_of(&header));
+    assert!(matches!(
+        BinaryView::view(&buffer),
+        Err(SerializationError::InconsistentHeader { .. })
+    ));
+}
+
+#[test]
+fn test_field_raw_bytes() {
+    let buffer = create_test_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+
+    let id = view.field_raw_bytes(1).unwrap();
+    assert_eq!(id.len(), 8);
+    if cfg!(target_endian = "little") {
+        assert_eq!(id, 12345u64.to_le_bytes());
+    }
+    assert_eq!(view.field_raw_bytes(4).unwrap(), &[1]);
+    assert!(matches!(view.field_raw_bytes(99), Err(SerializationError::FieldNotFound { field_id: 99 })));
+
+    let mut builder = user_schema_builder();
+    builder.add_var_with_capacity(5, FieldType::String, b"hi", 4).unwrap();
+    let buffer = builder.build().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.field_raw_bytes(5).unwrap(), b"hi\0\0");
+}
+
+#[test]
+fn test_view_with_external_schema() {
+    let schema = user_schema_builder().schema();
+    let full = user_schema_builder().build().unwrap();
+    let full_view = BinaryView::view(&full).unwrap();
+
+    let mut data = Vec::new();
+    for field_id in [1, 2, 3, 4] {
+        data.extend_from_slice(full_view.field_raw_bytes(field_id).unwrap());
+    }
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header_external(FormatHeader::new(48, data.len() as u32, 0));
+    serializer.write_data(&data);
+    let buffer = serializer.into_buffer();
+    assert_eq!(buffer.len(), full.len() - 4 * 12);
+
+    assert!(matches!(BinaryView::view(&buffer), Err(SerializationError::ExternalSchemaRequired)));
+    let mut copy = buffer.clone();
+    assert!(matches!(BinaryViewMut::view_mut(&mut copy), Err(SerializationError::ExternalSchemaRequired)));
+
+    let view = BinaryView::view_with_schema(&buffer, schema.entries()).unwrap();
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 12345);
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 30);
+    assert_eq!(view.get_field_copy::<f64>(3).unwrap(), 95.5);
+
+    // A schema that doesn't fit the header's data_size
+    let mut builder = user_schema_builder();
+    builder.add_fixed(5, FieldType::Uint64, &0u64.to_le_bytes()).unwrap();
+    let wider = builder.schema();
+    assert!(matches!(
+        BinaryView::view_with_schema(&buffer, wider.entries()),
+        Err(SerializationError::MisplacedField { field_id: 5 })
+    ));
+}
+
+#[test]
+fn test_schema_total_size() {
+    let builder = user_schema_builder();
+    // 80-byte header + 4 * 12-byte entries + 21 data bytes
+    assert_eq!(builder.schema().total_size(), 80 + 48 + 21);
+    assert_eq!(builder.total_size(), builder.build().unwrap().len());
+
+    let mut builder = user_schema_builder();
+    builder
+        .add_var_with_capacity(5, FieldType::String, b"hi", 32).unwrap()
+        .add_named(1, "id").unwrap();
+    let buffer = builder.build().unwrap();
+    assert_eq!(builder.total_size(), buffer.len());
+    // The name table lives outside the schema's sections
+    assert!(builder.schema().total_size() < buffer.len());
+}
+
+#[test]
+fn test_redact() {
+    let buffer = create_test_buffer();
+    let redacted = redact(&buffer, &[1]).unwrap();
+    assert_eq!(redacted.len(), buffer.len());
+
+    let view = BinaryView::view(&redacted).unwrap();
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 0);
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 30);
+    assert_eq!(view.get_field_copy::<f64>(3).unwrap(), 95.5);
+    assert_eq!(view.get_field_copy::<u8>(4).unwrap(), 1);
+
+    // Strings become empty with no trace of the old contents
+    let mut builder = user_schema_builder();
+    builder
+        .field_crcs(true)
+        .add_var(5, FieldType::String, b"alice@example.com").unwrap()
+        .add_var(6, FieldType::Blob, &[7; 4]).unwrap();
+    let mut buffer = builder.build().unwrap();
+    BinaryViewMut::view_mut(&mut buffer).unwrap().store_checksum().unwrap();
+    let redacted = redact(&buffer, &[5, 6]).unwrap();
+    let view = BinaryView::view(&redacted).unwrap();
+    assert_eq!(view.get_string(5).unwrap(), "");
+    assert_eq!(view.field_raw_bytes(5).unwrap(), &[0; 18]);
+    assert_eq!(view.get_blob(6).unwrap(), &[0; 4]);
+    view.verify().unwrap();
+    view.verify_field(5).unwrap();
+    view.verify_field(6).unwrap();
+
+    assert!(matches!(redact(&buffer, &[99]), Err(SerializationError::FieldNotFound { field_id: 99 })));
+}
+
+#[test]
+fn test_iter_records() {
+    let mut log = Vec::new();
+    for age in [30u32, 31, 32] {
+        let mut record = create_test_buffer();
+        BinaryViewMut::view_mut(&mut record).unwrap().modify_field(2, &age).unwrap();
+        log.extend_from_slice(&record);
+    }
+    let truncated = create_test_buffer();
+    log.extend_from_slice(&truncated[..truncated.len() - 10]);
+
+    let records: Vec<_> = iter_records(&log).collect();
+    assert_eq!(records.len(), 4);
+    for (record, age) in records[..3].iter().zip([30u32, 31, 32]) {
+        assert_eq!(record.as_ref().unwrap().get_field_copy::<u32>(2).unwrap(), age);
+    }
+    assert!(matches!(records[3], Err(SerializationError::BufferTooSmall { .. })));
+
+    assert_eq!(iter_records(&[]).count(), 0);
+}
+
+#[test]
+fn test_split() {
+    // Concatenated stream: each buffer comes back byte for byte
+    let mut log = Vec::new();
+    let mut originals = Vec::new();
+    for age in [30u32, 31] {
+        let mut record = create_test_buffer();
+        BinaryViewMut::view_mut(&mut record).unwrap().modify_field(2, &age).unwrap();
+        log.extend_from_slice(&record);
+        originals.push(record);
+    }
+    assert_eq!(split(&log).unwrap(), originals);
+
+    // Batch: each record gets its own header and offset table
+    let mut builder = SchemaBuilder::new();
+    builder
+        .add_fixed(1, FieldType::Uint64, &0u64.to_le_bytes()).unwrap()
+        .add_var_with_capacity(2, FieldType::String, b"", 16).unwrap();
+    let mut batch = BatchSerializer::new(builder.schema());
+    for (id, message) in [(1u64, "start"), (2, "tick"), (3, "stop")] {
+        batch.push(&[FieldValue::Uint64(id), FieldValue::String(message)]).unwrap();
+    }
+    let batch = batch.finish();
+
+    let records = split(&batch).unwrap();
+    assert_eq!(records.len(), 3);
+    for (record, (id, message)) in records.iter().zip([(1u64, "start"), (2, "tick"), (3, "stop")]) {
+        let view = BinaryView::view(record).unwrap();
+        assert_eq!(view.record_count(), 1);
+        assert_eq!(view.get_field_copy::<u64>(1).unwrap(), id);
+        assert_eq!(view.get_string(2).unwrap(), message);
+        assert_eq!(record.len(), builder.total_size());
+    }
+
+    // Records keep the batch's array element types
+    let mut builder = SchemaBuilder::new();
+    builder.add_array(1, &[0u32; 2]).unwrap();
+    let mut arrays = BatchSerializer::new(builder.schema());
+    arrays.push(&[FieldValue::Array(bytemuck::cast_slice(&[5u32, 6]))]).unwrap();
+    let records = split(&arrays.finish()).unwrap();
+    let view = BinaryView::view(&records[0]).unwrap();
+    assert_eq!(view.array_element_type(1), Some(FieldType::Uint32));
+    assert_eq!(view.get_array_copy::<u32>(1).unwrap(), [5, 6]);
+
+    // Mixed stream of a plain buffer followed by a batch
+    let mut mixed = create_test_buffer();
+    mixed.extend_from_slice(&batch);
+    assert_eq!(split(&mixed).unwrap().len(), 4);
+
+    log.truncate(log.len() - 10);
+    assert!(matches!(split(&log), Err(SerializationError::BufferTooSmall { .. })));
+}
+
+#[test]
+fn test_as_field_type() {
+    assert_eq!(<u64 as AsFieldType>::FIELD_TYPE, FieldType::Uint64);
+    assert_eq!(<u64 as AsFieldType>::SIZE, 8);
+    assert_eq!(<bool as AsFieldType>::FIELD_TYPE, FieldType::Bool);
+    assert_eq!(<bool as AsFieldType>::SIZE, 1);
+    assert_eq!(Some(<f32 as AsFieldType>::SIZE), FieldType::Float32.fixed_size());
+
+    let mut builder = SchemaBuilder::new();
+    builder.add::<u64>(1).add::<u32>(2).add::<f64>(3).add::<u8>(4);
+    user_schema_builder().schema().conforms_exact(&builder.build().unwrap()).unwrap();
+}
+
+#[test]
+fn test_crc32_implementations_agree() {
+    use bisere::checksum::{crc32, crc32_scalar, crc32_scalar_update, crc32_update};
+
+    let data: Vec<u8> = (0..(1u32 << 20) + 7).map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8).collect();
+    // Short inputs and odd offsets exercise the accelerated path's head and tail handling
+    for len in (0..300).chain([4096, 65_537, data.len()]) {
+        for start in [0, 1, 3] {
+            let bytes = &data[start..start + len.min(data.len() - start)];
+            assert_eq!(crc32(bytes), crc32_scalar(bytes), "len {} start {}", len, start);
+        }
+    }
+
+    let (head, tail) = data.split_at(12_345);
+    assert_eq!(crc32_update(crc32(head), tail), crc32_scalar_update(crc32_scalar(head), tail));
+    assert_eq!(crc32_update(crc32(head), tail), crc32(&data));
+}
+
+#[test]
+fn test_crc32c() {
+    use bisere::checksum::{crc32c, crc32c_scalar, crc32c_scalar_update, crc32c_update};
+
+    assert_eq!(crc32c(b"123456789"), 0xE306_9283);
+    assert_eq!(crc32c_scalar(b"123456789"), 0xE306_9283);
+    let data: Vec<u8> = (0..70_000u32).map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8).collect();
+    for len in (0..40).chain([4096, data.len() - 3]) {
+        for start in [0, 1, 3] {
+            let bytes = &data[start..start + len];
+            assert_eq!(crc32c(bytes), crc32c_scalar(bytes), "len {} start {}", len, start);
+        }
+    }
+    let (head, tail) = data.split_at(1_234);
+    assert_eq!(crc32c_update(crc32c(head), tail), crc32c_scalar_update(crc32c_scalar(head), tail));
+    assert_eq!(crc32c_update(crc32c(head), tail), crc32c(&data));
+
+    let mut serializer = BinarySerializer::new().with_checksum(ChecksumAlgo::Crc32c);
+    serializer.write_header(FormatHeader::new(std::mem::size_of::<OffsetEntry>() as u32, 4, 0));
+    serializer.write_offset_table(&[OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Uint32 as u16, size: 4 }]).unwrap();
+    serializer.write_data(&7u32.to_le_bytes());
+    let finished = serializer.finish().unwrap();
+    let stored = finished.checksum.unwrap();
+    assert!(stored <= u32::MAX as u64);
+
+    let mut buffer = finished.bytes;
+    let view = BinaryView::view_verified(&buffer).unwrap();
+    assert_eq!(view.checksum_info(), Some((ChecksumAlgo::Crc32c, stored)));
+    BinaryViewMut::view_mut(&mut buffer).unwrap().modify_field(1, &8u32).unwrap();
+    assert!(matches!(
+        BinaryView::view_verified(&buffer),
+        Err(SerializationError::ChecksumMismatch { .. })
+    ));
+
+    // Switching algorithms leaves only the new one recorded
+    let mut view = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    view.store_checksum_with(ChecksumAlgo::Crc32).unwrap();
+    let view = BinaryView::view_verified(&buffer).unwrap();
+    assert_eq!(view.checksum_info().map(|(algo, _)| algo), Some(ChecksumAlgo::Crc32));
+}
+
+#[test]
+fn test_per_field_crcs() {
+    assert_eq!(bisere::checksum::crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(bisere::checksum::crc32_scalar(b"123456789"), 0xCBF4_3926);
+
+    let mut builder = user_schema_builder();
+    builder
+        .add_var(5, FieldType::String, b"Alice").unwrap()
+        .field_crcs(true);
+    let mut buffer = builder.build().unwrap();
+    assert_eq!(builder.total_size(), buffer.len());
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(view.has_field_crcs());
+    for field_id in [1, 2, 3, 4, 5] {
+        view.verify_field(field_id).unwrap();
+    }
+
+    // Flip a byte of field 2 behind the views' backs
+    let age_offset = BinaryView::view(&buffer).unwrap().field_offset(2).unwrap();
+    buffer[age_offset] ^= 0xff;
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(matches!(view.verify_field(2), Err(SerializationError::ChecksumMismatch { .. })));
+    for field_id in [1, 3, 4, 5] {
+        view.verify_field(field_id).unwrap();
+    }
+
+    let mut view = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    view.modify_field(2, &40u32).unwrap();
+    view.update_field_crc(2).unwrap();
+    BinaryView::view(&buffer).unwrap().verify_field(2).unwrap();
+
+    // No table: nothing to check
+    let buffer = create_test_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(!view.has_field_crcs());
+    view.verify_field(1).unwrap();
+}
+
+#[test]
+fn test_string_lengths() {
+    let long = "x".repeat(1000);
+    let mut builder = SchemaBuilder::new();
+    builder
+        .add_var(1, FieldType::String, long.as_bytes()).unwrap()
+        .add_var_with_capacity(2, FieldType::String, b"a\0b", 16).unwrap()
+        .string_lengths(true);
+    let mut buffer = builder.build().unwrap();
+    assert_eq!(builder.total_size(), buffer.len());
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(view.has_string_lengths());
+    assert_eq!(view.get_string(1).unwrap(), long);
+    assert_eq!(view.get_string(2).unwrap(), "a\0b");
+    assert_eq!(ValidatedView::new(&buffer).unwrap().get_string(2).unwrap(), "a\0b");
+
+    // The stored length is authoritative: clobbering the terminator doesn't
+    // make the read run on
+    let terminator = view.field_offset(1).unwrap() + 1000;
+    buffer[terminator] = b'y';
+    assert_eq!(BinaryView::view(&buffer).unwrap().get_string(1).unwrap().len(), 1000);
+
+    let mut view = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    view.modify_string(2, "c\0\0d").unwrap();
+    assert_eq!(BinaryView::view(&buffer).unwrap().get_string(2).unwrap(), "c\0\0d");
+    BinaryViewMut::view_mut(&mut buffer).unwrap().zero_field(2).unwrap();
+    assert_eq!(BinaryView::view(&buffer).unwrap().get_string(2).unwrap(), "");
+
+    // Rebuilding keeps the table
+    BinaryViewMut::view_mut(&mut buffer).unwrap().modify_string(2, "e\0f").unwrap();
+    let rebuilt = SchemaBuilder::from_view(&BinaryView::view(&buffer).unwrap()).unwrap().build().unwrap();
+    assert_eq!(BinaryView::view(&rebuilt).unwrap().get_string(2).unwrap(), "e\0f");
+
+    // Without the table an embedded NUL ends the string
+    let mut builder = SchemaBuilder::new();
+    builder.add_var(2, FieldType::String, b"a\0b").unwrap();
+    let buffer = builder.build().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(!view.has_string_lengths());
+    assert_eq!(view.get_string(2).unwrap(), "a");
+}
+
+#[test]
+fn test_string_lengths_exact_fill() {
+    let mut builder = SchemaBuilder::new();
+    builder
+        .string_lengths(true)
+        .add_string_field(1, 5, "exact").unwrap()
+        .add_string_field(2, 4, "").unwrap()
+        .add_string_field(3, 3, "a\0b").unwrap();
+    let mut buffer = builder.build().unwrap();
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_string(1).unwrap(), "exact");
+    assert_eq!(view.get_string(2).unwrap(), "");
+    assert_eq!(view.get_string(3).unwrap(), "a\0b");
+
+    let mut view = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    view.modify_string(1, "\0\0\0\0\0").unwrap();
+    view.modify_string(2, "full").unwrap();
+    assert!(matches!(
+        view.modify_string(3, "four"),
+        Err(SerializationError::FieldSizeMismatch { expected: 3, got: 4 })
+    ));
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_string(1).unwrap(), "\0\0\0\0\0");
+    assert_eq!(view.get_string(2).unwrap(), "full");
+
+    // Without recorded lengths a terminator is still required
+    let mut builder = SchemaBuilder::new();
+    assert!(matches!(
+        builder.add_string_field(1, 5, "exact"),
+        Err(SerializationError::FieldSizeMismatch { expected: 5, got: 6 })
+    ));
+    builder.add_string_field(1, 6, "exact").unwrap();
+    let mut buffer = builder.build().unwrap();
+    assert!(matches!(
+        BinaryViewMut::view_mut(&mut buffer).unwrap().modify_string(1, "exact!"),
+        Err(SerializationError::FieldSizeMismatch { expected: 6, got: 7 })
+    ));
+}
+
+#[test]
+fn test_resize_var_field() {
+    let mut builder = user_schema_builder();
+    builder
+        .add_var_with_capacity(5, FieldType::String, b"first", 16).unwrap()
+        .add_var_with_capacity(6, FieldType::String, b"middle", 16).unwrap()
+        .add_var(7, FieldType::Blob, &[1, 2, 3]).unwrap()
+        .add_named(7, "payload").unwrap()
+        .field_crcs(true);
+    let buffer = builder.build().unwrap();
+    let var_size = |buffer: &[u8]| {
+        let header: FormatHeader = bytemuck::pod_read_unaligned(&buffer[..80]);
+        header.var_size
+    };
+
+    for new_size in [7u16, 40] {
+        let resized = resize_var_field(&buffer, 6, new_size).unwrap();
+        assert_eq!(resized.len() as i64 - buffer.len() as i64, new_size as i64 - 16);
+        assert_eq!(var_size(&resized) as i64 - var_size(&buffer) as i64, new_size as i64 - 16);
+
+        let view = BinaryView::view(&resized).unwrap();
+        assert_eq!(view.find_entry(6).map(|e| e.size), Some(new_size));
+        assert_eq!(view.get_string(5).unwrap(), "first");
+        assert_eq!(view.get_string(6).unwrap(), "middle");
+        assert_eq!(view.get_blob(7).unwrap(), &[1, 2, 3]);
+        assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 30);
+        assert_eq!(view.field_name(7), Some("payload"));
+        for field_id in [5, 6, 7] {
+            view.verify_field(field_id).unwrap();
+        }
+    }
+
+    // "middle" plus its terminator needs 7 bytes
+    assert!(matches!(
+        resize_var_field(&buffer, 6, 6),
+        Err(SerializationError::FieldSizeMismatch { expected: 7, got: 6 })
+    ));
+    assert!(matches!(
+        resize_var_field(&buffer, 2, 8),
+        Err(SerializationError::FieldSizeMismatch { .. })
+    ));
+
+    let mut builder = SchemaBuilder::new();
+    builder.add_var_with_capacity(1, FieldType::String, b"", 4).unwrap();
+    let mut batch = BatchSerializer::new(builder.schema());
+    batch.push(&[FieldValue::String("one")]).unwrap();
+    assert!(matches!(resize_var_field(&batch.finish(), 1, 8), Err(SerializationError::BatchBuffer)));
+}
+
+#[test]
+fn test_owned_document_grows_var_fields() {
+    let mut builder = user_schema_builder();
+    builder
+        .add_var_with_capacity(5, FieldType::String, b"first", 8).unwrap()
+        .add_var_with_capacity(6, FieldType::String, b"middle", 8).unwrap()
+        .add_var(7, FieldType::Blob, &[1, 2, 3]).unwrap()
+        .add_named(7, "payload").unwrap()
+        .field_crcs(true);
+    let buffer = builder.build().unwrap();
+    let data_section = |buffer: &[u8]| {
+        let header: FormatHeader = bytemuck::pod_read_unaligned(&buffer[..80]);
+        buffer[header.data_section_offset()..header.var_section_offset()].to_vec()
+    };
+    let mut document = OwnedDocument::new(buffer.clone()).unwrap();
+
+    // Fits: written in place, nothing moves
+    document.set_string(6, "short").unwrap();
+    assert_eq!(document.as_bytes().len(), buffer.len());
+
+    // Growing a middle field shifts the fields behind it
+    document.set_string(5, "a considerably longer first value").unwrap();
+    // Growing the last var field only moves the extension area
+    document.set_blob(7, &[9; 20]).unwrap();
+
+    let bytes = document.as_bytes();
+    assert_eq!(bytes.len(), buffer.len() + (34 - 8) + (20 - 3));
+    let view = document.view().unwrap();
+    assert_eq!(view.get_string(5).unwrap(), "a considerably longer first value");
+    assert_eq!(view.get_string(6).unwrap(), "short");
+    assert_eq!(view.get_blob(7).unwrap(), &[9; 20]);
+    assert_eq!(view.find_entry(5).map(|e| e.size), Some(34));
+    assert_eq!(view.find_entry(6).map(|e| e.offset), Some(34));
+    assert_eq!(view.find_entry(7).map(|e| (e.offset, e.size)), Some((42, 20)));
+    assert_eq!(data_section(bytes), data_section(&buffer));
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 30);
+    assert_eq!(view.field_name(7), Some("payload"));
+    for field_id in [5, 6, 7] {
+        view.verify_field(field_id).unwrap();
+    }
+
+    assert!(matches!(document.set_string(7, "text"), Err(SerializationError::FieldSizeMismatch { .. })));
+    assert!(matches!(
+        document.set_string(5, &"x".repeat(70_000)),
+        Err(SerializationError::FieldTooLarge { field_id: 5, requested: 70_001 })
+    ));
+
+    // Batch records share one layout, so their fields can't grow
+    let mut builder = SchemaBuilder::new();
+    builder.add_var_with_capacity(1, FieldType::String, b"", 4).unwrap();
+    let mut batch = BatchSerializer::new(builder.schema());
+    batch.push(&[FieldValue::String("one")]).unwrap();
+    batch.push(&[FieldValue::String("two")]).unwrap();
+    let mut document = OwnedDocument::new(batch.finish()).unwrap();
+    document.set_string(1, "new").unwrap();
+    assert!(matches!(document.set_string(1, "longer"), Err(SerializationError::BatchBuffer)));
+    assert!(matches!(document.append_fixed_field(2, FieldType::Bool, &[1]), Err(SerializationError::BatchBuffer)));
+    assert_eq!(document.view().unwrap().record(1).unwrap().get_string(1).unwrap(), "two");
+}
+
+#[test]
+fn test_schema_builder_from_view() {
+    let buffer = create_test_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+
+    let mut builder = SchemaBuilder::from_view(&view).unwrap();
+    builder.add_var(5, FieldType::String, b"Alice").unwrap();
+    let migrated = builder.build().unwrap();
+
+    let view = BinaryView::view(&migrated).unwrap();
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 12345);
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 30);
+    assert_eq!(view.get_field_copy::<f64>(3).unwrap(), 95.5);
+    assert_eq!(view.get_field_copy::<u8>(4).unwrap(), 1);
+    assert_eq!(view.get_string(5).unwrap(), "Alice");
+
+    let mut builder = SchemaBuilder::from_view(&view).unwrap();
+    builder.remove_field(3).unwrap();
+    assert!(matches!(builder.remove_field(3), Err(SerializationError::FieldNotFound { field_id: 3 })));
+    let trimmed = builder.build().unwrap();
+    let view = BinaryView::view(&trimmed).unwrap();
+    assert!(view.find_entry(3).is_none());
+    assert_eq!(view.get_string(5).unwrap(), "Alice");
+
+    // Values of a foreign-order buffer are rebuilt in native order
+    let mut builder = SchemaBuilder::new();
+    builder
+        .add_fixed(1, FieldType::Uint32, &0x01020304u32.to_ne_bytes()).unwrap()
+        .add_array(2, &[0x0506u16, 0x0708]).unwrap();
+    let mut buffer = builder.build().unwrap();
+    swap_endianness(&mut buffer).unwrap();
+    let rebuilt = SchemaBuilder::from_view(&BinaryView::view(&buffer).unwrap()).unwrap().build().unwrap();
+    let view = BinaryView::view(&rebuilt).unwrap();
+    assert_eq!(view.field_raw_bytes(1).unwrap(), 0x01020304u32.to_ne_bytes());
+    assert_eq!(view.get_field_copy::<u32>(1).unwrap(), 0x01020304);
+    assert_eq!(view.get_array_copy::<u16>(2).unwrap(), [0x0506, 0x0708]);
+}
+
+#[test]
+fn test_modify_string_truncating() {
+    let mut builder = user_schema_builder();
+    builder
+        .add_var_with_capacity(5, FieldType::String, b"", 5).unwrap()
+        .add_var(6, FieldType::Blob, &[0; 8]).unwrap();
+    let mut buffer = builder.build().unwrap();
+    let mut view = BinaryViewMut::view_mut(&mut buffer).unwrap();
+
+    // Four bytes of room: one whole 3-byte character, not half of the second
+    assert_eq!(view.modify_string_truncating(5, "世界").unwrap(), 3);
+    assert_eq!(BinaryView::view(&buffer).unwrap().get_string(5).unwrap(), "世");
+
+    let mut view = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    assert_eq!(view.modify_string_truncating(5, "hello world").unwrap(), 4);
+    assert_eq!(view.modify_string_truncating(5, "ok").unwrap(), 2);
+    assert!(view.modify_string_truncating(6, "blob").is_err());
+    assert_eq!(BinaryView::view(&buffer).unwrap().get_string(5).unwrap(), "ok");
+
+    // With recorded lengths there is no terminator, so the whole field is used
+    builder.string_lengths(true);
+    let mut buffer = builder.build().unwrap();
+    let mut view = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    assert_eq!(view.modify_string_truncating(5, "hello world").unwrap(), 5);
+    assert_eq!(BinaryView::view(&buffer).unwrap().get_string(5).unwrap(), "hello");
+}
+
+#[test]
+fn test_peek_header_ignores_version() {
+    let mut buffer = create_test_buffer();
+    buffer[4..8].copy_from_slice(&999u32.to_le_bytes());
+    assert!(matches!(
+        BinaryView::view(&buffer),
+        Err(SerializationError::UnsupportedVersion { version: 999 })
+    ));
+
+    let info = peek_header(&buffer).unwrap();
+    assert_eq!(info.version, 999);
+    assert!(!info.is_supported());
+    assert_eq!(info.header_size, 80);
+    assert_eq!(info.offset_table_size, 48);
+    assert_eq!(info.var_size, 256);
+
+    buffer[0] ^= 0xff;
+    assert!(matches!(peek_header(&buffer), Err(SerializationError::InvalidMagic { .. })));
+    assert!(matches!(peek_header(&buffer[..10]), Err(SerializationError::BufferTooSmall { .. })));
+}
+
+#[test]
+fn test_gaps() {
+    let mut builder = user_schema_builder();
+    builder
+        .add_var_with_capacity(5, FieldType::String, b"abc", 10).unwrap()
+        .add_var(6, FieldType::Blob, &[1, 2]).unwrap();
+    let buffer = builder.build().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+
+    // Field 5 uses 4 of its 10 bytes, leaving a gap before field 6
+    let var_start = view.field_offset(5).unwrap();
+    assert_eq!(view.gaps(), vec![var_start + 4..var_start + 10]);
+
+    // The hand-built buffer reserves 256 var bytes with no var fields
+    let buffer = create_test_buffer();
+    let view = BinaryView::view(&buffer).unwrap();
+    let var_start = 80 + 48 + 21;
+    assert_eq!(view.gaps(), vec![var_start..var_start + 256]);
+}
+
+#[test]
+fn test_validated_view() {
+    let mut builder = user_schema_builder();
+    builder
+        .add_var(5, FieldType::String, b"Alice").unwrap()
+        .add_var(6, FieldType::Blob, &[1, 2, 3]).unwrap();
+    let buffer = builder.build().unwrap();
+
+    let view = ValidatedView::new(&buffer).unwrap();
+    assert_eq!(view.len(), 6);
+    assert_eq!(view.get::<u64>(1).unwrap(), 12345);
+    assert_eq!(view.get::<f64>(3).unwrap(), 95.5);
+    assert_eq!(view.get_string(5).unwrap(), "Alice");
+    assert_eq!(view.get_blob(6).unwrap(), &[1, 2, 3]);
+    assert_eq!(view.field_raw_bytes(2).unwrap(), view.view().field_raw_bytes(2).unwrap());
+    let mut swapped = buffer.clone();
+    swap_endianness(&mut swapped).unwrap();
+    assert_eq!(ValidatedView::new(&swapped).unwrap().get::<u32>(2).unwrap(), 30);
+    assert!(matches!(
+        view.get::<u32>(1),
+        Err(SerializationError::FieldTypeMismatch { field_id: 1, .. })
+    ));
+    assert!(matches!(view.get_string(6), Err(SerializationError::FieldTypeMismatch { .. })));
+    assert!(matches!(view.get_blob(99), Err(SerializationError::FieldNotFound { field_id: 99 })));
+
+    // Overlapping fixed fields are rejected up front
+    let entries = [
+        OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Uint64 as u16, size: 8 },
+        OffsetEntry { field_id: 2, offset: 4, field_type: FieldType::Uint32 as u16, size: 4 },
+    ];
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(FormatHeader::new(24, 8, 0));
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&[0; 8]);
+    let buffer = serializer.into_buffer();
+    BinaryView::view(&buffer).unwrap();
+    assert!(matches!(
+        ValidatedView::new(&buffer),
+        Err(SerializationError::OverlappingFields { first: 1, second: 2 })
+    ));
+}
+
+#[test]
+fn test_validated_iter() {
+    let mut builder = user_schema_builder();
+    builder
+        .add_var(5, FieldType::String, b"Alice").unwrap()
+        .add_var(6, FieldType::Blob, &[1, 2, 3]).unwrap();
+    let buffer = builder.build().unwrap();
+
+    let fields: Vec<_> = BinaryView::view(&buffer).unwrap()
+        .into_validated_iter()
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(fields, vec![
+        (1, FieldValue::Uint64(12345)),
+        (2, FieldValue::Uint32(30)),
+        (3, FieldValue::Float64(95.5)),
+        (4, FieldValue::Uint8(1)),
+        (5, FieldValue::String("Alice")),
+        (6, FieldValue::Blob(&[1, 2, 3])),
+    ]);
+
+    // An unknown type between two good fields
+    let entries = [
+        OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Uint32 as u16, size: 4 },
+        OffsetEntry { field_id: 2, offset: 4, field_type: 999, size: 4 },
+        OffsetEntry { field_id: 3, offset: 8, field_type: FieldType::Uint32 as u16, size: 4 },
+    ];
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(FormatHeader::new(36, 12, 0));
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(&[7, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0]);
+    let buffer = serializer.into_buffer();
+
+    let results: Vec<_> = BinaryView::view(&buffer).unwrap().into_validated_iter().collect();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap(), &(1, FieldValue::Uint32(7)));
+    assert!(matches!(results[1], Err(SerializationError::UnknownFieldType { value: 999 })));
+    assert_eq!(results[2].as_ref().unwrap(), &(3, FieldValue::Uint32(9)));
+
+    let results: Vec<_> = BinaryView::view(&buffer).unwrap()
+        .into_validated_iter()
+        .stop_on_error(true)
+        .collect();
+    assert_eq!(results.len(), 2);
+    assert!(results[1].is_err());
+}
+
+#[test]
+fn test_enum_fields() {
+    let statuses = ["active", "inactive", "banned"];
+    let mut builder = user_schema_builder();
+    builder.add_enum(5, &statuses, "inactive").unwrap();
+    assert!(matches!(
+        SchemaBuilder::new().add_enum(1, &statuses, "deleted"),
+        Err(SerializationError::UnknownEnumValue { field_id: 1 })
+    ));
+    let mut buffer = builder.build().unwrap();
+    assert_eq!(builder.total_size(), buffer.len());
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_enum(5).unwrap(), "inactive");
+    assert_eq!(view.get_field_copy::<u16>(5).unwrap(), 1);
+    assert_eq!(view.enum_dictionary(5).unwrap(), statuses);
+    assert!(view.get_enum(1).is_err());
+
+    let mut view = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    view.modify_enum(5, "banned").unwrap();
+    assert!(matches!(
+        view.modify_enum(5, "deleted"),
+        Err(SerializationError::UnknownEnumValue { field_id: 5 })
+    ));
+    assert_eq!(BinaryView::view(&buffer).unwrap().get_enum(5).unwrap(), "banned");
+
+    // Dictionaries survive a rebuild
+    let rebuilt = SchemaBuilder::from_view(&BinaryView::view(&buffer).unwrap()).unwrap().build().unwrap();
+    assert_eq!(BinaryView::view(&rebuilt).unwrap().get_enum(5).unwrap(), "banned");
+}
+
+#[test]
+fn test_bool_fields() {
+    let mut builder = user_schema_builder();
+    builder
+        .add_fixed(5, FieldType::Bool, &[1]).unwrap()
+        .add_fixed(6, FieldType::Bool, &[0x7f]).unwrap();
+    let mut buffer = builder.build().unwrap();
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(view.get_bool(5).unwrap());
+    assert!(view.get_bool(6).unwrap(), "any nonzero byte is true");
+    // Field 4 is a `Uint8` holding 1, not a `Bool`
+    assert!(matches!(
+        view.get_bool(4),
+        Err(SerializationError::FieldSizeMismatch { expected: 11, got: 5 })
+    ));
+    assert!(matches!(view.get_bool(9), Err(SerializationError::FieldNotFound { field_id: 9 })));
+
+    let mut view = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    view.set_bool(5, false).unwrap();
+    view.set_bool(6, true).unwrap();
+    assert!(matches!(view.set_bool(4, true), Err(SerializationError::FieldSizeMismatch { .. })));
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(!view.get_bool(5).unwrap());
+    assert_eq!(view.get_field_copy::<u8>(6).unwrap(), 1);
+    assert_eq!(view.get_field_copy::<u8>(4).unwrap(), 1);
+}
+
+#[test]
+fn test_array_fields() {
+    let position = [1.5f32, -2.0, 3.25];
+    let mut builder = user_schema_builder();
+    builder
+        .add_array(5, &position).unwrap()
+        .add_array(6, &[7u32, 8, 9]).unwrap()
+        .add_var(7, FieldType::Blob, &[1, 2]).unwrap()
+        .align_fields(true);
+    let buffer = builder.build().unwrap();
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.field_type(5).unwrap(), FieldType::Array);
+    assert_eq!(view.find_entry(5).map(|e| e.size), Some(12));
+    assert_eq!(view.array_element_type(5), Some(FieldType::Float32));
+    assert_eq!(view.array_element_type(6), Some(FieldType::Uint32));
+    assert_eq!(view.array_element_type(1), None);
+    assert_eq!(view.get_array_copy::<f32>(5).unwrap(), position);
+    assert_eq!(view.get_array_copy::<u32>(6).unwrap(), [7, 8, 9]);
+    assert!(view.field_offset(6).unwrap().is_multiple_of(4));
+    if (buffer.as_ptr() as usize).is_multiple_of(4) {
+        assert_eq!(view.get_array::<f32>(5).unwrap(), position);
+    }
+
+    // The recorded element type must match
+    assert!(matches!(
+        view.get_array_copy::<u32>(5),
+        Err(SerializationError::FieldTypeMismatch { field_id: 5, expected: 7, found: 9 })
+    ));
+    assert!(matches!(view.get_array_copy::<u64>(1), Err(SerializationError::FieldSizeMismatch { .. })));
+    assert!(matches!(view.get_array_copy::<f32>(99), Err(SerializationError::FieldNotFound { field_id: 99 })));
+
+    let mut iter = view.into_validated_iter();
+    let (_, value) = iter.nth(4).unwrap().unwrap();
+    assert_eq!(value, FieldValue::Array(bytemuck::cast_slice(&position)));
+
+    // Elements are swapped one by one, and the table survives a rebuild
+    let mut swapped = buffer.clone();
+    swap_endianness(&mut swapped).unwrap();
+    let view = BinaryView::view(&swapped).unwrap();
+    assert_eq!(view.get_array_copy::<f32>(5).unwrap(), position);
+    assert!(matches!(view.get_array::<f32>(5), Err(SerializationError::ForeignByteOrder { field_id: 5 })));
+    assert_eq!(view.get_field_copy::<[u32; 3]>(6).unwrap(), [7, 8, 9]);
+    let delta = make_delta(&buffer, &swapped).unwrap();
+    assert_eq!(BinaryView::view(&delta).unwrap().field_count(), 0);
+    BinaryViewMut::view_mut(&mut swapped).unwrap().modify_field(6, &[10u32, 20, 30]).unwrap();
+    assert_eq!(BinaryView::view(&swapped).unwrap().get_array_copy::<u32>(6).unwrap(), [10, 20, 30]);
+    let extracted = BinaryView::view(&swapped).unwrap().extract_field(6).unwrap();
+    assert_eq!(BinaryView::view(&extracted).unwrap().get_array_copy::<u32>(6).unwrap(), [10, 20, 30]);
+
+    // Deltas and remapping keep the element types
+    let mut changed = buffer.clone();
+    BinaryViewMut::view_mut(&mut changed).unwrap().modify_field(6, &[1u32, 2, 3]).unwrap();
+    let applied = apply_delta(&buffer, &make_delta(&buffer, &changed).unwrap()).unwrap();
+    let view = BinaryView::view(&applied).unwrap();
+    assert_eq!(view.get_array_copy::<u32>(6).unwrap(), [1, 2, 3]);
+    assert_eq!(view.get_array_copy::<f32>(5).unwrap(), position);
+    let target = Schema::new(vec![OffsetEntry { field_id: 60, offset: 0, field_type: FieldType::Array as u16, size: 12 }]).unwrap();
+    let remapped = remap(&buffer, &[(6, 60)], &target).unwrap();
+    assert_eq!(BinaryView::view(&remapped).unwrap().get_array_copy::<u32>(60).unwrap(), [7, 8, 9]);
+
+    let rebuilt = SchemaBuilder::from_view(&BinaryView::view(&buffer).unwrap()).unwrap().build().unwrap();
+    assert_eq!(BinaryView::view(&rebuilt).unwrap().get_array_copy::<u32>(6).unwrap(), [7, 8, 9]);
+
+    // Batches take arrays of exactly the declared length and record their element type
+    let mut builder = SchemaBuilder::new();
+    builder.add_array(1, &[0u32; 2]).unwrap();
+    let mut batch = BatchSerializer::new(builder.schema());
+    let words = |values: &[u32]| bytemuck::cast_slice::<u32, u8>(values).to_vec();
+    for values in [[1u32, 2, 3].as_slice(), &[1]] {
+        assert!(matches!(
+            batch.push(&[FieldValue::Array(&words(values))]),
+            Err(SerializationError::FieldSizeMismatch { expected: 8, .. })
+        ));
+    }
+    batch.push(&[FieldValue::Array(&words(&[5, 6]))]).unwrap();
+    let mut buffer = batch.finish();
+    swap_endianness(&mut buffer).unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.array_element_type(1), Some(FieldType::Uint32));
+    assert_eq!(view.record(0).unwrap().get_field_copy::<[u32; 2]>(1).unwrap(), [5, 6]);
+}
+
+#[test]
+fn test_upgrade() {
+    // A version 1 buffer from a producer that predates the entry-count header slot
+    let entries = user_schema_builder().schema().entries().to_vec();
+    let mut buffer = bytemuck::bytes_of(&FormatHeader::new(48, 21, 0)).to_vec();
+    buffer.extend_from_slice(bytemuck::cast_slice(&entries));
+    buffer.extend_from_slice(&12345u64.to_le_bytes());
+    buffer.extend_from_slice(&30u32.to_le_bytes());
+    buffer.extend_from_slice(&95.5f64.to_le_bytes());
+    buffer.push(1);
+    assert_eq!(bytemuck::pod_read_unaligned::<FormatHeader>(&buffer[..80]).entry_count(), None);
+
+    let upgraded = upgrade(&buffer).unwrap();
+    assert_eq!(upgraded.len(), buffer.len());
+    assert_eq!(upgraded[80..], buffer[80..]);
+    assert_eq!(bytemuck::pod_read_unaligned::<FormatHeader>(&upgraded[..80]).entry_count(), Some(4));
+
+    let view = BinaryView::view(&upgraded).unwrap();
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 12345);
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 30);
+    assert_eq!(view.get_field_copy::<f64>(3).unwrap(), 95.5);
+    assert_eq!(view.get_field_copy::<u8>(4).unwrap(), 1);
+
+    buffer[4..8].copy_from_slice(&999u32.to_le_bytes());
+    assert!(matches!(upgrade(&buffer), Err(SerializationError::UnsupportedVersion { version: 999 })));
+}
+
+#[test]
+fn test_is_field_zero() {
+    let mut builder = user_schema_builder();
+    builder.add_var(5, FieldType::Blob, &[7, 7]).unwrap();
+    let mut buffer = builder.build().unwrap();
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(!view.is_field_zero(1).unwrap());
+    assert!(!view.is_field_zero(5).unwrap());
+
+    BinaryViewMut::view_mut(&mut buffer).unwrap().zero_field(5).unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(view.is_field_zero(5).unwrap());
+    assert!(!view.is_field_zero(2).unwrap());
+    assert!(matches!(view.is_field_zero(99), Err(SerializationError::FieldNotFound { field_id: 99 })));
+}
+
+#[test]
+fn test_build_from_tuples() {
+    let buffer = build(&[
+        (1, FieldType::Uint64, 12345u64.to_le_bytes().to_vec()),
+        (2, FieldType::Uint32, 30u32.to_le_bytes().to_vec()),
+        (3, FieldType::Float64, 95.5f64.to_le_bytes().to_vec()),
+        (4, FieldType::Uint8, vec![1]),
+        (5, FieldType::String, b"Alice".to_vec()),
+    ])
+    .unwrap();
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 12345);
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 30);
+    assert_eq!(view.get_field_copy::<f64>(3).unwrap(), 95.5);
+    assert_eq!(view.get_field_copy::<u8>(4).unwrap(), 1);
+    assert_eq!(view.get_string(5).unwrap(), "Alice");
+
+    assert!(matches!(
+        build(&[(1, FieldType::Uint64, vec![0; 4])]),
+        Err(SerializationError::FieldSizeMismatch { expected: 8, got: 4 })
+    ));
+    assert!(matches!(
+        build(&[(1, FieldType::Uint8, vec![0]), (1, FieldType::Uint8, vec![1])]),
+        Err(SerializationError::DuplicateFieldId { field_id: 1 })
+    ));
+}
+
+#[test]
+fn test_checksum_info() {
+    let mut buffer = create_test_buffer();
+    assert_eq!(BinaryView::view(&buffer).unwrap().checksum_info(), None);
+
+    let stored = BinaryViewMut::view_mut(&mut buffer)
+        .unwrap()
+        .store_checksum_with(ChecksumAlgo::Crc32)
+        .unwrap();
+    assert!(stored <= u32::MAX as u64);
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.checksum_info(), Some((ChecksumAlgo::Crc32, stored)));
+    view.verify().unwrap();
+
+    // Re-storing keeps the recorded algorithm
+    BinaryViewMut::view_mut(&mut buffer).unwrap().modify_field(2, &31u32).unwrap();
+    assert!(BinaryView::view(&buffer).unwrap().verify().is_err());
+    BinaryViewMut::view_mut(&mut buffer).unwrap().store_checksum().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.checksum_info().map(|(algo, _)| algo), Some(ChecksumAlgo::Crc32));
+    view.verify().unwrap();
+
+    BinaryViewMut::view_mut(&mut buffer).unwrap().store_checksum_with(ChecksumAlgo::Fnv1a64).unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.checksum_info().map(|(algo, _)| algo), Some(ChecksumAlgo::Fnv1a64));
+    view.verify().unwrap();
+}
+
+#[test]
+fn test_write_offset_table_rejects_duplicate_ids() {
+    let entry = |field_id: u32, offset: u32| OffsetEntry { field_id, offset, field_type: FieldType::Uint8 as u16, size: 1 };
+
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(FormatHeader::new(2 * std::mem::size_of::<OffsetEntry>() as u32, 2, 0));
+    assert!(matches!(
+        serializer.write_offset_table(&[entry(3, 0), entry(3, 1)]),
+        Err(SerializationError::DuplicateFieldId { field_id: 3 })
+    ));
+    assert_eq!(serializer.into_buffer().len(), format::HEADER_SIZE);
+
+    // Past the pairwise limit the ids are checked through a sorted copy
+    let mut many: Vec<OffsetEntry> = (0..40).rev().map(|i| entry(i, 39 - i)).collect();
+    many[25].field_id = 7;
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(FormatHeader::new(40 * std::mem::size_of::<OffsetEntry>() as u32, 40, 0));
+    assert!(matches!(
+        serializer.write_offset_table(&many),
+        Err(SerializationError::DuplicateFieldId { field_id: 7 })
+    ));
+    let mut serializer = BinarySerializer::new().with_sorted_table();
+    serializer.write_header(FormatHeader::new(40 * std::mem::size_of::<OffsetEntry>() as u32, 40, 0));
+    assert!(matches!(
+        serializer.write_offset_table(&many),
+        Err(SerializationError::DuplicateFieldId { field_id: 7 })
+    ));
+
+    let mut incremental = IncrementalSerializer::new();
+    incremental.push_fixed(1, FieldType::Uint8, &[1]).unwrap();
+    assert!(matches!(
+        incremental.push_var(1, FieldType::String, b"a", 4),
+        Err(SerializationError::DuplicateFieldId { field_id: 1 })
+    ));
+    assert_eq!(incremental.len(), 1);
+}
+
+#[test]
+fn test_view_validated() {
+    let buffer = create_test_buffer();
+    BinaryView::view_validated(&buffer).unwrap();
+
+    // Field 1 pointed far past the buffer: `view` defers to the accessors
+    let mut far = buffer.clone();
+    far[84..88].copy_from_slice(&10_000u32.to_le_bytes());
+    let view = BinaryView::view(&far).unwrap();
+    assert!(matches!(view.get_field_copy::<u64>(1), Err(SerializationError::InvalidOffset { .. })));
+    assert!(matches!(
+        BinaryView::view_validated(&far),
+        Err(SerializationError::InvalidOffset { offset: 10_136, size: 149 })
+    ));
+
+    // Field 2 moved onto the second half of field 1
+    let mut overlapping = buffer.clone();
+    overlapping[96..100].copy_from_slice(&4u32.to_le_bytes());
+    BinaryView::view(&overlapping).unwrap();
+    assert!(matches!(
+        BinaryView::view_validated(&overlapping),
+        Err(SerializationError::InvalidOffset { offset: 136, size: 132 })
+    ));
+}
+
+#[test]
+fn test_blob_lengths() {
+    let mut builder = SchemaBuilder::new();
+    builder
+        .blob_lengths(true)
+        .add_var_with_capacity(1, FieldType::Blob, &[1, 2, 0], 8).unwrap()
+        .add_var_with_capacity(2, FieldType::Blob, &[], 4).unwrap()
+        .add_var(3, FieldType::Blob, &[9; 6]).unwrap();
+    let mut buffer = builder.build().unwrap();
+    assert_eq!(builder.total_size(), buffer.len());
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(view.has_blob_lengths());
+    assert_eq!(view.get_blob(1).unwrap(), &[1, 2, 0]);
+    assert_eq!(view.get_blob_raw(1).unwrap(), &[1, 2, 0, 0, 0, 0, 0, 0]);
+    assert!(view.get_blob(2).unwrap().is_empty());
+    assert_eq!(view.get_blob_raw(2).unwrap().len(), 4);
+    assert_eq!(view.get_blob(3).unwrap(), &[9; 6]);
+    assert_eq!(ValidatedView::new(&buffer).unwrap().get_blob(1).unwrap(), &[1, 2, 0]);
+
+    let mut view = BinaryViewMut::view_mut(&mut buffer).unwrap();
+    view.modify_blob(1, &[7; 8]).unwrap();
+    view.modify_blob(3, &[]).unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_blob(1).unwrap(), &[7; 8]);
+    assert!(view.get_blob(3).unwrap().is_empty());
+
+    BinaryViewMut::view_mut(&mut buffer).unwrap().modify_blob(1, &[5, 0]).unwrap();
+    let rebuilt = SchemaBuilder::from_view(&BinaryView::view(&buffer).unwrap()).unwrap().build().unwrap();
+    assert_eq!(BinaryView::view(&rebuilt).unwrap().get_blob(1).unwrap(), &[5, 0]);
+    BinaryViewMut::view_mut(&mut buffer).unwrap().zero_field(1).unwrap();
+    assert!(BinaryView::view(&buffer).unwrap().get_blob(1).unwrap().is_empty());
+
+    // Without the table the whole reservation comes back
+    let mut builder = SchemaBuilder::new();
+    builder.add_var_with_capacity(1, FieldType::Blob, &[1, 2], 4).unwrap();
+    let buffer = builder.build().unwrap();
+    let view = BinaryView::view(&buffer).unwrap();
+    assert!(!view.has_blob_lengths());
+    assert_eq!(view.get_blob(1).unwrap(), &[1, 2, 0, 0]);
+    assert_eq!(view.get_blob(1).unwrap(), view.get_blob_raw(1).unwrap());
+}
+
+#[test]
+fn test_owned_document_append_fixed_field() {
+    let mut builder = user_schema_builder();
+    builder
+        .add_var_with_capacity(10, FieldType::String, b"Alice", 16).unwrap()
+        .add_metadata("producer", "ingest-7").unwrap();
+    let mut buffer = builder.build().unwrap();
+    BinaryViewMut::view_mut(&mut buffer).unwrap().store_checksum().unwrap();
+    let mut document = OwnedDocument::new(buffer.clone()).unwrap();
+
+    document.append_fixed_field(7, FieldType::Int16, &(-3i16).to_le_bytes()).unwrap();
+    let bytes = document.as_bytes();
+    assert_eq!(bytes.len(), buffer.len() + std::mem::size_of::<OffsetEntry>() + 2);
+    let header: FormatHeader = bytemuck::pod_read_unaligned(&bytes[..80]);
+    assert_eq!({ header.offset_table_size }, 6 * std::mem::size_of::<OffsetEntry>() as u32);
+    assert_eq!({ header.data_size }, 21 + 2);
+
+    let view = document.view().unwrap();
+    view.verify().unwrap();
+    assert_eq!(view.field_count(), 6);
+    assert_eq!(view.get_field_copy::<i16>(7).unwrap(), -3);
+    assert_eq!(view.find_entry(7).map(|e| e.offset), Some(21));
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 12345);
+    assert_eq!(view.get_field_copy::<f64>(3).unwrap(), 95.5);
+    assert_eq!(view.get_string(10).unwrap(), "Alice");
+    assert_eq!(view.metadata("producer"), Some("ingest-7"));
+
+    assert!(matches!(
+        document.append_fixed_field(2, FieldType::Uint32, &[0; 4]),
+        Err(SerializationError::DuplicateFieldId { field_id: 2 })
+    ));
+    assert!(matches!(
+        document.append_fixed_field(8, FieldType::Uint32, &[0; 2]),
+        Err(SerializationError::FieldSizeMismatch { expected: 4, got: 2 })
+    ));
+    assert_eq!(document.view().unwrap().field_count(), 6);
+
+    // A sorted table takes the entry at its place, so lookups still binary-search
+    let mut buffer = create_test_buffer();
+    let mut flags = u64::from_le_bytes(buffer[32..40].try_into().unwrap());
+    flags |= format::FLAG_SORTED_TABLE;
+    buffer[32..40].copy_from_slice(&flags.to_le_bytes());
+    let mut document = OwnedDocument::new(buffer).unwrap();
+    document.append_fixed_field(0, FieldType::Bool, &[1]).unwrap();
+    let view = document.view().unwrap();
+    let ids: Vec<u32> = view.raw_fields().map(|field| field.unwrap().0).collect();
+    assert_eq!(ids, [0, 1, 2, 3, 4]);
+    assert!(view.get_bool(0).unwrap());
+    assert_eq!(view.get_field_copy::<u32>(2).unwrap(), 30);
+}
+
+#[test]
+fn test_get_record_slice() {
+    let records: Vec<TestData> = (0..3)
+        .map(|i| TestData { id: 100 + i, age: 20 + i as u32, score: i as f64 * 1.5, active: (i % 2) as u8 })
+        .collect();
+    let mut entries = Vec::new();
+    for index in 0..records.len() as u32 {
+        let base = index * 21;
+        let id = index * 4;
+        entries.extend([
+            OffsetEntry { field_id: id + 1, offset: base, field_type: FieldType::Uint64 as u16, size: 8 },
+            OffsetEntry { field_id: id + 2, offset: base + 8, field_type: FieldType::Uint32 as u16, size: 4 },
+            OffsetEntry { field_id: id + 3, offset: base + 12, field_type: FieldType::Float64 as u16, size: 8 },
+            OffsetEntry { field_id: id + 4, offset: base + 20, field_type: FieldType::Uint8 as u16, size: 1 },
+        ]);
+    }
+    let mut serializer = BinarySerializer::new();
+    serializer.write_header(FormatHeader::new(
+        std::mem::size_of_val(entries.as_slice()) as u32,
+        std::mem::size_of_val(records.as_slice()) as u32,
+        0,
+    ));
+    serializer.write_offset_table(&entries).unwrap();
+    serializer.write_data(bytemuck::cast_slice(&records));
+    let mut buffer = serializer.into_buffer();
+
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_record_slice::<TestData>(1, 3).unwrap(), records.as_slice());
+    assert_eq!(view.get_record_slice::<TestData>(5, 2).unwrap(), &records[1..]);
+    assert!(view.get_record_slice::<TestData>(9, 0).unwrap().is_empty());
+    assert!(matches!(
+        view.get_record_slice::<TestData>(5, 3),
+        Err(SerializationError::InvalidOffset { .. })
+    ));
+    assert!(matches!(
+        view.get_record_slice::<TestData>(99, 1),
+        Err(SerializationError::FieldNotFound { field_id: 99 })
+    ));
+    // A 16-byte record cuts the Float64 field of the first record in half
+    assert!(matches!(
+        view.get_record_slice::<[u64; 2]>(1, 2),
+        Err(SerializationError::NonUniformRecords { field_id: 1 })
+    ));
+
+    // Retyping one field of the last record breaks the pattern only for slices reaching it
+    let table_start = 80;
+    let last_active = table_start + 11 * std::mem::size_of::<OffsetEntry>() + 8;
+    buffer[last_active..last_active + 2].copy_from_slice(&(FieldType::Int8 as u16).to_le_bytes());
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_record_slice::<TestData>(1, 2).unwrap().len(), 2);
+    assert!(matches!(
+        view.get_record_slice::<TestData>(1, 3),
+        Err(SerializationError::NonUniformRecords { field_id: 1 })
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_roundtrip() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Tier {
+        Free,
+        Pro,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct User<'a> {
+        id: u64,
+        age: u32,
+        score: f64,
+        active: bool,
+        delta: i16,
+        initial: char,
+        name: String,
+        nickname: Option<&'a str>,
+        tier: Tier,
+    }
+
+    let user = User {
+        id: 12345,
+        age: 30,
+        score: 95.5,
+        active: true,
+        delta: -7,
+        initial: 'é',
+        name: "Alice".to_string(),
+        nickname: None,
+        tier: Tier::Pro,
+    };
+    let buffer = bisere::to_bytes(&user).unwrap();
+
+    // Ids follow declaration order; the `None` field is left out
+    let view = BinaryView::view(&buffer).unwrap();
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 12345);
+    assert!(view.get_bool(4).unwrap());
+    assert_eq!(view.get_string(7).unwrap(), "Alice");
+    assert!(!view.has_field(8));
+    assert_eq!(view.get_string(9).unwrap(), "Pro");
+    assert_eq!(bisere::from_bytes::<User>(&buffer).unwrap(), user);
+
+    let with_nickname = User { nickname: Some("Al"), tier: Tier::Free, ..user };
+    let buffer = bisere::to_bytes(&with_nickname).unwrap();
+    let decoded: User = bisere::from_bytes(&buffer).unwrap();
+    assert_eq!(decoded, with_nickname);
+
+    #[derive(Serialize)]
+    struct Nested {
+        inner: (u8, u8),
+    }
+    assert!(matches!(
+        bisere::to_bytes(&Nested { inner: (1, 2) }),
+        Err(SerializationError::Serde { .. })
+    ));
+    assert!(matches!(bisere::to_bytes(&5u32), Err(SerializationError::Serde { .. })));
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn test_var_compression() {
+    use bisere::format::FLAG_VAR_ZSTD;
+
+    let entries = [
+        OffsetEntry { field_id: 1, offset: 0, field_type: FieldType::Uint64 as u16, size: 8 },
+        OffsetEntry { field_id: 2, offset: 0, field_type: FieldType::String as u16, size: 512 },
+        OffsetEntry { field_id: 3, offset: 512, field_type: FieldType::Blob as u16, size: 512 },
+    ];
+    let build = |codec: Option<Codec>, var_size: u32| {
+        let mut serializer = BinarySerializer::new().with_checksum(ChecksumAlgo::Crc32c);
+        if let Some(codec) = codec {
+            serializer = serializer.with_var_compression(codec);
+        }
+        let table = if var_size == 0 { &entries[..1] } else { &entries[..] };
+        serializer.write_header(FormatHeader::new(std::mem::size_of_val(table) as u32, 8, var_size));
+        serializer.write_offset_table(table).unwrap();
+        serializer.write_data(&42u64.to_le_bytes());
+        if var_size > 0 {
+            let mut var_data = vec![0u8; var_size as usize];
+            var_data[..5].copy_from_slice(b"hello");
+            var_data[512..516].copy_from_slice(&[1, 2, 3, 4]);
+            serializer.write_var_data(&var_data);
+        }
+        serializer.finish().unwrap().bytes
+    };
+
+    let plain = build(None, 1024);
+    let compressed = build(Some(Codec::Zstd { level: 3 }), 1024);
+    assert!(compressed.len() < plain.len());
+    let header = bytemuck::pod_read_unaligned::<FormatHeader>(&compressed[..80]);
+    assert!(header.has_flag(FLAG_VAR_ZSTD));
+    assert_eq!(header.uncompressed_var_size(), Some(1024));
+    assert_eq!(header.total_size(), compressed.len());
+    assert!({ header.var_size } < 1024);
+
+    // Fixed fields stay zero-copy; var fields come from the decompressed copy
+    let view = BinaryView::view_verified(&compressed).unwrap();
+    assert_eq!(view.field_raw_bytes(1).unwrap(), &42u64.to_le_bytes());
+    assert_eq!(view.get_field_copy::<u64>(1).unwrap(), 42);
+    assert_eq!(view.get_string(2).unwrap(), "hello");
+    assert_eq!(&view.get_blob_cow(3).unwrap()[..4], &[1, 2, 3, 4]);
+    assert_eq!(view.get_blob_cow(3).unwrap().len(), 512);
+    assert!(matches!(view.get_blob(3), Err(SerializationError::CompressedVarSection)));
+
+    // Whole-buffer accessors read var fields from the decompressed copy too
+    let plain_view = BinaryView::view(&plain).unwrap();
+    assert_eq!(view.gaps(), plain_view.gaps());
+    assert_eq!(view.utilization().used_bytes, plain_view.utilization().used_bytes);
+    assert_eq!(view.utilization().unused_var_bytes, plain_view.utilization().unused_var_bytes);
+    let fields: Vec<(u32, &[u8])> = view.raw_fields().collect::<Result<_>>().unwrap();
+    let plain_fields: Vec<(u32, &[u8])> = plain_view.raw_fields().collect::<Result<_>>().unwrap();
+    assert_eq!(fields, plain_fields);
+    let extracted = view.extract_field(2).unwrap();
+    assert_eq!(BinaryView::view(&extracted).unwrap().get_string(2).unwrap(), "hello");
+    assert!(matches!(ValidatedView::new(&compressed), Err(SerializationError::CompressedVarSection)));
+    #[cfg(feature = "serde")]
+    {
+        #[derive(serde::Deserialize)]
+        struct Record {
+            id: u64,
+            name: String,
+        }
+        let record: Record = bisere::from_bytes(&compressed).unwrap();
+        assert_eq!((record.id, record.name.as_str()), (42, "hello"));
+    }
+
+    let mut edited = compressed.clone();
+    let mut view = BinaryViewMut::view_mut(&mut edited).unwrap();
+    view.modify_field(1, &7u64).unwrap();
+    assert!(matches!(view.modify_string(2, "bye"), Err(SerializationError::CompressedVarSection)));
+
+    // Nothing to compress: the flag stays clear
+    let empty = build(Some(Codec::Zstd { level: 3 }), 0);
+    let header = bytemuck::pod_read_unaligned::<FormatHeader>(&empty[..80]);
+    assert!(!header.has_flag(FLAG_VAR_ZSTD));
+    assert_eq!(empty, build(None, 0));
+}
+
+#[test]
+fn test_debug_dump() {
+    let mut buffer = create_test_buffer();
+    let dump = BinaryView::view(&buffer).unwrap().debug_dump();
+    let lines: Vec<&str> = dump.lines().collect();
+    assert_eq!(lines[0], "magic: 0x42495345");
+    assert!(lines.contains(&"offset_table_size: 48 (4 entries)"));
+    assert!(lines.contains(&"var_size: 256"));
+    assert!(lines.contains(&"field 1: Uint64 at 128 size 8: 39 30 00 00 00 00 00 00"));
+    assert!(lines.contains(&"field 4: Uint8 at 148 size 1: 01"));
+    assert_eq!(lines.len(), 9 + 4);
+
+    // An unknown type code is shown raw rather than rejected
+    let type_at = 80 + 12 + 8;
+    buffer[type_at..type_at + 2].copy_from_slice(&99u16.to_le_bytes());
+    let dump = BinaryView::view(&buffer).unwrap().debug_dump();
+    assert!(dump.contains("field 2: type 99 at 136 size 4: 1e 00 00 00"));
+}
+
+#[test]
+fn test_diff() {
+    let base = create_test_buffer();
+    let view = BinaryView::view(&base).unwrap();
+    assert!(view.diff(&view).is_empty());
+
+    let mut changed = base.clone();
+    BinaryViewMut::view_mut(&mut changed).unwrap().modify_field(2, &31u32).unwrap();
+    let mut document = OwnedDocument::new(changed).unwrap();
+    document.append_fixed_field(9, FieldType::Uint16, &7u16.to_le_bytes()).unwrap();
+    let other = document.view().unwrap();
+    assert_eq!(
+        view.diff(&other),
+        vec![FieldDiff::Changed { field_id: 2 }, FieldDiff::OnlyInB { field_id: 9 }]
+    );
+    assert_eq!(
+        other.diff(&view),
+        vec![FieldDiff::Changed { field_id: 2 }, FieldDiff::OnlyInA { field_id: 9 }]
+    );
+
+    // Raw bytes: the same value stored big-endian is a change
+    let mut swapped = base.clone();
+    swap_endianness(&mut swapped).unwrap();
+    let swapped_view = BinaryView::view(&swapped).unwrap();
+    assert_eq!(view.diff(&swapped_view).len(), 3);
+
+    // Fields that can't be read aren't taken as equal
+    let mut broken = base.clone();
+    broken[120..124].copy_from_slice(&1000u32.to_le_bytes());
+    let broken_view = BinaryView::view_unchecked(&broken).unwrap();
+    assert_eq!(broken_view.diff(&broken_view), vec![FieldDiff::Changed { field_id: 4 }]);
 }